@@ -176,6 +176,14 @@ impl ActivityIndicator {
             .flatten()
     }
 
+    /// The progress, from 0 to 100, of the slowest pending language server work that reports
+    /// one, such as indexing a project, or `None` when there is no such work.
+    pub fn indexing_progress(&self, cx: &AppContext) -> Option<usize> {
+        self.pending_language_server_work(cx)
+            .filter_map(|work| work.progress.percentage)
+            .min()
+    }
+
     fn pending_environment_errors<'a>(
         &'a self,
         cx: &'a AppContext,
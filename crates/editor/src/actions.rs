@@ -1,6 +1,6 @@
 //! This module contains all actions supported by [`Editor`].
 use super::*;
-use gpui::action_as;
+use gpui::{action_as, impl_action_as};
 use util::serde::default_true;
 
 #[derive(PartialEq, Clone, Deserialize, Default)]
@@ -161,6 +161,40 @@ pub struct FoldAtLevel {
     pub level: u32,
 }
 
+#[derive(PartialEq, Clone, Deserialize, Default)]
+pub struct FoldByIndent {
+    /// Lines indented by more than this many tab stops are folded.
+    /// When `None`, such as when run from the command palette, the level is prompted for.
+    #[serde(default)]
+    pub max_indent: Option<usize>,
+}
+
+#[derive(PartialEq, Clone, Deserialize, Default)]
+pub struct SortLinesCaseInsensitive {
+    #[serde(default)]
+    pub descending: bool,
+}
+
+#[derive(PartialEq, Clone, Deserialize, Default)]
+pub struct SortLinesCaseSensitive {
+    #[serde(default)]
+    pub descending: bool,
+}
+
+/// Replaces the first match of `pattern` within each selection, or within the word
+/// around each empty selection, with `replacement`. The replacement can refer to
+/// capture groups, as in `$1` or `${name}`.
+///
+/// When the pattern is empty, such as when run from the command palette, the pattern
+/// and replacement are prompted for.
+#[derive(PartialEq, Clone, Deserialize, Default)]
+pub struct MultiCursorPatternEdit {
+    #[serde(default)]
+    pub pattern: String,
+    #[serde(default)]
+    pub replacement: String,
+}
+
 #[derive(PartialEq, Clone, Deserialize, Default)]
 pub struct SpawnNearestTask {
     #[serde(default)]
@@ -199,15 +233,23 @@ impl_actions!(
         SelectToBeginningOfLine,
         SelectToEndOfLine,
         SelectUpByLines,
+        SortLinesCaseInsensitive,
+        SortLinesCaseSensitive,
         SpawnNearestTask,
         ShowCompletions,
         ToggleCodeActions,
         ToggleComments,
         UnfoldAt,
-        FoldAtLevel
+        FoldAtLevel,
+        FoldByIndent
     ]
 );
 
+impl_action_as!(
+    editor,
+    MultiCursorPatternEdit as TransformSelectionsWithRegex
+);
+
 gpui::actions!(
     editor,
     [
@@ -242,6 +284,7 @@ gpui::actions!(
         CopyRelativePath,
         Cut,
         CutToEndOfLine,
+        CycleCase,
         Delete,
         DeleteLine,
         DeleteToBeginningOfLine,
@@ -320,6 +363,7 @@ gpui::actions!(
         PageDown,
         PageUp,
         Paste,
+        PasteAndIndent,
         PreviousInlineCompletion,
         Redo,
         RedoSelection,
@@ -359,8 +403,6 @@ gpui::actions!(
         ShowInlineCompletion,
         ShowSignatureHelp,
         ShuffleLines,
-        SortLinesCaseInsensitive,
-        SortLinesCaseSensitive,
         SplitSelectionIntoLines,
         SwitchSourceHeader,
         Tab,
@@ -375,6 +417,7 @@ gpui::actions!(
         ToggleLineNumbers,
         ToggleRelativeLineNumbers,
         ToggleSelectionMenu,
+        ToggleShowWhitespace,
         ToggleSoftWrap,
         ToggleTabBar,
         Transpose,
@@ -40,7 +40,8 @@ pub use crease_map::*;
 pub use fold_map::{Fold, FoldId, FoldPlaceholder, FoldPoint};
 use fold_map::{FoldMap, FoldSnapshot};
 use gpui::{
-    AnyElement, Font, HighlightStyle, LineLayout, Model, ModelContext, Pixels, UnderlineStyle,
+    AnyElement, Font, HighlightStyle, LineLayout, Model, ModelContext, Pixels, UnderlinePattern,
+    UnderlineStyle,
 };
 pub(crate) use inlay_map::Inlay;
 use inlay_map::{InlayMap, InlaySnapshot};
@@ -631,7 +632,7 @@ impl<'a> HighlightedChunk<'a> {
                         underline: Some(UnderlineStyle {
                             color: Some(editor_style.status.hint),
                             thickness: px(1.),
-                            wavy: false,
+                            ..Default::default()
                         }),
                         ..Default::default()
                     };
@@ -653,7 +654,7 @@ impl<'a> HighlightedChunk<'a> {
                         underline: Some(UnderlineStyle {
                             color: Some(editor_style.status.hint),
                             thickness: px(1.),
-                            wavy: false,
+                            ..Default::default()
                         }),
                         ..Default::default()
                     };
@@ -932,7 +933,7 @@ impl DisplaySnapshot {
                     diagnostic_highlight.underline = Some(UnderlineStyle {
                         color: Some(diagnostic_color),
                         thickness: 1.0.into(),
-                        wavy: true,
+                        pattern: UnderlinePattern::wavy(px(1.)),
                     });
                 }
             }
@@ -28,6 +28,7 @@ mod hover_popover;
 mod hunk_diff;
 mod indent_guides;
 mod inlay_hint_cache;
+mod input_prompt;
 pub mod items;
 mod linked_editing_ranges;
 mod lsp_ext;
@@ -82,9 +83,9 @@ use gpui::{
     DispatchPhase, ElementId, EventEmitter, FocusHandle, FocusOutEvent, FocusableView, FontId,
     FontWeight, Global, HighlightStyle, Hsla, InteractiveText, KeyContext, Model, ModelContext,
     MouseButton, PaintQuad, ParentElement, Pixels, Render, SharedString, Size, Styled, StyledText,
-    Subscription, Task, TextStyle, TextStyleRefinement, UTF16Selection, UnderlineStyle,
-    UniformListScrollHandle, View, ViewContext, ViewInputHandler, VisualContext, WeakFocusHandle,
-    WeakView, WindowContext,
+    Subscription, Task, TextStyle, TextStyleRefinement, UTF16Selection, UnderlinePattern,
+    UnderlineStyle, UniformListScrollHandle, View, ViewContext, ViewInputHandler, VisualContext,
+    WeakFocusHandle, WeakView, WindowContext,
 };
 use highlight_matching_bracket::refresh_matching_bracket_highlights;
 use hover_popover::{hide_hover, HoverState};
@@ -94,10 +95,13 @@ use indent_guides::ActiveIndentGuidesState;
 use inlay_hint_cache::{InlayHintCache, InlaySplice, InvalidationStrategy};
 pub use inline_completion::Direction;
 use inline_completion::{InlineCompletionProvider, InlineCompletionProviderHandle};
+use input_prompt::InputPrompt;
 pub use items::MAX_TAB_TITLE_LEN;
 use itertools::Itertools;
 use language::{
-    language_settings::{self, all_language_settings, language_settings, InlayHintSettings},
+    language_settings::{
+        self, all_language_settings, language_settings, InlayHintSettings, ShowWhitespaceSetting,
+    },
     markdown, point_from_lsp, AutoindentMode, BracketPair, Buffer, Capability, CharKind, CodeLabel,
     CursorShape, Diagnostic, DiagnosticEntry, Documentation, IndentKind, IndentSize, Language,
     OffsetRangeExt, Point, Selection, SelectionGoal, TransactionId,
@@ -135,6 +139,7 @@ use project::{
     LspStore, Project, ProjectItem, ProjectTransaction, TaskSourceKind,
 };
 use rand::prelude::*;
+use regex::Regex;
 use rpc::{proto::*, ErrorExt};
 use scroll::{Autoscroll, OngoingScroll, ScrollAnchor, ScrollManager, ScrollbarAutoHide};
 use selections_collection::{
@@ -614,6 +619,7 @@ pub struct Editor {
     show_runnables: Option<bool>,
     show_wrap_guides: Option<bool>,
     show_indent_guides: Option<bool>,
+    show_whitespaces: Option<ShowWhitespaceSetting>,
     placeholder_text: Option<Arc<str>>,
     highlight_order: usize,
     highlighted_rows: HashMap<TypeId, Vec<RowHighlight>>,
@@ -1252,6 +1258,7 @@ impl Editor {
             show_runnables: None,
             show_wrap_guides: None,
             show_indent_guides,
+            show_whitespaces: None,
             placeholder_text: None,
             highlight_order: 0,
             highlighted_rows: HashMap::default(),
@@ -5913,18 +5920,20 @@ impl Editor {
 
     pub fn sort_lines_case_sensitive(
         &mut self,
-        _: &SortLinesCaseSensitive,
+        action: &SortLinesCaseSensitive,
         cx: &mut ViewContext<Self>,
     ) {
-        self.manipulate_lines(cx, |lines| lines.sort())
+        let descending = action.descending;
+        self.manipulate_lines(cx, |lines| sort_lines(lines, false, descending))
     }
 
     pub fn sort_lines_case_insensitive(
         &mut self,
-        _: &SortLinesCaseInsensitive,
+        action: &SortLinesCaseInsensitive,
         cx: &mut ViewContext<Self>,
     ) {
-        self.manipulate_lines(cx, |lines| lines.sort_by_key(|line| line.to_lowercase()))
+        let descending = action.descending;
+        self.manipulate_lines(cx, |lines| sort_lines(lines, true, descending))
     }
 
     pub fn unique_lines_case_insensitive(
@@ -6219,6 +6228,77 @@ impl Editor {
         })
     }
 
+    /// Converts the word under each cursor, or each selection, to the next case in the order of
+    /// [`WordCase`], starting over from snake_case once it reaches kebab-case.
+    pub fn cycle_case(&mut self, _: &CycleCase, cx: &mut ViewContext<Self>) {
+        self.manipulate_text(cx, |text| {
+            let next_case = WordCase::detect(text).map_or(WordCase::Snake, WordCase::next);
+            transform_to_case(text, next_case)
+        })
+    }
+
+    pub fn multi_cursor_pattern_edit(
+        &mut self,
+        action: &MultiCursorPatternEdit,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if action.pattern.is_empty() {
+            self.prompt_for_input(
+                "Transform selections with regex",
+                &["Pattern", "Replacement"],
+                |inputs, editor, cx| {
+                    let mut inputs = inputs.into_iter();
+                    let pattern = inputs.next().unwrap_or_default();
+                    let replacement = inputs.next().unwrap_or_default();
+                    if !pattern.is_empty() {
+                        editor.multi_cursor_pattern_edit(
+                            &MultiCursorPatternEdit {
+                                pattern,
+                                replacement,
+                            },
+                            cx,
+                        );
+                    }
+                },
+                cx,
+            );
+            return;
+        }
+        let regex = match Regex::new(&action.pattern) {
+            Ok(regex) => regex,
+            Err(error) => {
+                log::error!("invalid pattern {:?}: {error}", action.pattern);
+                return;
+            }
+        };
+
+        self.manipulate_text(cx, |text| {
+            regex
+                .replace(text, action.replacement.as_str())
+                .into_owned()
+        })
+    }
+
+    /// Asks for the arguments of an action in a modal, calling `on_confirm` with the text
+    /// entered for each placeholder once they've all been confirmed.
+    fn prompt_for_input(
+        &mut self,
+        title: &'static str,
+        placeholders: &'static [&'static str],
+        on_confirm: impl FnOnce(Vec<String>, &mut Editor, &mut ViewContext<Editor>) + 'static,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(workspace) = self.workspace() else {
+            return;
+        };
+        let editor = cx.view().downgrade();
+        workspace.update(cx, |workspace, cx| {
+            workspace.toggle_modal(cx, move |cx| {
+                InputPrompt::new(title, placeholders, editor, on_confirm, cx)
+            })
+        });
+    }
+
     fn manipulate_text<Fn>(&mut self, cx: &mut ViewContext<Self>, mut callback: Fn)
     where
         Fn: FnMut(&str) -> String,
@@ -6908,6 +6988,19 @@ impl Editor {
         clipboard_selections: Option<Vec<ClipboardSelection>>,
         handle_entire_lines: bool,
         cx: &mut ViewContext<Self>,
+    ) {
+        self.do_paste_with_auto_indent(text, clipboard_selections, handle_entire_lines, false, cx)
+    }
+
+    /// Pastes `text`, re-indenting it to match the surrounding code even when the
+    /// `auto_indent_on_paste` setting is disabled if `force_auto_indent` is true.
+    fn do_paste_with_auto_indent(
+        &mut self,
+        text: &String,
+        clipboard_selections: Option<Vec<ClipboardSelection>>,
+        handle_entire_lines: bool,
+        force_auto_indent: bool,
+        cx: &mut ViewContext<Self>,
     ) {
         if self.read_only(cx) {
             return;
@@ -6930,8 +7023,8 @@ impl Editor {
 
                 this.buffer.update(cx, |buffer, cx| {
                     let snapshot = buffer.read(cx);
-                    auto_indent_on_paste =
-                        snapshot.settings_at(cursor_offset, cx).auto_indent_on_paste;
+                    auto_indent_on_paste = force_auto_indent
+                        || snapshot.settings_at(cursor_offset, cx).auto_indent_on_paste;
 
                     let mut start_offset = 0;
                     let mut edits = Vec::new();
@@ -6984,6 +7077,17 @@ impl Editor {
 
                 let selections = this.selections.all::<usize>(cx);
                 this.change_selections(Some(Autoscroll::fit()), cx, |s| s.select(selections));
+            } else if force_auto_indent {
+                // Text copied outside of Zed carries no indentation metadata, so strip
+                // its common indentation and indent it relative to the cursor instead.
+                let original_indent_columns = vec![0; this.selections.count()];
+                this.insert_with_autoindent_mode(
+                    &strip_common_indent(&clipboard_text),
+                    Some(AutoindentMode::Block {
+                        original_indent_columns,
+                    }),
+                    cx,
+                );
             } else {
                 this.insert(&clipboard_text, cx);
             }
@@ -6991,6 +7095,14 @@ impl Editor {
     }
 
     pub fn paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
+        self.paste_from_clipboard(false, cx);
+    }
+
+    pub fn paste_and_indent(&mut self, _: &PasteAndIndent, cx: &mut ViewContext<Self>) {
+        self.paste_from_clipboard(true, cx);
+    }
+
+    fn paste_from_clipboard(&mut self, force_auto_indent: bool, cx: &mut ViewContext<Self>) {
         if let Some(item) = cx.read_from_clipboard() {
             let entries = item.entries();
 
@@ -6998,13 +7110,20 @@ impl Editor {
                 // For now, we only support applying metadata if there's one string. In the future, we can incorporate all the selections
                 // of all the pasted entries.
                 Some(ClipboardEntry::String(clipboard_string)) if entries.len() == 1 => self
-                    .do_paste(
+                    .do_paste_with_auto_indent(
                         clipboard_string.text(),
                         clipboard_string.metadata_json::<Vec<ClipboardSelection>>(),
                         true,
+                        force_auto_indent,
                         cx,
                     ),
-                _ => self.do_paste(&item.text().unwrap_or_default(), None, true, cx),
+                _ => self.do_paste_with_auto_indent(
+                    &item.text().unwrap_or_default(),
+                    None,
+                    true,
+                    force_auto_indent,
+                    cx,
+                ),
             }
         }
     }
@@ -10634,6 +10753,81 @@ impl Editor {
         self.fold_creases(to_fold, true, cx);
     }
 
+    fn fold_by_indent(&mut self, fold_by: &FoldByIndent, cx: &mut ViewContext<Self>) {
+        if !self.buffer.read(cx).is_singleton() {
+            return;
+        }
+        let Some(max_indent) = fold_by.max_indent else {
+            self.prompt_for_input(
+                "Fold by indent level",
+                &["Indent level"],
+                |inputs, editor, cx| {
+                    if let Some(max_indent) = inputs
+                        .first()
+                        .and_then(|level| level.trim().parse::<usize>().ok())
+                    {
+                        editor.fold_by_indent(
+                            &FoldByIndent {
+                                max_indent: Some(max_indent),
+                            },
+                            cx,
+                        );
+                    }
+                },
+                cx,
+            );
+            return;
+        };
+
+        let snapshot = self.buffer.read(cx).snapshot(cx);
+        let tab_size = snapshot.settings_at(0, cx).tab_size.get();
+        let is_too_deep = |row: u32| {
+            let indent = snapshot.indent_size_for_line(MultiBufferRow(row));
+            let level = match indent.kind {
+                IndentKind::Tab => indent.len,
+                IndentKind::Space => indent.len / tab_size,
+            };
+            level as usize > max_indent
+        };
+
+        let placeholder = self.display_map.read(cx).fold_placeholder.clone();
+        let mut creases = Vec::new();
+        let mut row = 0;
+        while row <= snapshot.max_row().0 {
+            if snapshot.is_line_blank(MultiBufferRow(row)) || !is_too_deep(row) {
+                row += 1;
+                continue;
+            }
+
+            // Blank lines don't end a run, but trailing ones are left unfolded.
+            let start_row = row;
+            let mut end_row = row;
+            row += 1;
+            while row <= snapshot.max_row().0 {
+                if !snapshot.is_line_blank(MultiBufferRow(row)) {
+                    if !is_too_deep(row) {
+                        break;
+                    }
+                    end_row = row;
+                }
+                row += 1;
+            }
+
+            // Keep the line that introduces the block visible, folding from its end.
+            let start = match start_row.checked_sub(1) {
+                Some(previous_row) => Point::new(
+                    previous_row,
+                    snapshot.line_len(MultiBufferRow(previous_row)),
+                ),
+                None => Point::zero(),
+            };
+            let end = Point::new(end_row, snapshot.line_len(MultiBufferRow(end_row)));
+            creases.push(Crease::simple(start..end, placeholder.clone()));
+        }
+
+        self.fold_creases(creases, true, cx);
+    }
+
     pub fn fold_all(&mut self, _: &actions::FoldAll, cx: &mut ViewContext<Self>) {
         if self.buffer.read(cx).is_singleton() {
             let mut fold_ranges = Vec::new();
@@ -11252,6 +11446,22 @@ impl Editor {
         self.show_indent_guides
     }
 
+    pub fn toggle_show_whitespace(&mut self, _: &ToggleShowWhitespace, cx: &mut ViewContext<Self>) {
+        let show_whitespaces = match self.show_whitespaces(cx) {
+            ShowWhitespaceSetting::None => ShowWhitespaceSetting::All,
+            _ => ShowWhitespaceSetting::None,
+        };
+        self.show_whitespaces = Some(show_whitespaces);
+        cx.notify();
+    }
+
+    /// Returns how whitespace is rendered in this editor, preferring a per-editor
+    /// override over the `show_whitespaces` language setting.
+    pub fn show_whitespaces(&self, cx: &AppContext) -> ShowWhitespaceSetting {
+        self.show_whitespaces
+            .unwrap_or_else(|| self.buffer.read(cx).settings_at(0, cx).show_whitespaces)
+    }
+
     pub fn toggle_line_numbers(&mut self, _: &ToggleLineNumbers, cx: &mut ViewContext<Self>) {
         let mut editor_settings = EditorSettings::get_global(cx).clone();
         editor_settings.gutter.line_numbers = !editor_settings.gutter.line_numbers;
@@ -11332,6 +11542,15 @@ impl Editor {
         cx.notify();
     }
 
+    pub fn set_show_whitespaces(
+        &mut self,
+        show_whitespaces: ShowWhitespaceSetting,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.show_whitespaces = Some(show_whitespaces);
+        cx.notify();
+    }
+
     pub fn working_directory(&self, cx: &WindowContext) -> Option<PathBuf> {
         if let Some(buffer) = self.buffer().read(cx).as_singleton() {
             if let Some(file) = buffer.read(cx).file().and_then(|f| f.as_local()) {
@@ -13915,6 +14134,128 @@ fn consume_contiguous_rows(
     (start_row, end_row)
 }
 
+fn sort_lines(lines: &mut [&str], case_insensitive: bool, descending: bool) {
+    if case_insensitive {
+        lines.sort_by_cached_key(|line| line.to_lowercase());
+    } else {
+        lines.sort();
+    }
+    if descending {
+        lines.reverse();
+    }
+}
+
+/// Removes the leading whitespace shared by every non-blank line of `text`.
+fn strip_common_indent(text: &str) -> String {
+    let leading_whitespace = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let common_indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(leading_whitespace)
+        .min()
+        .unwrap_or(0);
+    if common_indent == 0 {
+        return text.to_string();
+    }
+
+    let mut stripped = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        stripped.push_str(&line[common_indent.min(leading_whitespace(line))..]);
+    }
+    stripped
+}
+
+/// The cases [`Editor::cycle_case`] goes through, in order. They all keep identifiers
+/// free of spaces, so that each step still produces valid code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WordCase {
+    /// `snake_case`
+    Snake,
+    /// `UPPER_SNAKE_CASE`
+    UpperSnake,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `kebab-case`
+    Kebab,
+}
+
+impl WordCase {
+    const ALL: [Self; 5] = [
+        Self::Snake,
+        Self::UpperSnake,
+        Self::Pascal,
+        Self::Camel,
+        Self::Kebab,
+    ];
+
+    /// The first case that leaves the text unchanged, if any. Single words match several cases,
+    /// such as `word` being both snake_case and camelCase, in which case the earliest one wins.
+    fn detect(text: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|case| transform_to_case(text, *case) == text)
+    }
+
+    fn next(self) -> Self {
+        let ix = Self::ALL.iter().position(|case| *case == self).unwrap_or(0);
+        Self::ALL[(ix + 1) % Self::ALL.len()]
+    }
+}
+
+impl From<WordCase> for Case {
+    fn from(case: WordCase) -> Self {
+        match case {
+            WordCase::Snake => Case::Snake,
+            WordCase::UpperSnake => Case::UpperSnake,
+            WordCase::Pascal => Case::Pascal,
+            WordCase::Camel => Case::Camel,
+            WordCase::Kebab => Case::Kebab,
+        }
+    }
+}
+
+fn transform_to_case(text: &str, case: WordCase) -> String {
+    text.to_case(case.into())
+}
+
+#[test]
+fn test_transform_to_case() {
+    assert_eq!(
+        transform_to_case("fooBar_baz-qux", WordCase::Snake),
+        "foo_bar_baz_qux"
+    );
+
+    let text = "parseHttpRequest";
+    assert_eq!(
+        transform_to_case(text, WordCase::Snake),
+        "parse_http_request"
+    );
+    assert_eq!(
+        transform_to_case(text, WordCase::UpperSnake),
+        "PARSE_HTTP_REQUEST"
+    );
+    assert_eq!(
+        transform_to_case(text, WordCase::Pascal),
+        "ParseHttpRequest"
+    );
+    assert_eq!(transform_to_case(text, WordCase::Camel), "parseHttpRequest");
+    assert_eq!(
+        transform_to_case(text, WordCase::Kebab),
+        "parse-http-request"
+    );
+
+    assert_eq!(
+        WordCase::detect("PARSE_HTTP_REQUEST"),
+        Some(WordCase::UpperSnake)
+    );
+    assert_eq!(WordCase::detect("ParseHttpRequest"), Some(WordCase::Pascal));
+    assert_eq!(WordCase::detect("word"), Some(WordCase::Snake));
+    assert_eq!(WordCase::detect("Parse_http"), None);
+    assert_eq!(WordCase::Kebab.next(), WordCase::Snake);
+}
+
 fn ending_row(next_selection: &Selection<Point>, display_map: &DisplaySnapshot) -> MultiBufferRow {
     if next_selection.end.column > 0 || next_selection.is_empty() {
         MultiBufferRow(display_map.next_line_boundary(next_selection.end).0.row + 1)
@@ -14439,7 +14780,7 @@ impl ViewInputHandler for Editor {
                         underline: Some(UnderlineStyle {
                             thickness: px(1.),
                             color: None,
-                            wavy: false,
+                            pattern: UnderlinePattern::dashed(px(3.)),
                         }),
                         ..Default::default()
                     },
@@ -346,6 +346,27 @@ fn test_ime_composition(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_ime_pre_edit_text(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+    let mut cx = EditorTestContext::new(cx).await;
+    cx.set_state("abˇc");
+    cx.run_until_parked();
+    assert_eq!(cx.update(|cx| cx.ime_pre_edit_text()), None);
+
+    cx.update_editor(|editor, cx| editor.replace_and_mark_text_in_range(None, "に", None, cx));
+    cx.run_until_parked();
+    assert_eq!(
+        cx.update(|cx| cx.ime_pre_edit_text()),
+        Some(SharedString::from("に"))
+    );
+
+    cx.update_editor(|editor, cx| editor.replace_text_in_range(None, "日", cx));
+    cx.run_until_parked();
+    assert_eq!(cx.update(|cx| cx.ime_pre_edit_text()), None);
+    cx.assert_editor_state("ab日ˇc");
+}
+
 #[gpui::test]
 fn test_selection_with_mouse(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -1194,6 +1215,74 @@ fn test_fold_at_level(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+fn test_fold_by_indent(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let view = cx.add_window(|cx| {
+        let buffer = MultiBuffer::build_simple(
+            &"
+                class Foo:
+                    def a():
+                        print(1)
+
+                        print(2)
+
+                    def b():
+                        print(3)
+
+                class Bar:
+                    pass
+            "
+            .unindent(),
+            cx,
+        );
+        build_editor(buffer.clone(), cx)
+    });
+
+    _ = view.update(cx, |view, cx| {
+        view.fold_by_indent(
+            &FoldByIndent {
+                max_indent: Some(1),
+            },
+            cx,
+        );
+        assert_eq!(
+            view.display_text(cx),
+            "
+                class Foo:
+                    def a():⋯
+
+                    def b():⋯
+
+                class Bar:
+                    pass
+            "
+            .unindent(),
+        );
+
+        view.unfold_all(&UnfoldAll, cx);
+        view.fold_by_indent(
+            &FoldByIndent {
+                max_indent: Some(0),
+            },
+            cx,
+        );
+        assert_eq!(
+            view.display_text(cx),
+            "
+                class Foo:⋯
+
+                class Bar:⋯
+            "
+            .unindent(),
+        );
+    });
+
+    // Without a level, as from the command palette, the action can still be built.
+    cx.update(|cx| assert!(cx.build_action("editor::FoldByIndent", None).is_ok()));
+}
+
 #[gpui::test]
 fn test_move_cursor(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -3372,6 +3461,44 @@ async fn test_custom_newlines_cause_no_false_positive_diffs(
     });
 }
 
+#[gpui::test]
+async fn test_sort_lines_per_selection(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let mut cx = EditorTestContext::new(cx).await;
+
+    // Each selection is sorted independently of the others.
+    cx.set_state(indoc! {"
+        «use c;
+        use a;
+        use b;ˇ»
+
+        «fn z() {}
+        fn x() {}ˇ»
+    "});
+    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive::default(), cx));
+    cx.assert_editor_state(indoc! {"
+        «use a;
+        use b;
+        use c;ˇ»
+
+        «fn x() {}
+        fn z() {}ˇ»
+    "});
+
+    cx.update_editor(|e, cx| {
+        e.sort_lines_case_sensitive(&SortLinesCaseSensitive { descending: true }, cx)
+    });
+    cx.assert_editor_state(indoc! {"
+        «use c;
+        use b;
+        use a;ˇ»
+
+        «fn z() {}
+        fn x() {}ˇ»
+    "});
+}
+
 #[gpui::test]
 async fn test_manipulate_lines_with_single_selection(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -3387,7 +3514,9 @@ async fn test_manipulate_lines_with_single_selection(cx: &mut TestAppContext) {
         Y
         Xˇ»
     "});
-    cx.update_editor(|e, cx| e.sort_lines_case_insensitive(&SortLinesCaseInsensitive, cx));
+    cx.update_editor(|e, cx| {
+        e.sort_lines_case_insensitive(&SortLinesCaseInsensitive::default(), cx)
+    });
     cx.assert_editor_state(indoc! {"
         «x
         X
@@ -3397,6 +3526,23 @@ async fn test_manipulate_lines_with_single_selection(cx: &mut TestAppContext) {
         Zˇ»
     "});
 
+    // Test sort_lines_case_insensitive() in descending order
+    cx.set_state(indoc! {"
+        «b
+        C
+        a
+        Bˇ»
+    "});
+    cx.update_editor(|e, cx| {
+        e.sort_lines_case_insensitive(&SortLinesCaseInsensitive { descending: true }, cx)
+    });
+    cx.assert_editor_state(indoc! {"
+        «C
+        B
+        b
+        aˇ»
+    "});
+
     // Test reverse_lines()
     cx.set_state(indoc! {"
         «5
@@ -3426,7 +3572,7 @@ async fn test_manipulate_lines_with_single_selection(cx: &mut TestAppContext) {
         bb
         a
     "});
-    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive, cx));
+    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive::default(), cx));
     cx.assert_editor_state(indoc! {"
         «ddddˇ»
         ccc
@@ -3443,7 +3589,7 @@ async fn test_manipulate_lines_with_single_selection(cx: &mut TestAppContext) {
         bb
         aaaaaˇ»
     "});
-    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive, cx));
+    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive::default(), cx));
     cx.assert_editor_state(indoc! {"
         «aaaaa
         bb
@@ -3461,7 +3607,7 @@ async fn test_manipulate_lines_with_single_selection(cx: &mut TestAppContext) {
 
         ˇ»
     "});
-    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive, cx));
+    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive::default(), cx));
     cx.assert_editor_state(indoc! {"
         «
 
@@ -3606,7 +3752,7 @@ async fn test_manipulate_lines_with_multi_selection(cx: &mut TestAppContext) {
         bb
         aaaˇ»aa
     "});
-    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive, cx));
+    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive::default(), cx));
     cx.assert_editor_state(indoc! {"
         «aaaaa
         bb
@@ -3627,7 +3773,7 @@ async fn test_manipulate_lines_with_multi_selection(cx: &mut TestAppContext) {
         bb
         aaaˇ»aa
     "});
-    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive, cx));
+    cx.update_editor(|e, cx| e.sort_lines_case_sensitive(&SortLinesCaseSensitive::default(), cx));
     cx.assert_editor_state(indoc! {"
         «1
         2
@@ -3680,6 +3826,32 @@ async fn test_manipulate_lines_with_multi_selection(cx: &mut TestAppContext) {
     "});
 }
 
+#[gpui::test]
+async fn test_cycle_case(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let mut cx = EditorTestContext::new(cx).await;
+    cx.set_state("let parse_ˇhttp_request = 1;");
+
+    for expected in [
+        "let «PARSE_HTTP_REQUESTˇ» = 1;",
+        "let «ParseHttpRequestˇ» = 1;",
+        "let «parseHttpRequestˇ» = 1;",
+        "let «parse-http-requestˇ» = 1;",
+        "let «parse_http_requestˇ» = 1;",
+    ] {
+        cx.update_editor(|e, cx| e.cycle_case(&CycleCase, cx));
+        cx.assert_editor_state(expected);
+    }
+
+    // A single word only has distinct snake_case, UPPER_SNAKE_CASE, and PascalCase forms.
+    cx.set_state("fn ˇmain() {}");
+    for expected in ["fn «MAINˇ»() {}", "fn «Mainˇ»() {}", "fn «mainˇ»() {}"] {
+        cx.update_editor(|e, cx| e.cycle_case(&CycleCase, cx));
+        cx.assert_editor_state(expected);
+    }
+}
+
 #[gpui::test]
 async fn test_manipulate_text(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -3794,6 +3966,128 @@ async fn test_manipulate_text(cx: &mut TestAppContext) {
     "});
 }
 
+#[gpui::test]
+async fn test_multi_cursor_pattern_edit(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let mut cx = EditorTestContext::new(cx).await;
+
+    // Empty selections transform the word they are in, and capture groups can be referenced.
+    cx.set_state(indoc! {"
+        let ˇitem_1 = item_2ˇ + ˇitem_30;
+    "});
+    cx.update_editor(|e, cx| {
+        e.multi_cursor_pattern_edit(
+            &MultiCursorPatternEdit {
+                pattern: r"(\w+)_(\d+)".into(),
+                replacement: "${1}s[$2]".into(),
+            },
+            cx,
+        )
+    });
+    cx.assert_editor_state(indoc! {"
+        let «items[1]ˇ» = «items[2]ˇ» + «items[30]ˇ»;
+    "});
+
+    // Only the first match within each selection is replaced, and an invalid
+    // pattern leaves the text unchanged.
+    cx.set_state(indoc! {"
+        «a-b-cˇ»
+        «d-eˇ»
+    "});
+    cx.update_editor(|e, cx| {
+        e.multi_cursor_pattern_edit(
+            &MultiCursorPatternEdit {
+                pattern: "-".into(),
+                replacement: "+".into(),
+            },
+            cx,
+        );
+        e.multi_cursor_pattern_edit(
+            &MultiCursorPatternEdit {
+                pattern: "(".into(),
+                replacement: "".into(),
+            },
+            cx,
+        );
+    });
+    cx.assert_editor_state(indoc! {"
+        «a+b-cˇ»
+        «d+eˇ»
+    "});
+
+    // Without a pattern, as from the command palette, the action can still be built.
+    cx.update(|cx| {
+        assert!(cx
+            .build_action("editor::TransformSelectionsWithRegex", None)
+            .is_ok());
+        assert!(cx
+            .build_action(
+                "editor::TransformSelectionsWithRegex",
+                Some(json!({ "pattern": "-" })),
+            )
+            .is_ok());
+    });
+}
+
+#[gpui::test]
+async fn test_prompting_for_action_arguments(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let fs = FakeFs::new(cx.executor());
+    let project = Project::test(fs, [], cx).await;
+    let workspace = cx.add_window(|cx| Workspace::test_new(project, cx));
+    let editor = workspace
+        .update(cx, |workspace, cx| {
+            let buffer = MultiBuffer::build_simple("fn a() {\n    let item_1 = 1;\n}\n", cx);
+            let editor = cx.new_view(|cx| build_editor(buffer, cx));
+            workspace.add_item_to_active_pane(Box::new(editor.clone()), None, true, cx);
+            editor
+        })
+        .unwrap();
+    let cx = &mut VisualTestContext::from_window(*workspace.deref(), cx);
+    let has_prompt = |cx: &mut VisualTestContext| {
+        workspace
+            .update(cx, |workspace, cx| {
+                workspace.active_modal::<InputPrompt>(cx).is_some()
+            })
+            .unwrap()
+    };
+
+    // The pattern and replacement are asked for in turn.
+    editor.update(cx, |editor, cx| {
+        editor.change_selections(None, cx, |s| s.select_ranges([17..17]));
+        editor.multi_cursor_pattern_edit(&MultiCursorPatternEdit::default(), cx);
+    });
+    cx.run_until_parked();
+    assert!(has_prompt(cx));
+    cx.simulate_input(r"(\w+)_(\d+)");
+    cx.dispatch_action(menu::Confirm);
+    assert!(has_prompt(cx));
+    cx.simulate_input("${1}s[$2]");
+    cx.dispatch_action(menu::Confirm);
+    cx.run_until_parked();
+    assert!(!has_prompt(cx));
+    assert_eq!(
+        editor.update(cx, |editor, cx| editor.text(cx)),
+        "fn a() {\n    let items[1] = 1;\n}\n"
+    );
+
+    editor.update(cx, |editor, cx| {
+        editor.fold_by_indent(&FoldByIndent::default(), cx)
+    });
+    cx.run_until_parked();
+    assert!(has_prompt(cx));
+    cx.simulate_input("0");
+    cx.dispatch_action(menu::Confirm);
+    cx.run_until_parked();
+    assert!(!has_prompt(cx));
+    assert_eq!(
+        editor.update(cx, |editor, cx| editor.display_text(cx)),
+        "fn a() {⋯\n}\n"
+    );
+}
+
 #[gpui::test]
 fn test_duplicate_line(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -4697,6 +4991,54 @@ async fn test_paste_multiline(cx: &mut gpui::TestAppContext) {
     "});
 }
 
+#[gpui::test]
+async fn test_paste_and_indent(cx: &mut gpui::TestAppContext) {
+    init_test(cx, |settings| {
+        settings.defaults.auto_indent_on_paste = Some(false);
+    });
+
+    let mut cx = EditorTestContext::new(cx).await;
+    let language = Arc::new(Language::new(
+        LanguageConfig::default(),
+        Some(tree_sitter_rust::LANGUAGE.into()),
+    ));
+    cx.update_buffer(|buffer, cx| buffer.set_language(Some(language), cx));
+
+    // Text copied from another application, with no indentation metadata.
+    let clipboard_text = "        d(\n            e\n        )";
+
+    // A regular paste keeps the copied indentation.
+    cx.set_state(indoc! {"
+        const a: B = (
+            ˇ
+        );
+    "});
+    cx.update(|cx| cx.write_to_clipboard(gpui::ClipboardItem::new_string(clipboard_text.into())));
+    cx.update_editor(|e, cx| e.paste(&Paste, cx));
+    cx.assert_editor_state(indoc! {"
+        const a: B = (
+                    d(
+                        e
+                    )ˇ
+        );
+    "});
+
+    // Pasting and indenting strips the common indentation and indents relative to the cursor.
+    cx.set_state(indoc! {"
+        const a: B = (
+            ˇ
+        );
+    "});
+    cx.update_editor(|e, cx| e.paste_and_indent(&PasteAndIndent, cx));
+    cx.assert_editor_state(indoc! {"
+        const a: B = (
+            d(
+                e
+            )ˇ
+        );
+    "});
+}
+
 #[gpui::test]
 fn test_select_all(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
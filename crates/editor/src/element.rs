@@ -197,6 +197,7 @@ impl EditorElement {
         register_action(view, cx, Editor::join_lines);
         register_action(view, cx, Editor::sort_lines_case_sensitive);
         register_action(view, cx, Editor::sort_lines_case_insensitive);
+        register_action(view, cx, Editor::multi_cursor_pattern_edit);
         register_action(view, cx, Editor::reverse_lines);
         register_action(view, cx, Editor::shuffle_lines);
         register_action(view, cx, Editor::convert_to_upper_case);
@@ -207,6 +208,7 @@ impl EditorElement {
         register_action(view, cx, Editor::convert_to_upper_camel_case);
         register_action(view, cx, Editor::convert_to_lower_camel_case);
         register_action(view, cx, Editor::convert_to_opposite_case);
+        register_action(view, cx, Editor::cycle_case);
         register_action(view, cx, Editor::delete_to_previous_word_start);
         register_action(view, cx, Editor::delete_to_previous_subword_start);
         register_action(view, cx, Editor::delete_to_next_word_end);
@@ -226,6 +228,7 @@ impl EditorElement {
         register_action(view, cx, Editor::kill_ring_yank);
         register_action(view, cx, Editor::copy);
         register_action(view, cx, Editor::paste);
+        register_action(view, cx, Editor::paste_and_indent);
         register_action(view, cx, Editor::undo);
         register_action(view, cx, Editor::redo);
         register_action(view, cx, Editor::move_page_up);
@@ -345,6 +348,7 @@ impl EditorElement {
         register_action(view, cx, Editor::open_file);
         register_action(view, cx, Editor::fold);
         register_action(view, cx, Editor::fold_at_level);
+        register_action(view, cx, Editor::fold_by_indent);
         register_action(view, cx, Editor::fold_all);
         register_action(view, cx, Editor::fold_function_bodies);
         register_action(view, cx, Editor::fold_at);
@@ -366,6 +370,7 @@ impl EditorElement {
         register_action(view, cx, Editor::toggle_line_numbers);
         register_action(view, cx, Editor::toggle_relative_line_numbers);
         register_action(view, cx, Editor::toggle_indent_guides);
+        register_action(view, cx, Editor::toggle_show_whitespace);
         register_action(view, cx, Editor::toggle_inlay_hints);
         register_action(view, cx, Editor::toggle_inline_completions);
         register_action(view, cx, hover_popover::hover);
@@ -1138,6 +1143,8 @@ impl EditorElement {
                                             background_color: None,
                                             strikethrough: None,
                                             underline: None,
+                                            letter_spacing: None,
+                                            word_spacing: None,
                                         }],
                                     )
                                     .log_err()
@@ -2187,6 +2194,8 @@ impl EditorElement {
                         background_color: None,
                         underline: Default::default(),
                         strikethrough: None,
+                        letter_spacing: None,
+                        word_spacing: None,
                     };
                     cx.text_system()
                         .shape_line(line.to_string().into(), font_size, &[run])
@@ -4259,13 +4268,7 @@ impl EditorElement {
         layout: &mut EditorLayout,
         cx: &mut WindowContext,
     ) {
-        let whitespace_setting = self
-            .editor
-            .read(cx)
-            .buffer
-            .read(cx)
-            .settings_at(0, cx)
-            .show_whitespaces;
+        let whitespace_setting = self.editor.read(cx).show_whitespaces(cx);
 
         for (ix, line_with_invisibles) in layout.position_map.line_layouts.iter().enumerate() {
             let row = DisplayRow(layout.visible_display_row_range.start.0 + ix as u32);
@@ -5082,6 +5085,8 @@ impl EditorElement {
                     background_color: None,
                     underline: None,
                     strikethrough: None,
+                    letter_spacing: None,
+                    word_spacing: None,
                 }],
             )
             .unwrap();
@@ -5107,6 +5112,8 @@ impl EditorElement {
             background_color: None,
             underline: None,
             strikethrough: None,
+            letter_spacing: None,
+            word_spacing: None,
         };
         cx.text_system().shape_line(
             text,
@@ -5501,6 +5508,8 @@ impl LineWithInvisibles {
                             background_color: text_style.background_color,
                             underline: text_style.underline,
                             strikethrough: text_style.strikethrough,
+                            letter_spacing: text_style.letter_spacing,
+                            word_spacing: text_style.word_spacing,
                         };
                         let line_layout = cx
                             .text_system()
@@ -5523,6 +5532,13 @@ impl LineWithInvisibles {
                         width += shaped_line.width;
                         len += shaped_line.len;
                         fragments.push(LineFragment::Text(shaped_line));
+                        // Soft wraps break display rows too, but only a real line break
+                        // (the next row starting a new buffer row) gets a newline marker.
+                        if editor_mode == EditorMode::Full && !is_row_soft_wrapped(row + 1) {
+                            invisibles.push(Invisible::Newline {
+                                line_offset: line.len(),
+                            });
+                        }
                         layouts.push(Self {
                             width: mem::take(&mut width),
                             len: mem::take(&mut len),
@@ -5564,6 +5580,8 @@ impl LineWithInvisibles {
                             background_color: text_style.background_color,
                             underline: text_style.underline,
                             strikethrough: text_style.strikethrough,
+                            letter_spacing: text_style.letter_spacing,
+                            word_spacing: text_style.word_spacing,
                         });
 
                         if editor_mode == EditorMode::Full {
@@ -5699,6 +5717,9 @@ impl LineWithInvisibles {
                 Invisible::Whitespace { line_offset } => {
                     (*line_offset, line_offset + 1, &layout.space_invisible)
                 }
+                Invisible::Newline { line_offset } => {
+                    (*line_offset, line_offset + 1, &layout.newline_invisible)
+                }
             };
 
             let x_offset = self.x_for_index(token_offset);
@@ -5746,6 +5767,11 @@ impl LineWithInvisibles {
                 for (([start, end], paint), invisible) in
                     invisible_iter.zip_eq(self.invisibles.iter())
                 {
+                    // Line breaks are never a boundary between words.
+                    if matches!(invisible, Invisible::Newline { .. }) {
+                        continue;
+                    }
+
                     let should_render = match (&last_seen, invisible) {
                         (_, Invisible::Tab { .. }) => true,
                         (Some((_, last_end, _)), _) => *last_end == start,
@@ -5878,6 +5904,10 @@ enum Invisible {
     Whitespace {
         line_offset: usize,
     },
+    /// The end of a buffer line, drawn right after its last character.
+    Newline {
+        line_offset: usize,
+    },
 }
 
 impl EditorElement {
@@ -6727,6 +6757,8 @@ impl Element for EditorElement {
                                 background_color: None,
                                 underline: None,
                                 strikethrough: None,
+                                letter_spacing: None,
+                                word_spacing: None,
                             }],
                         )
                         .unwrap();
@@ -6742,6 +6774,25 @@ impl Element for EditorElement {
                                 background_color: None,
                                 underline: None,
                                 strikethrough: None,
+                                letter_spacing: None,
+                                word_spacing: None,
+                            }],
+                        )
+                        .unwrap();
+                    let newline_invisible = cx
+                        .text_system()
+                        .shape_line(
+                            "↵".into(),
+                            invisible_symbol_font_size,
+                            &[TextRun {
+                                len: "↵".len(),
+                                font: self.style.text.font(),
+                                color: cx.theme().colors().editor_invisible,
+                                background_color: None,
+                                underline: None,
+                                strikethrough: None,
+                                letter_spacing: None,
+                                word_spacing: None,
                             }],
                         )
                         .unwrap();
@@ -6788,6 +6839,7 @@ impl Element for EditorElement {
                         crease_trailers,
                         tab_invisible,
                         space_invisible,
+                        newline_invisible,
                         sticky_buffer_header,
                     }
                 })
@@ -6983,6 +7035,7 @@ pub struct EditorLayout {
     mouse_context_menu: Option<AnyElement>,
     tab_invisible: ShapedLine,
     space_invisible: ShapedLine,
+    newline_invisible: ShapedLine,
     sticky_buffer_header: Option<AnyElement>,
 }
 
@@ -7594,7 +7647,7 @@ mod tests {
     use crate::{
         display_map::{BlockPlacement, BlockProperties},
         editor_tests::{init_test, update_test_language_settings},
-        Editor, MultiBuffer,
+        Editor, MultiBuffer, ToggleShowWhitespace,
     };
     use gpui::{TestAppContext, VisualTestContext};
     use language::language_settings;
@@ -7969,6 +8022,48 @@ mod tests {
         }
     }
 
+    #[gpui::test]
+    fn test_newline_invisibles_drawing(cx: &mut TestAppContext) {
+        init_test(cx, |s| {
+            s.defaults.show_whitespaces = Some(ShowWhitespaceSetting::All);
+        });
+
+        let invisibles =
+            collect_invisibles_from_new_editor(cx, EditorMode::Full, "a b\n\ncd", px(500.0), true);
+        assert_eq!(
+            invisibles,
+            vec![
+                Invisible::Whitespace { line_offset: 1 },
+                Invisible::Newline { line_offset: 3 },
+                Invisible::Newline { line_offset: 0 },
+            ]
+        );
+    }
+
+    #[gpui::test]
+    fn test_toggle_show_whitespace(cx: &mut TestAppContext) {
+        init_test(cx, |s| {
+            s.defaults.show_whitespaces = Some(ShowWhitespaceSetting::Selection);
+        });
+
+        let window = cx.add_window(|cx| {
+            let buffer = MultiBuffer::build_simple("a b", cx);
+            Editor::new(EditorMode::Full, buffer, None, true, cx)
+        });
+        window
+            .update(cx, |editor, cx| {
+                assert_eq!(
+                    editor.show_whitespaces(cx),
+                    ShowWhitespaceSetting::Selection
+                );
+                editor.toggle_show_whitespace(&ToggleShowWhitespace, cx);
+                assert_eq!(editor.show_whitespaces(cx), ShowWhitespaceSetting::None);
+                editor.toggle_show_whitespace(&ToggleShowWhitespace, cx);
+                assert_eq!(editor.show_whitespaces(cx), ShowWhitespaceSetting::All);
+            })
+            .unwrap();
+    }
+
     #[gpui::test]
     fn test_wrapped_invisibles_drawing(cx: &mut TestAppContext) {
         let tab_size = 4;
@@ -363,7 +363,7 @@ fn show_hover(
                                 underline: Some(gpui::UnderlineStyle {
                                     thickness: px(1.),
                                     color: Some(cx.theme().colors().editor_foreground),
-                                    wavy: false,
+                                    ..Default::default()
                                 }),
                                 ..Default::default()
                             },
@@ -569,7 +569,7 @@ async fn parse_blocks(
                     underline: Some(gpui::UnderlineStyle {
                         thickness: px(1.),
                         color: Some(cx.theme().colors().editor_foreground),
-                        wavy: false,
+                        ..Default::default()
                     }),
                     ..Default::default()
                 },
@@ -0,0 +1,113 @@
+use gpui::{
+    AppContext, DismissEvent, EventEmitter, FocusHandle, FocusableView, View, ViewContext, WeakView,
+};
+use ui::prelude::*;
+use workspace::ModalView;
+
+use crate::Editor;
+
+type OnConfirm = Box<dyn FnOnce(Vec<String>, &mut Editor, &mut ViewContext<Editor>)>;
+
+/// A modal that asks for the arguments of an editor action run without them, such as
+/// from the command palette. Confirming an input moves on to the next one, and confirming
+/// the last one runs the action on the editor with the text of every input.
+pub(crate) struct InputPrompt {
+    title: SharedString,
+    inputs: Vec<View<Editor>>,
+    active_editor: WeakView<Editor>,
+    on_confirm: Option<OnConfirm>,
+}
+
+impl ModalView for InputPrompt {}
+
+impl EventEmitter<DismissEvent> for InputPrompt {}
+
+impl FocusableView for InputPrompt {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.inputs[0].focus_handle(cx)
+    }
+}
+
+impl InputPrompt {
+    pub(crate) fn new(
+        title: impl Into<SharedString>,
+        placeholders: &[&str],
+        active_editor: WeakView<Editor>,
+        on_confirm: impl FnOnce(Vec<String>, &mut Editor, &mut ViewContext<Editor>) + 'static,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let inputs = placeholders
+            .iter()
+            .map(|placeholder| {
+                cx.new_view(|cx| {
+                    let mut input = Editor::single_line(cx);
+                    input.set_placeholder_text(*placeholder, cx);
+                    input
+                })
+            })
+            .collect();
+
+        Self {
+            title: title.into(),
+            inputs,
+            active_editor,
+            on_confirm: Some(Box::new(on_confirm)),
+        }
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        cx.emit(DismissEvent);
+    }
+
+    fn confirm(&mut self, _: &menu::Confirm, cx: &mut ViewContext<Self>) {
+        let focused_ix = self
+            .inputs
+            .iter()
+            .position(|input| input.focus_handle(cx).is_focused(cx))
+            .unwrap_or(0);
+        if let Some(next_input) = self.inputs.get(focused_ix + 1) {
+            next_input.focus_handle(cx).focus(cx);
+            return;
+        }
+
+        let values = self
+            .inputs
+            .iter()
+            .map(|input| input.read(cx).text(cx))
+            .collect();
+        if let (Some(on_confirm), Some(editor)) =
+            (self.on_confirm.take(), self.active_editor.upgrade())
+        {
+            editor.update(cx, |editor, cx| {
+                editor.focus(cx);
+                on_confirm(values, editor, cx);
+            });
+        }
+        cx.emit(DismissEvent);
+    }
+}
+
+impl Render for InputPrompt {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .w(rems(24.))
+            .elevation_2(cx)
+            .key_context("InputPrompt")
+            .on_action(cx.listener(Self::cancel))
+            .on_action(cx.listener(Self::confirm))
+            .child(
+                h_flex()
+                    .px_2()
+                    .py_1()
+                    .child(Label::new(self.title.clone()).color(Color::Muted)),
+            )
+            .children(self.inputs.iter().map(|input| {
+                div()
+                    .border_t_1()
+                    .border_color(cx.theme().colors().border_variant)
+                    .px_2()
+                    .py_1()
+                    .child(input.clone())
+            }))
+    }
+}
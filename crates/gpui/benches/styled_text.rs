@@ -0,0 +1,36 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use gpui::{div, point, prelude::*, px, size, AvailableSpace, SharedString, TestAppContext};
+
+const LABEL_COUNT: usize = 1000;
+
+fn render_labels(c: &mut Criterion) {
+    let mut cx = TestAppContext::single();
+    let cx = cx.add_empty_window();
+    let space = size(
+        AvailableSpace::Definite(px(800.)),
+        AvailableSpace::MinContent,
+    );
+
+    let identical_labels: Vec<SharedString> = vec!["loading…".into(); LABEL_COUNT];
+    let distinct_labels: Vec<SharedString> = (0..LABEL_COUNT)
+        .map(|ix| format!("loading {ix}…").into())
+        .collect();
+
+    let mut group = c.benchmark_group("render labels");
+    for (name, labels) in [
+        ("identical", &identical_labels),
+        ("distinct", &distinct_labels),
+    ] {
+        group.bench_with_input(BenchmarkId::from_parameter(name), labels, |b, labels| {
+            b.iter(|| {
+                cx.draw(point(px(0.), px(0.)), space, |_| {
+                    div().children(labels.iter().cloned())
+                });
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, render_labels);
+criterion_main!(benches);
@@ -411,6 +411,8 @@ impl Element for TextElement {
             background_color: None,
             underline: None,
             strikethrough: None,
+            letter_spacing: None,
+            word_spacing: None,
         };
         let runs = if let Some(marked_range) = input.marked_range.as_ref() {
             vec![
@@ -423,7 +425,7 @@ impl Element for TextElement {
                     underline: Some(UnderlineStyle {
                         color: Some(run.color),
                         thickness: px(1.0),
-                        wavy: false,
+                        ..Default::default()
                     }),
                     ..run.clone()
                 },
@@ -32,14 +32,15 @@ pub use test_context::*;
 use util::ResultExt;
 
 use crate::{
-    current_platform, hash, init_app_menus, Action, ActionRegistry, Any, AnyView, AnyWindowHandle,
-    Asset, AssetSource, BackgroundExecutor, Bounds, ClipboardItem, Context, DispatchPhase,
-    DisplayId, Entity, EventEmitter, FocusHandle, FocusId, ForegroundExecutor, Global, KeyBinding,
-    Keymap, Keystroke, LayoutId, Menu, MenuItem, OwnedMenu, PathPromptOptions, Pixels, Platform,
-    PlatformDisplay, Point, PromptBuilder, PromptHandle, PromptLevel, Render,
-    RenderablePromptHandle, Reservation, ScreenCaptureSource, SharedString, SubscriberSet,
-    Subscription, SvgRenderer, Task, TextSystem, View, ViewContext, Window, WindowAppearance,
-    WindowContext, WindowHandle, WindowId,
+    current_platform, hash, init_app_menus, Action, ActionRegistry, Any, AnyElement, AnyView,
+    AnyWindowHandle, Asset, AssetSource, BackgroundExecutor, Bounds, ClipboardItem, Context,
+    DispatchPhase, DisplayId, Entity, EventEmitter, FocusHandle, FocusId, ForegroundExecutor,
+    Global, KeyBinding, Keymap, Keystroke, LayoutId, Menu, MenuItem, Notification, NotificationId,
+    NotificationRenderer, OwnedMenu, PathPromptOptions, Pixels, Platform, PlatformDisplay, Point,
+    PromptBuilder, PromptHandle, PromptLevel, Render, RenderablePromptHandle, Reservation,
+    SaveDialogOptions, ScreenCaptureSource, SharedString, SubscriberSet, Subscription, SvgRenderer,
+    Task, TextSystem, View, ViewContext, Window, WindowAppearance, WindowContext, WindowHandle,
+    WindowId,
 };
 
 mod async_context;
@@ -260,12 +261,15 @@ pub struct AppContext {
     pub(crate) event_listeners: SubscriberSet<EntityId, (TypeId, Listener)>,
     pub(crate) keystroke_observers: SubscriberSet<(), KeystrokeObserver>,
     pub(crate) keyboard_layout_observers: SubscriberSet<(), Handler>,
+    pub(crate) global_hotkeys: Rc<RefCell<FxHashMap<u32, Box<dyn Action>>>>,
+    next_global_hotkey_id: u32,
     pub(crate) release_listeners: SubscriberSet<EntityId, ReleaseListener>,
     pub(crate) global_observers: SubscriberSet<TypeId, Handler>,
     pub(crate) quit_observers: SubscriberSet<(), QuitHandler>,
     pub(crate) layout_id_buffer: Vec<LayoutId>, // We recycle this memory across layout requests.
     pub(crate) propagate_event: bool,
     pub(crate) prompt_builder: Option<PromptBuilder>,
+    pub(crate) notification_renderer: Option<NotificationRenderer>,
 
     #[cfg(any(test, feature = "test-support", debug_assertions))]
     pub(crate) name: Option<&'static str>,
@@ -322,11 +326,14 @@ impl AppContext {
                 release_listeners: SubscriberSet::new(),
                 keystroke_observers: SubscriberSet::new(),
                 keyboard_layout_observers: SubscriberSet::new(),
+                global_hotkeys: Default::default(),
+                next_global_hotkey_id: 0,
                 global_observers: SubscriberSet::new(),
                 quit_observers: SubscriberSet::new(),
                 layout_id_buffer: Default::default(),
                 propagate_event: true,
                 prompt_builder: Some(PromptBuilder::Default),
+                notification_renderer: None,
 
                 #[cfg(any(test, feature = "test-support", debug_assertions))]
                 name: None,
@@ -348,6 +355,15 @@ impl AppContext {
             }
         }));
 
+        platform.on_global_hotkey(Box::new({
+            let app = Rc::downgrade(&app);
+            move |id| {
+                if let Some(app) = app.upgrade() {
+                    app.borrow_mut().dispatch_global_hotkey(id);
+                }
+            }
+        }));
+
         platform.on_quit(Box::new({
             let cx = app.clone();
             move || {
@@ -402,6 +418,52 @@ impl AppContext {
         subscription
     }
 
+    /// Registers a system-wide keyboard shortcut that dispatches the given action to the
+    /// frontmost window, even while another application has focus.
+    /// The hotkey is unregistered when the returned subscription is dropped.
+    pub fn set_global_hotkey(
+        &mut self,
+        hotkey: Keystroke,
+        action: Box<dyn Action>,
+    ) -> Result<Subscription> {
+        let id = self.next_global_hotkey_id;
+        self.next_global_hotkey_id += 1;
+        self.platform.register_global_hotkey(id, &hotkey)?;
+        self.global_hotkeys.borrow_mut().insert(id, action);
+
+        let platform = self.platform.clone();
+        let global_hotkeys = self.global_hotkeys.clone();
+        Ok(Subscription::new(move || {
+            platform.unregister_global_hotkey(id);
+            global_hotkeys.borrow_mut().remove(&id);
+        }))
+    }
+
+    fn dispatch_global_hotkey(&mut self, id: u32) {
+        let Some(action) = self
+            .global_hotkeys
+            .borrow()
+            .get(&id)
+            .map(|action| action.boxed_clone())
+        else {
+            return;
+        };
+
+        let window = self
+            .window_stack()
+            .and_then(|windows| windows.first().copied())
+            .or_else(|| self.active_window())
+            .or_else(|| self.windows().first().copied());
+        if let Some(window) = window {
+            window
+                .update(self, |_, cx| {
+                    cx.activate_window();
+                    cx.dispatch_action(action);
+                })
+                .log_err();
+        }
+    }
+
     /// Gracefully quit the application via the platform's standard routine.
     pub fn quit(&self) {
         self.platform.quit();
@@ -730,6 +792,17 @@ impl AppContext {
         self.platform.prompt_for_new_path(directory)
     }
 
+    /// Displays a platform modal for choosing where to save a file, configured by the given [`SaveDialogOptions`].
+    /// When a path is selected, it is relayed asynchronously via the returned oneshot channel.
+    /// If cancelled, a `None` will be relayed instead.
+    /// May return an error on Linux if the file picker couldn't be opened.
+    pub fn prompt_for_save_path(
+        &self,
+        options: SaveDialogOptions,
+    ) -> oneshot::Receiver<Result<Option<PathBuf>>> {
+        self.platform.prompt_for_save_path(options)
+    }
+
     /// Reveals the specified path at the platform level, such as in Finder on macOS.
     pub fn reveal_path(&self, path: &Path) {
         self.platform.reveal_path(path)
@@ -948,6 +1021,14 @@ impl AppContext {
         self.foreground_executor.spawn(f(self.to_async()))
     }
 
+    /// Runs the given function on the main thread, on a later turn of the run loop rather than
+    /// in the caller's stack frame. This is the place for synchronous platform APIs that may
+    /// only be called from the main thread. As the function never leaves the main thread,
+    /// neither it nor its result need to be `Send`.
+    pub fn spawn_on_main_thread<T: 'static>(&self, f: impl FnOnce() -> T + 'static) -> Task<T> {
+        self.foreground_executor.spawn(async move { f() })
+    }
+
     /// Schedules the given function to be run at the end of the current effect cycle, allowing entities
     /// that are currently on the stack to be returned to the app.
     pub fn defer(&mut self, f: impl FnOnce(&mut AppContext) + 'static) {
@@ -1289,6 +1370,15 @@ impl AppContext {
         self.platform.set_dock_menu(menus, &self.keymap.borrow());
     }
 
+    /// Shows the given count on the app icon in the dock, or clears it when `None` or zero.
+    /// This is currently only supported on macOS.
+    pub fn set_badge_count(&self, count: Option<usize>) {
+        let label = count
+            .filter(|count| *count > 0)
+            .map(|count| count.to_string());
+        self.platform.set_dock_badge(label.as_deref());
+    }
+
     /// Adds given path to the bottom of the list of recent paths for the application.
     /// The list is usually shown on the application icon's context menu in the dock,
     /// and allows to open the recent files via that context menu.
@@ -1379,6 +1469,16 @@ impl AppContext {
         self.prompt_builder = Some(PromptBuilder::Custom(Box::new(renderer)))
     }
 
+    /// Set the renderer for the notifications shown with [`WindowContext::push_notification`],
+    /// replacing GPUI's unstyled fallback. The renderer is called for each notification, and
+    /// is responsible for calling [`WindowContext::dismiss_notification`] when it is closed.
+    pub fn set_notification_renderer(
+        &mut self,
+        renderer: impl Fn(&Notification, NotificationId, &mut WindowContext) -> AnyElement + 'static,
+    ) {
+        self.notification_renderer = Some(Rc::new(renderer));
+    }
+
     /// Remove an asset from GPUI's cache
     pub fn remove_asset<A: Asset>(&mut self, source: &A::Source) {
         let asset_id = (TypeId::of::<A>(), hash(source));
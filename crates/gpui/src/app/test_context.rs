@@ -1,14 +1,16 @@
 use crate::{
-    Action, AnyView, AnyWindowHandle, AppCell, AppContext, AsyncAppContext, AvailableSpace,
-    BackgroundExecutor, BorrowAppContext, Bounds, ClipboardItem, Context, DrawPhase, Drawable,
-    Element, Empty, Entity, EventEmitter, ForegroundExecutor, Global, InputEvent, Keystroke, Model,
-    ModelContext, Modifiers, ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent,
-    MouseUpEvent, Pixels, Platform, Point, Render, Result, Size, Task, TestDispatcher,
-    TestPlatform, TestScreenCaptureSource, TestWindow, TextSystem, View, ViewContext,
-    VisualContext, WindowBounds, WindowContext, WindowHandle, WindowOptions,
+    px, size, Action, AnyView, AnyWindowHandle, AppCell, AppContext, AsyncAppContext,
+    AvailableSpace, BackgroundExecutor, BorrowAppContext, Bounds, ClipboardItem, Context,
+    DrawPhase, Drawable, Element, Empty, Entity, EventEmitter, ForegroundExecutor, Global,
+    InputEvent, IntoElement, KeyUpEvent, Keystroke, Model, ModelContext, Modifiers,
+    ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, Pixels,
+    Platform, PlatformInput, Point, Render, Result, Size, Task, TestDispatcher, TestPlatform,
+    TestScreenCaptureSource, TestWindow, TextSystem, View, ViewContext, VisualContext,
+    WindowBounds, WindowContext, WindowHandle, WindowOptions,
 };
 use anyhow::{anyhow, bail};
 use futures::{channel::oneshot, Stream, StreamExt};
+use rand::{rngs::StdRng, SeedableRng};
 use std::{cell::RefCell, future::Future, ops::Deref, rc::Rc, sync::Arc, time::Duration};
 
 /// A TestAppContext is provided to tests created with `#[gpui::test]`, it provides
@@ -119,6 +121,12 @@ impl TestAppContext {
         }
     }
 
+    /// Creates a standalone `TestAppContext` outside of `#[gpui::test]`, e.g. for benchmarks.
+    pub fn single() -> Self {
+        let dispatcher = TestDispatcher::new(StdRng::from_entropy());
+        Self::new(dispatcher, None)
+    }
+
     /// The name of the test function that created this `TestAppContext`
     pub fn test_function_name(&self) -> Option<&'static str> {
         self.fn_name
@@ -215,6 +223,18 @@ impl TestAppContext {
         cx
     }
 
+    /// Renders the element to a new window and asserts that the text of the lines
+    /// it paints, joined in the order they were painted, is equal to `expected`.
+    #[track_caller]
+    pub fn assert_element_text<E: IntoElement>(&mut self, element: E, expected: &str) {
+        let cx = self.add_empty_window();
+        cx.draw(Point::default(), size(px(1024.), px(768.)), |_| {
+            element.into_element()
+        });
+        let text = cx.update(|cx| cx.window.rendered_frame.painted_text.join(""));
+        assert_eq!(text, expected);
+    }
+
     /// Adds a new window, and returns its root view and a `VisualTestContext` which can be used
     /// as a `WindowContext` for the rest of the test. Typically you would shadow this context with
     /// the returned one. `let (view, cx) = cx.add_window_view(...);`
@@ -389,11 +409,21 @@ impl TestAppContext {
 
     /// simulate_input takes a string of text to type.
     /// cx.simulate_input("abc")
-    /// will type abc into your current editor
+    /// will type abc into your current editor, dispatching a key down and a key up
+    /// event for each unicode scalar value in the input.
     /// This will also run the background executor until it's parked.
     pub fn simulate_input(&mut self, window: AnyWindowHandle, input: &str) {
-        for keystroke in input.split("").map(Keystroke::parse).map(Result::unwrap) {
-            self.dispatch_keystroke(window, keystroke);
+        for ch in input.chars() {
+            let keystroke = Keystroke {
+                modifiers: Modifiers::default(),
+                key: ch.to_string(),
+                key_char: Some(ch.to_string()),
+            };
+            self.dispatch_keystroke(window, keystroke.clone());
+            self.update_window(window, |_, cx| {
+                cx.dispatch_event(PlatformInput::KeyUp(KeyUpEvent { keystroke }));
+            })
+            .unwrap();
         }
 
         self.background_executor.run_until_parked()
@@ -813,8 +843,7 @@ impl VisualTestContext {
     /// Simulate an event from the platform, e.g. a SrollWheelEvent
     /// Make sure you've called [VisualTestContext::draw] first!
     pub fn simulate_event<E: InputEvent>(&mut self, event: E) {
-        self.test_window(self.window)
-            .simulate_input(event.to_platform_input());
+        self.update(|cx| cx.emit_system_event(event));
         self.background_executor.run_until_parked();
     }
 
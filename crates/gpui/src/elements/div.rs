@@ -20,8 +20,8 @@ use crate::{
     ClickEvent, DispatchPhase, Element, ElementId, FocusHandle, Global, GlobalElementId, Hitbox,
     HitboxId, IntoElement, IsZero, KeyContext, KeyDownEvent, KeyUpEvent, LayoutId,
     ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    ParentElement, Pixels, Point, Render, ScrollWheelEvent, SharedString, Size, Style,
-    StyleRefinement, Styled, Task, TooltipId, View, Visibility, WindowContext,
+    ParentElement, Pixels, Point, PointerEvents, Render, ScrollWheelEvent, SharedString, Size,
+    Style, StyleRefinement, Styled, Task, TooltipId, View, Visibility, WindowContext,
 };
 use collections::HashMap;
 use refineable::Refineable;
@@ -413,6 +413,9 @@ impl Interactivity {
     /// Bind the given callback to drop events of the given type, whether or not the drag started on this element
     /// The imperative API equivalent to [`InteractiveElement::on_drop`]
     ///
+    /// Values are dragged from elements registered with [`StatefulInteractiveElement::on_drag`], and the
+    /// position of the drop is available from [`WindowContext::mouse_position`] within the callback.
+    ///
     /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
     pub fn on_drop<T: 'static>(&mut self, listener: impl Fn(&T, &mut WindowContext) + 'static) {
         self.drop_listeners.push((
@@ -866,6 +869,9 @@ pub trait InteractiveElement: Sized {
     /// Bind the given callback to drop events of the given type, whether or not the drag started on this element
     /// The fluent API equivalent to [`Interactivity::on_drop`]
     ///
+    /// Values are dragged from elements registered with [`StatefulInteractiveElement::on_drag`], and the
+    /// position of the drop is available from [`WindowContext::mouse_position`] within the callback.
+    ///
     /// See [`ViewContext::listener`](crate::ViewContext::listener) to get access to a view's state from this callback.
     fn on_drop<T: 'static>(mut self, listener: impl Fn(&T, &mut WindowContext) + 'static) -> Self {
         self.interactivity().on_drop(listener);
@@ -1427,6 +1433,10 @@ impl Interactivity {
     }
 
     fn should_insert_hitbox(&self, style: &Style) -> bool {
+        if style.pointer_events == PointerEvents::None {
+            return false;
+        }
+
         self.occlude_mouse
             || style.mouse_cursor.is_some()
             || self.group.is_some()
@@ -1,8 +1,14 @@
+use std::{collections::VecDeque, future::Future, sync::Arc};
+
 use crate::{
-    geometry::Negate as _, point, px, radians, size, Bounds, Element, GlobalElementId, Hitbox,
-    InteractiveElement, Interactivity, IntoElement, LayoutId, Pixels, Point, Radians, SharedString,
-    Size, StyleRefinement, Styled, TransformationMatrix, WindowContext,
+    geometry::Negate as _, point, px, radians, size, swap_rgba_pa_to_bgra, AppContext, Asset,
+    Bounds, Corners, DevicePixels, Element, Global, GlobalElementId, Hitbox, ImageCacheError,
+    InteractiveElement, Interactivity, IntoElement, IsZero, LayoutId, Pixels, Point, Radians,
+    RenderImage, SharedString, Size, StyleRefinement, Styled, SvgSize, TransformationMatrix,
+    WindowContext,
 };
+use image::{Frame, ImageBuffer};
+use smallvec::SmallVec;
 use util::ResultExt;
 
 /// An SVG element.
@@ -10,6 +16,7 @@ pub struct Svg {
     interactivity: Interactivity,
     transformation: Option<Transformation>,
     path: Option<SharedString>,
+    source: Option<SharedString>,
 }
 
 /// Create a new SVG element.
@@ -18,10 +25,24 @@ pub fn svg() -> Svg {
         interactivity: Interactivity::default(),
         transformation: None,
         path: None,
+        source: None,
     }
 }
 
 impl Svg {
+    /// Create an SVG element that renders the given SVG document, rather than a bundled asset.
+    ///
+    /// Unlike SVGs loaded with [`Svg::path`], which are drawn as a mask in the text color,
+    /// inline SVGs keep their own colors. They are rasterized at the element's size on the
+    /// display, and the result is cached by source and size. Only the most recently painted
+    /// inline SVGs are kept in the cache.
+    pub fn from_string(source: impl Into<SharedString>) -> Self {
+        Svg {
+            source: Some(source.into()),
+            ..svg()
+        }
+    }
+
     /// Set the path to the SVG file for this element.
     pub fn path(mut self, path: impl Into<SharedString>) -> Self {
         self.path = Some(path.into());
@@ -78,7 +99,25 @@ impl Element for Svg {
     {
         self.interactivity
             .paint(global_id, bounds, hitbox.as_ref(), cx, |style, cx| {
-                if let Some((path, color)) = self.path.as_ref().zip(style.text.color) {
+                if let Some(source) = self.source.as_ref() {
+                    let scale_factor = cx.scale_factor();
+                    let device_size = size(
+                        DevicePixels((bounds.size.width.0 * scale_factor).ceil() as i32),
+                        DevicePixels((bounds.size.height.0 * scale_factor).ceil() as i32),
+                    );
+                    if device_size.is_zero() {
+                        return;
+                    }
+                    let key = (source.clone(), device_size);
+                    InlineSvgCache::touch(&key, cx);
+                    if let Some(image) = cx
+                        .use_asset::<InlineSvgRasterizer>(&key)
+                        .and_then(Result::ok)
+                    {
+                        cx.paint_image(bounds, Corners::default(), image, 0, false)
+                            .log_err();
+                    }
+                } else if let Some((path, color)) = self.path.as_ref().zip(style.text.color) {
                     let transformation = self
                         .transformation
                         .as_ref()
@@ -94,6 +133,63 @@ impl Element for Svg {
     }
 }
 
+/// Rasterizes inline SVG documents into images for [`Svg::from_string`].
+#[derive(Clone)]
+enum InlineSvgRasterizer {}
+
+impl Asset for InlineSvgRasterizer {
+    type Source = (SharedString, Size<DevicePixels>);
+    type Output = Result<Arc<RenderImage>, ImageCacheError>;
+
+    fn load(
+        (source, size): Self::Source,
+        cx: &mut AppContext,
+    ) -> impl Future<Output = Self::Output> + Send + 'static {
+        let svg_renderer = cx.svg_renderer();
+        async move {
+            let pixmap = svg_renderer.render_pixmap(source.as_bytes(), SvgSize::Size(size))?;
+            let mut buffer =
+                ImageBuffer::from_raw(pixmap.width(), pixmap.height(), pixmap.take()).unwrap();
+            for pixel in buffer.chunks_exact_mut(4) {
+                swap_rgba_pa_to_bgra(pixel);
+            }
+            Ok(Arc::new(RenderImage::new(SmallVec::from_elem(
+                Frame::new(buffer),
+                1,
+            ))))
+        }
+    }
+}
+
+/// The number of rasterized inline SVGs kept in the asset cache.
+const MAX_CACHED_INLINE_SVGS: usize = 64;
+
+/// The inline SVGs whose rasterized images are in the asset cache, least recently painted first.
+#[derive(Default)]
+struct InlineSvgCache(VecDeque<(SharedString, Size<DevicePixels>)>);
+
+impl Global for InlineSvgCache {}
+
+impl InlineSvgCache {
+    /// Marks the inline SVG as the most recently painted one, evicting the least recently
+    /// painted image from the asset cache if there are too many.
+    fn touch(key: &(SharedString, Size<DevicePixels>), cx: &mut AppContext) {
+        let cache = &mut cx.default_global::<Self>().0;
+        if let Some(ix) = cache.iter().position(|cached| cached == key) {
+            let key = cache.remove(ix).unwrap();
+            cache.push_back(key);
+            return;
+        }
+
+        cache.push_back(key.clone());
+        if cache.len() > MAX_CACHED_INLINE_SVGS {
+            if let Some(evicted) = cache.pop_front() {
+                cx.remove_asset::<InlineSvgRasterizer>(&evicted);
+            }
+        }
+    }
+}
+
 impl IntoElement for Svg {
     type Element = Self;
 
@@ -179,7 +275,11 @@ impl Transformation {
         self
     }
 
-    fn into_matrix(self, center: Point<Pixels>, scale_factor: f32) -> TransformationMatrix {
+    pub(crate) fn into_matrix(
+        self,
+        center: Point<Pixels>,
+        scale_factor: f32,
+    ) -> TransformationMatrix {
         //Note: if you read this as a sequence of matrix multiplications, start from the bottom
         TransformationMatrix::unit()
             .translate(center.scale(scale_factor) + self.translate.scale(scale_factor))
@@ -188,3 +288,41 @@ impl Transformation {
             .translate(center.scale(scale_factor).negate())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as gpui, TestAppContext};
+
+    #[gpui::test]
+    fn test_inline_svg_cache_is_bounded(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let keys = (0..MAX_CACHED_INLINE_SVGS + 2)
+                .map(|ix| {
+                    let size = size(DevicePixels(ix as i32 + 1), DevicePixels(1));
+                    (SharedString::from("<svg/>"), size)
+                })
+                .collect::<Vec<_>>();
+            let assets_before = cx.loading_assets.len();
+
+            for key in &keys[..=MAX_CACHED_INLINE_SVGS] {
+                InlineSvgCache::touch(key, cx);
+                cx.fetch_asset::<InlineSvgRasterizer>(key);
+            }
+            let cache = &cx.default_global::<InlineSvgCache>().0;
+            assert_eq!(cache.len(), MAX_CACHED_INLINE_SVGS);
+            assert!(!cache.contains(&keys[0]));
+            assert_eq!(
+                cx.loading_assets.len() - assets_before,
+                MAX_CACHED_INLINE_SVGS
+            );
+
+            // Painting an image again keeps it in the cache, so the next oldest one is evicted.
+            InlineSvgCache::touch(&keys[1], cx);
+            InlineSvgCache::touch(&keys[MAX_CACHED_INLINE_SVGS + 1], cx);
+            let cache = &cx.default_global::<InlineSvgCache>().0;
+            assert!(cache.contains(&keys[1]));
+            assert!(!cache.contains(&keys[2]));
+        });
+    }
+}
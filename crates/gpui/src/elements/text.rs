@@ -1,10 +1,12 @@
 use crate::{
-    ActiveTooltip, AnyTooltip, AnyView, Bounds, DispatchPhase, Element, ElementId, GlobalElementId,
-    HighlightStyle, Hitbox, IntoElement, LayoutId, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    Pixels, Point, SharedString, Size, TextRun, TextStyle, Truncate, WhiteSpace, WindowContext,
-    WrappedLine, TOOLTIP_DELAY,
+    fill, point, size, truncate_line_at_chars, ActiveTooltip, AnyTooltip, AnyView, AnyWindowHandle,
+    Bounds, DispatchPhase, Element, ElementId, FocusHandle, GlobalElementId, HighlightStyle,
+    Hitbox, Hsla, IntoElement, KeyDownEvent, LayoutId, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, Pixels, Point, SharedString, Size, TextRun, TextShadow, TextStyle, Truncate,
+    WhiteSpace, WindowContext, WrappedLine, TOOLTIP_DELAY,
 };
 use anyhow::anyhow;
+use collections::FxHashSet;
 use parking_lot::{Mutex, MutexGuard};
 use smallvec::SmallVec;
 use std::{
@@ -13,7 +15,9 @@ use std::{
     ops::Range,
     rc::Rc,
     sync::Arc,
+    time::{Duration, Instant},
 };
+use unicode_width::UnicodeWidthChar as _;
 use util::ResultExt;
 
 impl Element for &'static str {
@@ -130,26 +134,93 @@ impl IntoElement for SharedString {
 pub struct StyledText {
     text: SharedString,
     runs: Option<Vec<TextRun>>,
+    ruby_annotations: Vec<(Range<usize>, SharedString)>,
+    background_highlights: Vec<(Range<usize>, Hsla)>,
+    cursor: Option<(usize, TextCursorStyle)>,
     layout: TextLayout,
 }
 
+/// The appearance of an edit cursor drawn within a [`StyledText`].
+#[derive(Clone, Debug)]
+pub struct TextCursorStyle {
+    /// The width of the cursor.
+    pub width: Pixels,
+    /// The color of the cursor.
+    pub color: Hsla,
+    /// Whether the cursor blinks.
+    pub blink: bool,
+}
+
+const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+thread_local! {
+    /// The instant all blinking text cursors are in phase with, so that they
+    /// blink together and don't restart their blink when re-rendered.
+    static CURSOR_BLINK_EPOCH: Instant = Instant::now();
+
+    /// The windows with a timer pending to repaint their blinking cursors.
+    static CURSOR_BLINK_WINDOWS: RefCell<FxHashSet<AnyWindowHandle>> = RefCell::default();
+}
+
+/// Refreshes the window when the blinking cursors in it next change phase. Only one timer is
+/// pending per window, no matter how many cursors are painted or how often.
+fn schedule_cursor_blink(elapsed: Duration, cx: &mut WindowContext) {
+    let window = cx.window_handle();
+    if !CURSOR_BLINK_WINDOWS.with(|windows| windows.borrow_mut().insert(window)) {
+        return;
+    }
+
+    let interval = CURSOR_BLINK_INTERVAL.as_millis();
+    let until_next_blink =
+        Duration::from_millis((interval - elapsed.as_millis() % interval) as u64);
+    cx.spawn(|mut cx| async move {
+        cx.background_executor().timer(until_next_blink).await;
+        CURSOR_BLINK_WINDOWS.with(|windows| windows.borrow_mut().remove(&window));
+        cx.update(|cx| cx.refresh()).ok();
+    })
+    .detach();
+}
+
 impl StyledText {
     /// Construct a new styled text element from the given string.
     pub fn new(text: impl Into<SharedString>) -> Self {
         StyledText {
             text: text.into(),
             runs: None,
+            ruby_annotations: Vec::new(),
+            background_highlights: Vec::new(),
+            cursor: None,
             layout: TextLayout::default(),
         }
     }
 
+    /// Draw an edit cursor before the character at the given byte index.
+    pub fn with_cursor(mut self, byte_index: usize, style: TextCursorStyle) -> Self {
+        self.cursor = Some((byte_index, style));
+        self
+    }
+
     /// Get the layout for this element. This can be used to map indices to pixels and vice versa.
     pub fn layout(&self) -> &TextLayout {
         &self.layout
     }
 
+    /// Get the index of the range containing the character at the given pixel position.
+    /// See [`TextLayout::hit_test_range`].
+    pub fn hit_test_range(
+        &self,
+        position: Point<Pixels>,
+        ranges: &[Range<usize>],
+    ) -> Option<usize> {
+        self.layout.hit_test_range(position, ranges)
+    }
+
     /// Set the styling attributes for the given text, as well as
     /// as any ranges of text that have had their style customized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a highlight range is reversed or extends past the end of the text.
     pub fn with_highlights(
         mut self,
         default_style: &TextStyle,
@@ -158,6 +229,11 @@ impl StyledText {
         let mut runs = Vec::new();
         let mut ix = 0;
         for (range, highlight) in highlights {
+            assert!(
+                range.start <= range.end && range.end <= self.text.len(),
+                "invalid highlight range {range:?} for text of length {}",
+                self.text.len()
+            );
             if ix < range.start {
                 runs.push(default_style.clone().to_run(range.start - ix));
             }
@@ -181,6 +257,75 @@ impl StyledText {
         self.runs = Some(runs);
         self
     }
+
+    /// Fill the background behind the given ranges of the text with a color, such as to
+    /// highlight search matches. Unlike [`Self::with_highlights`], this keeps the style of
+    /// the text itself, and can be combined with other highlights.
+    pub fn with_background_highlights(
+        mut self,
+        ranges: impl IntoIterator<Item = Range<usize>>,
+        color: Hsla,
+    ) -> Self {
+        self.background_highlights
+            .extend(ranges.into_iter().map(|range| (range, color)));
+        self
+    }
+
+    /// Annotate ranges of the text with ruby text, such as furigana or bopomofo.
+    /// Each annotation is painted at half the font size, centered above the glyphs in its range,
+    /// and every line reserves room above its glyphs for them.
+    pub fn with_ruby_annotations(mut self, annotations: Vec<(Range<usize>, SharedString)>) -> Self {
+        self.ruby_annotations = annotations;
+        self
+    }
+
+    fn paint_cursor(&self, byte_index: usize, style: &TextCursorStyle, cx: &mut WindowContext) {
+        if style.blink {
+            let elapsed = CURSOR_BLINK_EPOCH.with(|epoch| epoch.elapsed());
+            let phase = (elapsed.as_millis() / CURSOR_BLINK_INTERVAL.as_millis()) % 2;
+            schedule_cursor_blink(elapsed, cx);
+            if phase == 1 {
+                return;
+            }
+        }
+
+        if let Some(mut origin) = self.layout.position_for_index(byte_index) {
+            let ruby_height = self.layout.ruby_height();
+            origin.y += ruby_height;
+            let cursor_size = size(style.width, self.layout.line_height() - ruby_height);
+            cx.paint_quad(fill(Bounds::new(origin, cursor_size), style.color));
+        }
+    }
+
+    fn paint_ruby_annotations(&self, cx: &mut WindowContext) {
+        let text_style = cx.text_style();
+        let font_size = text_style.font_size.to_pixels(cx.rem_size()) * 0.5;
+        let line_height = self.layout.ruby_height();
+
+        for (range, annotation) in &self.ruby_annotations {
+            let Some(start) = self.layout.position_for_index(range.start) else {
+                continue;
+            };
+            let cluster_width = self
+                .layout
+                .position_for_index(range.end)
+                .filter(|end| end.y == start.y)
+                .map_or(Pixels::ZERO, |end| end.x - start.x);
+
+            let run = text_style.to_run(annotation.len());
+            let Some(shaped) = cx
+                .text_system()
+                .shape_line(annotation.clone(), font_size, &[run])
+                .log_err()
+            else {
+                continue;
+            };
+
+            let origin = point(start.x + (cluster_width - shaped.width) / 2., start.y);
+            let bounds = Bounds::new(origin, size(shaped.width, line_height));
+            cx.paint_layer(bounds, |cx| shaped.paint(origin, line_height, cx).log_err());
+        }
+    }
 }
 
 impl Element for StyledText {
@@ -198,7 +343,12 @@ impl Element for StyledText {
 
         cx: &mut WindowContext,
     ) -> (LayoutId, Self::RequestLayoutState) {
-        let layout_id = self.layout.layout(self.text.clone(), self.runs.take(), cx);
+        let layout_id = self.layout.layout(
+            self.text.clone(),
+            self.runs.take(),
+            !self.ruby_annotations.is_empty(),
+            cx,
+        );
         (layout_id, ())
     }
 
@@ -220,7 +370,24 @@ impl Element for StyledText {
         _: &mut Self::PrepaintState,
         cx: &mut WindowContext,
     ) {
-        self.layout.paint(&self.text, cx)
+        if !self.background_highlights.is_empty() {
+            // Like the glyphs, highlights start below the space reserved for ruby annotations.
+            let ruby_height = self.layout.ruby_height();
+            for (range, color) in &self.background_highlights {
+                for mut bounds in self.layout.selection_rects(range.clone()) {
+                    bounds.origin.y += ruby_height;
+                    bounds.size.height -= ruby_height;
+                    cx.paint_quad(fill(bounds, *color));
+                }
+            }
+        }
+        self.layout.paint(&self.text, cx);
+        if !self.ruby_annotations.is_empty() {
+            self.paint_ruby_annotations(cx);
+        }
+        if let Some((byte_index, style)) = &self.cursor {
+            self.paint_cursor(*byte_index, style, cx);
+        }
     }
 }
 
@@ -239,6 +406,10 @@ pub struct TextLayout(Arc<Mutex<Option<TextLayoutInner>>>);
 struct TextLayoutInner {
     lines: SmallVec<[WrappedLine; 1]>,
     line_height: Pixels,
+    /// The space reserved above the glyphs of every line for ruby annotations, which is
+    /// included in `line_height`.
+    ruby_height: Pixels,
+    shadows: SmallVec<[TextShadow; 1]>,
     wrap_width: Option<Pixels>,
     size: Option<Size<Pixels>>,
     bounds: Option<Bounds<Pixels>>,
@@ -255,13 +426,20 @@ impl TextLayout {
         &self,
         text: SharedString,
         runs: Option<Vec<TextRun>>,
+        has_ruby_annotations: bool,
         cx: &mut WindowContext,
     ) -> LayoutId {
         let text_style = cx.text_style();
         let font_size = text_style.font_size.to_pixels(cx.rem_size());
-        let line_height = text_style
+        let mut line_height = text_style
             .line_height
             .to_pixels(font_size.into(), cx.rem_size());
+        let ruby_height = if has_ruby_annotations {
+            line_height * 0.5
+        } else {
+            Pixels::ZERO
+        };
+        line_height += ruby_height;
 
         let mut runs = if let Some(runs) = runs {
             runs
@@ -273,7 +451,15 @@ impl TextLayout {
             let element_state = self.clone();
 
             move |known_dimensions, available_space, cx| {
-                let wrap_width = if text_style.white_space == WhiteSpace::Normal {
+                // Text is laid out at its max-content width unless the available width is definite,
+                // so it only wraps when it is given a width to wrap within.
+                let truncate_at_chars = match text_style.truncate {
+                    Some(Truncate::AtChars(max_chars)) => Some(max_chars),
+                    _ => None,
+                };
+                let wrap_width = if text_style.white_space == WhiteSpace::Normal
+                    && truncate_at_chars.is_none()
+                {
                     known_dimensions.width.or(match available_space.width {
                         crate::AvailableSpace::Definite(x) => Some(x),
                         _ => None,
@@ -291,6 +477,7 @@ impl TextLayout {
                     match truncate {
                         Truncate::Truncate => (width, None),
                         Truncate::Ellipsis => (width, Some(ELLIPSIS)),
+                        Truncate::AtChars(_) => (None, None),
                     }
                 } else {
                     (None, None)
@@ -305,7 +492,13 @@ impl TextLayout {
                 }
 
                 let mut line_wrapper = cx.text_system().line_wrapper(text_style.font(), font_size);
-                let text = if let Some(truncate_width) = truncate_width {
+                line_wrapper.set_spacing(
+                    text_style.letter_spacing.unwrap_or_default(),
+                    text_style.word_spacing.unwrap_or_default(),
+                );
+                let text = if let Some(max_chars) = truncate_at_chars {
+                    truncate_line_at_chars(text.clone(), max_chars, ELLIPSIS, &mut runs)
+                } else if let Some(truncate_width) = truncate_width {
                     line_wrapper.truncate_line(text.clone(), truncate_width, ellipsis, &mut runs)
                 } else {
                     text.clone()
@@ -321,6 +514,8 @@ impl TextLayout {
                     element_state.lock().replace(TextLayoutInner {
                         lines: Default::default(),
                         line_height,
+                        ruby_height,
+                        shadows: text_style.shadows.clone(),
                         wrap_width,
                         size: Some(Size::default()),
                         bounds: None,
@@ -338,6 +533,8 @@ impl TextLayout {
                 element_state.lock().replace(TextLayoutInner {
                     lines,
                     line_height,
+                    ruby_height,
+                    shadows: text_style.shadows.clone(),
                     wrap_width,
                     size: Some(size),
                     bounds: None,
@@ -371,7 +568,19 @@ impl TextLayout {
             .unwrap();
 
         let line_height = element_state.line_height;
-        let mut line_origin = bounds.origin;
+        // Glyphs are centered within the line height, so shift them down by half of the
+        // space reserved for ruby annotations to leave all of it above them.
+        let origin = bounds.origin + point(Pixels::ZERO, element_state.ruby_height / 2.);
+        for shadow in &element_state.shadows {
+            let mut line_origin = origin;
+            for line in &element_state.lines {
+                line.paint_shadow(line_origin, line_height, shadow, cx)
+                    .log_err();
+                line_origin.y += line.size(line_height).height;
+            }
+        }
+
+        let mut line_origin = origin;
         for line in &element_state.lines {
             line.paint(line_origin, line_height, cx).log_err();
             line_origin.y += line.size(line_height).height;
@@ -412,6 +621,83 @@ impl TextLayout {
         Err(line_start_ix.saturating_sub(1))
     }
 
+    /// Get the index of the range containing the character at the given pixel position.
+    ///
+    /// The ranges must be sorted and non-overlapping, which allows them to be binary searched.
+    pub fn hit_test_range(
+        &self,
+        position: Point<Pixels>,
+        ranges: &[Range<usize>],
+    ) -> Option<usize> {
+        let index = self.index_for_position(position).ok()?;
+        range_index_containing(ranges, index)
+    }
+
+    /// Get the bounds of each display row covered by the given byte range, as would be
+    /// highlighted when selecting it. Empty ranges have no bounds.
+    pub fn selection_rects(&self, range: Range<usize>) -> Vec<Bounds<Pixels>> {
+        let element_state = self.lock();
+        let element_state = element_state
+            .as_ref()
+            .expect("measurement has not been performed");
+        let bounds = element_state
+            .bounds
+            .expect("prepaint has not been performed");
+        let line_height = element_state.line_height;
+
+        let mut rects = Vec::new();
+        let mut line_origin = bounds.origin;
+        let mut line_start_ix = 0;
+        for line in &element_state.lines {
+            if line_start_ix >= range.end {
+                break;
+            }
+
+            let line_end_ix = line_start_ix + line.len();
+            let start_within_line = range.start.saturating_sub(line_start_ix).min(line.len());
+            let end_within_line = range.end.saturating_sub(line_start_ix).min(line.len());
+
+            let mut row_top = line_origin.y;
+            for row in line.row_ranges() {
+                let start_ix = start_within_line.max(row.start);
+                let end_ix = end_within_line.min(row.end);
+                if start_ix < end_ix {
+                    let row_start_x = line.unwrapped_layout.x_for_index(row.start);
+                    let start_x = line.unwrapped_layout.x_for_index(start_ix) - row_start_x;
+                    let end_x = line.unwrapped_layout.x_for_index(end_ix) - row_start_x;
+                    rects.push(Bounds::from_corners(
+                        point(line_origin.x + start_x, row_top),
+                        point(line_origin.x + end_x, row_top + line_height),
+                    ));
+                }
+                row_top += line_height;
+            }
+
+            line_origin.y += line.size(line_height).height;
+            line_start_ix = line_end_ix + 1;
+        }
+
+        rects
+    }
+
+    /// Get every range whose [selection rects](Self::selection_rects) intersect the given
+    /// bounds, along with its index, such as to find the ranges under a marquee selection.
+    pub fn hit_test_all_ranges<'a>(
+        &self,
+        rect: Bounds<Pixels>,
+        ranges: &'a [Range<usize>],
+    ) -> Vec<(usize, &'a Range<usize>)> {
+        ranges
+            .iter()
+            .enumerate()
+            .filter(|(_, range)| {
+                self.selection_rects((*range).clone())
+                    .iter()
+                    .any(|selection_rect| selection_rect.intersects(&rect))
+            })
+            .collect()
+    }
+
     /// Get the pixel position for the given byte index.
     pub fn position_for_index(&self, index: usize) -> Option<Point<Pixels>> {
         let element_state = self.lock();
@@ -443,6 +729,95 @@ impl TextLayout {
         None
     }
 
+    /// Get the x offset of each of the given columns within a display row, relative to the start
+    /// of the row. Rows count wrapped lines separately, and columns count display width, so wide
+    /// characters such as CJK ideographs take two columns and combining marks take none.
+    ///
+    /// A column that falls within a wide character is placed after it. Columns past the end of
+    /// the row are given an offset of infinity.
+    pub fn line_x_offsets_for_row(&self, row: usize, columns: &[u32]) -> Vec<Pixels> {
+        let element_state = self.lock();
+        let element_state = element_state
+            .as_ref()
+            .expect("measurement has not been performed");
+
+        let Some((line, row)) = element_state
+            .lines
+            .iter()
+            .flat_map(|line| line.row_ranges().map(move |row| (line, row)))
+            .nth(row)
+        else {
+            return vec![Pixels(f32::INFINITY); columns.len()];
+        };
+
+        // The column and byte index within the row of each boundary between characters, skipping
+        // the boundaries before zero-width characters so they stay with the preceding character.
+        let row_text = &line.text[row.clone()];
+        let mut boundaries = vec![(0, 0)];
+        for (ix, ch) in row_text.char_indices() {
+            let end_ix = ix + ch.len_utf8();
+            let width = ch.width().unwrap_or(0);
+            let last_boundary = boundaries.last_mut().unwrap();
+            if width == 0 {
+                last_boundary.1 = end_ix;
+            } else {
+                let column = last_boundary.0 + width;
+                boundaries.push((column, end_ix));
+            }
+        }
+
+        let row_start_x = line.unwrapped_layout.x_for_index(row.start);
+        columns
+            .iter()
+            .map(|&column| {
+                let column = column as usize;
+                match boundaries
+                    .iter()
+                    .find(|(boundary_column, _)| *boundary_column >= column)
+                {
+                    Some((_, ix)) => {
+                        line.unwrapped_layout.x_for_index(row.start + ix) - row_start_x
+                    }
+                    None => Pixels(f32::INFINITY),
+                }
+            })
+            .collect()
+    }
+
+    /// Map byte-range annotations to the display rows of this layout, returning one entry
+    /// per row with wrapped lines counted separately, such as for drawing git blame or
+    /// coverage next to each row.
+    ///
+    /// A row takes the first annotation overlapping its byte range, or for an empty row, the
+    /// first one containing its start. Rows not covered by any annotation are `None`.
+    pub fn annotate_lines<T: Clone>(&self, annotations: &[(Range<usize>, T)]) -> Vec<Option<T>> {
+        let element_state = self.lock();
+        let element_state = element_state
+            .as_ref()
+            .expect("measurement has not been performed");
+
+        let mut row_annotations = Vec::new();
+        let mut line_start_ix = 0;
+        for line in &element_state.lines {
+            for row in line.row_ranges() {
+                let row_start_ix = line_start_ix + row.start;
+                let row_end_ix = line_start_ix + row.end;
+                let annotation = annotations.iter().find(|(range, _)| {
+                    if row_start_ix == row_end_ix {
+                        range.contains(&row_start_ix)
+                    } else {
+                        range.start < row_end_ix && range.end > row_start_ix
+                    }
+                });
+                row_annotations.push(annotation.map(|(_, annotation)| annotation.clone()));
+            }
+
+            line_start_ix += line.len() + 1;
+        }
+
+        row_annotations
+    }
+
     /// The bounds of this layout.
     pub fn bounds(&self) -> Bounds<Pixels> {
         self.0.lock().as_ref().unwrap().bounds.unwrap()
@@ -453,6 +828,10 @@ impl TextLayout {
         self.0.lock().as_ref().unwrap().line_height
     }
 
+    fn ruby_height(&self) -> Pixels {
+        self.0.lock().as_ref().unwrap().ruby_height
+    }
+
     /// The text for this layout.
     pub fn text(&self) -> String {
         self.0
@@ -467,6 +846,25 @@ impl TextLayout {
     }
 }
 
+fn ranges_are_sorted(ranges: &[Range<usize>]) -> bool {
+    ranges.windows(2).all(|pair| pair[0].end <= pair[1].start)
+}
+
+/// Binary searches sorted, non-overlapping ranges for the one containing the given index.
+fn range_index_containing(ranges: &[Range<usize>], index: usize) -> Option<usize> {
+    ranges
+        .binary_search_by(|range| {
+            if range.end <= index {
+                std::cmp::Ordering::Less
+            } else if range.start > index {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+}
+
 /// A text element that can be interacted with.
 pub struct InteractiveText {
     element_id: ElementId,
@@ -475,7 +873,11 @@ pub struct InteractiveText {
         Option<Box<dyn Fn(&[Range<usize>], InteractiveTextClickEvent, &mut WindowContext)>>,
     hover_listener: Option<Box<dyn Fn(Option<usize>, MouseMoveEvent, &mut WindowContext)>>,
     tooltip_builder: Option<Rc<dyn Fn(usize, &mut WindowContext) -> Option<AnyView>>>,
+    drag_select_listener: Option<Rc<dyn Fn(Range<usize>, &mut WindowContext)>>,
+    triple_click_listener: Option<Box<dyn Fn(Range<usize>, &mut WindowContext)>>,
     clickable_ranges: Vec<Range<usize>>,
+    clickable_ranges_sorted: bool,
+    focusable: bool,
 }
 
 struct InteractiveTextClickEvent {
@@ -489,6 +891,10 @@ pub struct InteractiveTextState {
     mouse_down_index: Rc<Cell<Option<usize>>>,
     hovered_index: Rc<Cell<Option<usize>>>,
     active_tooltip: Rc<RefCell<Option<ActiveTooltip>>>,
+    focus_handle: Option<FocusHandle>,
+    selection_anchor: Rc<Cell<Option<usize>>>,
+    selection_range: Rc<RefCell<Option<Range<usize>>>>,
+    selection_dragging: Rc<Cell<bool>>,
 }
 
 /// InteractiveTest is a wrapper around StyledText that adds mouse interactions.
@@ -501,7 +907,11 @@ impl InteractiveText {
             click_listener: None,
             hover_listener: None,
             tooltip_builder: None,
+            drag_select_listener: None,
+            triple_click_listener: None,
             clickable_ranges: Vec::new(),
+            clickable_ranges_sorted: true,
+            focusable: false,
         }
     }
 
@@ -512,7 +922,18 @@ impl InteractiveText {
         ranges: Vec<Range<usize>>,
         listener: impl Fn(usize, &mut WindowContext) + 'static,
     ) -> Self {
+        let sorted = ranges_are_sorted(&ranges);
+        self.clickable_ranges_sorted = sorted;
         self.click_listener = Some(Box::new(move |ranges, event, cx| {
+            if sorted {
+                if let Some(range_ix) = range_index_containing(ranges, event.mouse_down_index) {
+                    if ranges[range_ix].contains(&event.mouse_up_index) {
+                        listener(range_ix, cx);
+                    }
+                }
+                return;
+            }
+
             for (range_ix, range) in ranges.iter().enumerate() {
                 if range.contains(&event.mouse_down_index) && range.contains(&event.mouse_up_index)
                 {
@@ -542,6 +963,174 @@ impl InteractiveText {
         self.tooltip_builder = Some(Rc::new(builder));
         self
     }
+
+    /// on_drag_select is called with the selected byte range whenever the user selects text,
+    /// either by dragging the mouse or, when focusable, by extending the selection with the keyboard.
+    pub fn on_drag_select(
+        mut self,
+        listener: impl Fn(Range<usize>, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.drag_select_listener = Some(Rc::new(listener));
+        self
+    }
+
+    /// on_triple_click is called when the user triple-clicks the text, passing the byte range of
+    /// the clicked line, from its first non-whitespace character up to the newline or end of text.
+    ///
+    /// Clicks are counted by the platform, so the three clicks must land close together within
+    /// the system's double-click interval.
+    pub fn on_triple_click(
+        mut self,
+        listener: impl Fn(Range<usize>, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.triple_click_listener = Some(Box::new(listener));
+        self
+    }
+
+    /// focusable makes the text focusable when clicked, allowing the selection to be extended
+    /// with `shift-left`, `shift-right`, `shift-home` and `shift-end`.
+    pub fn focusable(mut self) -> Self {
+        self.focusable = true;
+        self
+    }
+
+    fn paint_selection_listeners(
+        &mut self,
+        interactive_state: &InteractiveTextState,
+        hitbox: &Hitbox,
+        cx: &mut WindowContext,
+    ) {
+        let Some(drag_select_listener) = self.drag_select_listener.clone() else {
+            return;
+        };
+        let text_layout = self.text.layout().clone();
+
+        cx.on_mouse_event({
+            let hitbox = hitbox.clone();
+            let text_layout = text_layout.clone();
+            let focus_handle = interactive_state.focus_handle.clone();
+            let anchor = interactive_state.selection_anchor.clone();
+            let selection_range = interactive_state.selection_range.clone();
+            let dragging = interactive_state.selection_dragging.clone();
+            move |event: &MouseDownEvent, phase, cx| {
+                if phase == DispatchPhase::Bubble
+                    && event.button == MouseButton::Left
+                    && hitbox.is_hovered(cx)
+                {
+                    if let Some(focus_handle) = focus_handle.as_ref() {
+                        focus_handle.focus(cx);
+                    }
+                    let ix = text_layout
+                        .index_for_position(event.position)
+                        .unwrap_or_else(|ix| ix);
+                    anchor.set(Some(ix));
+                    selection_range.replace(Some(ix..ix));
+                    dragging.set(true);
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let drag_select_listener = drag_select_listener.clone();
+            let anchor = interactive_state.selection_anchor.clone();
+            let selection_range = interactive_state.selection_range.clone();
+            let dragging = interactive_state.selection_dragging.clone();
+            move |event: &MouseMoveEvent, phase, cx| {
+                if phase != DispatchPhase::Bubble
+                    || !dragging.get()
+                    || event.pressed_button != Some(MouseButton::Left)
+                {
+                    return;
+                }
+                let Some(anchor) = anchor.get() else {
+                    return;
+                };
+                let head = text_layout
+                    .index_for_position(event.position)
+                    .unwrap_or_else(|ix| ix);
+                let range = anchor.min(head)..anchor.max(head);
+                if selection_range.borrow().as_ref() != Some(&range) {
+                    selection_range.replace(Some(range.clone()));
+                    drag_select_listener(range, cx);
+                    cx.refresh();
+                }
+            }
+        });
+
+        cx.on_mouse_event({
+            let dragging = interactive_state.selection_dragging.clone();
+            move |_: &MouseUpEvent, phase, _| {
+                if phase == DispatchPhase::Bubble {
+                    dragging.set(false);
+                }
+            }
+        });
+
+        if interactive_state.focus_handle.is_some() {
+            let text = self.text.text.clone();
+            let anchor = interactive_state.selection_anchor.clone();
+            let selection_range = interactive_state.selection_range.clone();
+            cx.on_key_event(move |event: &KeyDownEvent, phase, cx| {
+                if phase != DispatchPhase::Bubble || !event.keystroke.modifiers.shift {
+                    return;
+                }
+                let Some(anchor_ix) = anchor.get() else {
+                    return;
+                };
+                let head = match selection_range.borrow().as_ref() {
+                    Some(range) if range.start == anchor_ix => range.end,
+                    Some(range) => range.start,
+                    None => anchor_ix,
+                };
+                let Some(head) = extend_selection_head(&text, head, &event.keystroke.key) else {
+                    return;
+                };
+
+                let range = anchor_ix.min(head)..anchor_ix.max(head);
+                selection_range.replace(Some(range.clone()));
+                drag_select_listener(range, cx);
+                cx.stop_propagation();
+                cx.refresh();
+            });
+        }
+    }
+}
+
+/// Returns the range of the line containing the given index, excluding its leading
+/// whitespace and trailing newline.
+fn line_range_at(text: &str, ix: usize) -> Range<usize> {
+    let ix = ix.min(text.len());
+    let line_start = text[..ix].rfind('\n').map_or(0, |newline| newline + 1);
+    let line_end = text[ix..]
+        .find('\n')
+        .map_or(text.len(), |newline| ix + newline);
+    let indent = text[line_start..line_end]
+        .find(|c: char| !c.is_whitespace())
+        .unwrap_or(line_end - line_start);
+    line_start + indent..line_end
+}
+
+/// Moves the head of a selection in response to one of the supported selection keys,
+/// returning `None` if the key doesn't extend the selection.
+fn extend_selection_head(text: &str, head: usize, key: &str) -> Option<usize> {
+    let head = head.min(text.len());
+    match key {
+        "left" => Some(
+            text[..head]
+                .chars()
+                .next_back()
+                .map_or(head, |ch| head - ch.len_utf8()),
+        ),
+        "right" => Some(
+            text[head..]
+                .chars()
+                .next()
+                .map_or(head, |ch| head + ch.len_utf8()),
+        ),
+        "home" => Some(text[..head].rfind('\n').map_or(0, |ix| ix + 1)),
+        "end" => Some(text[head..].find('\n').map_or(text.len(), |ix| head + ix)),
+        _ => None,
+    }
 }
 
 impl Element for InteractiveText {
@@ -570,9 +1159,19 @@ impl Element for InteractiveText {
         cx.with_optional_element_state::<InteractiveTextState, _>(
             global_id,
             |interactive_state, cx| {
-                let interactive_state = interactive_state
+                let mut interactive_state = interactive_state
                     .map(|interactive_state| interactive_state.unwrap_or_default());
 
+                if self.focusable {
+                    if let Some(interactive_state) = interactive_state.as_mut() {
+                        let focus_handle = interactive_state
+                            .focus_handle
+                            .get_or_insert_with(|| cx.focus_handle())
+                            .clone();
+                        cx.set_focus_handle(&focus_handle);
+                    }
+                }
+
                 if let Some(interactive_state) = interactive_state.as_ref() {
                     if let Some(active_tooltip) = interactive_state.active_tooltip.borrow().as_ref()
                     {
@@ -602,16 +1201,44 @@ impl Element for InteractiveText {
             global_id.unwrap(),
             |interactive_state, cx| {
                 let mut interactive_state = interactive_state.unwrap_or_default();
-                if let Some(click_listener) = self.click_listener.take() {
-                    let mouse_position = cx.mouse_position();
-                    if let Ok(ix) = text_layout.index_for_position(mouse_position) {
-                        if self
-                            .clickable_ranges
-                            .iter()
-                            .any(|range| range.contains(&ix))
+                self.paint_selection_listeners(&interactive_state, hitbox, cx);
+
+                if let Some(triple_click_listener) = self.triple_click_listener.take() {
+                    let hitbox = hitbox.clone();
+                    let text = self.text.text.clone();
+                    let text_layout = text_layout.clone();
+                    cx.on_mouse_event(move |event: &MouseDownEvent, phase, cx| {
+                        if phase == DispatchPhase::Bubble
+                            && event.button == MouseButton::Left
+                            && event.click_count == 3
+                            && hitbox.is_hovered(cx)
                         {
-                            cx.set_cursor_style(crate::CursorStyle::PointingHand, hitbox)
+                            let ix = text_layout
+                                .index_for_position(event.position)
+                                .unwrap_or_else(|ix| ix);
+                            triple_click_listener(line_range_at(&text, ix), cx);
+                            cx.refresh();
                         }
+                    });
+                }
+
+                if let Some(click_listener) = self.click_listener.take() {
+                    let mouse_position = cx.mouse_position();
+                    let is_over_clickable_range = if self.clickable_ranges_sorted {
+                        text_layout
+                            .hit_test_range(mouse_position, &self.clickable_ranges)
+                            .is_some()
+                    } else {
+                        text_layout
+                            .index_for_position(mouse_position)
+                            .is_ok_and(|ix| {
+                                self.clickable_ranges
+                                    .iter()
+                                    .any(|range| range.contains(&ix))
+                            })
+                    };
+                    if is_over_clickable_range {
+                        cx.set_cursor_style(crate::CursorStyle::PointingHand, hitbox)
                     }
 
                     let text_layout = text_layout.clone();
@@ -749,3 +1376,314 @@ impl IntoElement for InteractiveText {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as gpui, div, px, FontWeight, ParentElement, Styled, TestAppContext};
+
+    #[test]
+    fn test_range_index_containing() {
+        let ranges = [0..3, 5..8, 8..9, 12..20];
+        assert!(ranges_are_sorted(&ranges));
+        assert_eq!(range_index_containing(&ranges, 0), Some(0));
+        assert_eq!(range_index_containing(&ranges, 2), Some(0));
+        assert_eq!(range_index_containing(&ranges, 3), None);
+        assert_eq!(range_index_containing(&ranges, 7), Some(1));
+        assert_eq!(range_index_containing(&ranges, 8), Some(2));
+        assert_eq!(range_index_containing(&ranges, 11), None);
+        assert_eq!(range_index_containing(&ranges, 19), Some(3));
+        assert_eq!(range_index_containing(&ranges, 20), None);
+        assert_eq!(range_index_containing(&[], 0), None);
+
+        assert!(!ranges_are_sorted(&[5..8, 0..3]));
+        assert!(!ranges_are_sorted(&[0..5, 3..8]));
+    }
+
+    #[test]
+    fn test_line_range_at() {
+        let text = "fn main() {\n    let x = 1;\n\n}";
+        assert_eq!(&text[line_range_at(text, 3)], "fn main() {");
+        assert_eq!(&text[line_range_at(text, 14)], "let x = 1;");
+        assert_eq!(&text[line_range_at(text, 25)], "let x = 1;");
+        assert_eq!(line_range_at(text, 27), 27..27);
+        assert_eq!(&text[line_range_at(text, 28)], "}");
+        assert_eq!(&text[line_range_at(text, 100)], "}");
+    }
+
+    struct TripleClickView {
+        clicked_ranges: Rc<RefCell<Vec<Range<usize>>>>,
+    }
+
+    impl crate::Render for TripleClickView {
+        fn render(&mut self, _: &mut crate::ViewContext<Self>) -> impl IntoElement {
+            let clicked_ranges = self.clicked_ranges.clone();
+            div().size_full().child(
+                InteractiveText::new("text", StyledText::new("  hello world\nsecond line"))
+                    .on_triple_click(move |range, _| clicked_ranges.borrow_mut().push(range)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_on_triple_click(cx: &mut TestAppContext) {
+        let clicked_ranges = Rc::new(RefCell::new(Vec::new()));
+        let (_, cx) = cx.add_window_view(|_| TripleClickView {
+            clicked_ranges: clicked_ranges.clone(),
+        });
+
+        let position = point(px(30.), px(5.));
+        for click_count in 1..=3 {
+            cx.simulate_event(MouseDownEvent {
+                position,
+                modifiers: Default::default(),
+                button: MouseButton::Left,
+                click_count,
+                first_mouse: false,
+            });
+            cx.simulate_event(MouseUpEvent {
+                position,
+                modifiers: Default::default(),
+                button: MouseButton::Left,
+                click_count,
+            });
+        }
+
+        assert_eq!(*clicked_ranges.borrow(), vec![2..13]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid highlight range 2..6 for text of length 5")]
+    fn test_with_highlights_out_of_bounds() {
+        StyledText::new("hello")
+            .with_highlights(&TextStyle::default(), [(2..6, HighlightStyle::default())]);
+    }
+
+    #[gpui::test]
+    fn test_line_x_offsets_for_row(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let text = StyledText::new("hello world");
+        let layout = text.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| text);
+
+        let offsets = layout.line_x_offsets_for_row(0, &[0, 5, 11, 12]);
+        assert_eq!(offsets[0], px(0.));
+        assert!(offsets[1] > offsets[0]);
+        assert!(offsets[2] > offsets[1]);
+        assert_eq!(offsets[3], Pixels(f32::INFINITY));
+
+        assert_eq!(
+            layout.line_x_offsets_for_row(1, &[0]),
+            vec![Pixels(f32::INFINITY)]
+        );
+    }
+
+    #[gpui::test]
+    fn test_line_x_offsets_for_row_use_display_width(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let text = StyledText::new("漢字ab");
+        let layout = text.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| text);
+
+        // Each ideograph is two columns wide.
+        let offsets = layout.line_x_offsets_for_row(0, &[1, 2, 4, 5, 6, 7]);
+        let expected = [3, 3, 6, 7, 8]
+            .map(|ix| layout.position_for_index(ix).unwrap().x)
+            .into_iter()
+            .chain([Pixels(f32::INFINITY)])
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, expected);
+    }
+
+    #[gpui::test]
+    fn test_blinking_cursor_repaints_on_a_timer(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let window = cx.update(|cx| cx.window_handle());
+        let has_pending_blink =
+            || CURSOR_BLINK_WINDOWS.with(|windows| windows.borrow().contains(&window));
+        let style = TextCursorStyle {
+            width: px(1.),
+            color: crate::black(),
+            blink: true,
+        };
+
+        for _ in 0..2 {
+            let style = style.clone();
+            cx.draw(point(px(0.), px(0.)), size(px(100.), px(20.)), |_| {
+                StyledText::new("hello").with_cursor(1, style)
+            });
+            assert!(has_pending_blink());
+        }
+
+        cx.executor().advance_clock(CURSOR_BLINK_INTERVAL);
+        cx.run_until_parked();
+        assert!(!has_pending_blink());
+    }
+
+    #[gpui::test]
+    fn test_background_highlights(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let color = crate::red();
+        let text = StyledText::new("hello world").with_background_highlights([0..5, 6..11], color);
+        let layout = text.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| text);
+
+        cx.update(|cx| {
+            let scale_factor = cx.scale_factor();
+            let highlighted = cx
+                .window
+                .next_frame
+                .scene
+                .quads
+                .iter()
+                .filter(|quad| quad.background == color.into())
+                .map(|quad| quad.bounds)
+                .collect::<Vec<_>>();
+            let expected = [0..5, 6..11]
+                .into_iter()
+                .flat_map(|range| layout.selection_rects(range))
+                .map(|bounds| bounds.scale(scale_factor))
+                .collect::<Vec<_>>();
+            assert_eq!(expected.len(), 2);
+            assert_eq!(highlighted, expected);
+        });
+    }
+
+    #[gpui::test]
+    fn test_background_highlights_below_ruby_annotations(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let color = crate::red();
+        let text = StyledText::new("漢字")
+            .with_ruby_annotations(vec![(0..6, "かんじ".into())])
+            .with_background_highlights([0..6], color);
+        let layout = text.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| text);
+
+        cx.update(|cx| {
+            let scale_factor = cx.scale_factor();
+            let highlighted = cx
+                .window
+                .next_frame
+                .scene
+                .quads
+                .iter()
+                .filter(|quad| quad.background == color.into())
+                .map(|quad| quad.bounds)
+                .collect::<Vec<_>>();
+            let ruby_height = layout.ruby_height();
+            let mut expected = layout.selection_rects(0..6)[0];
+            expected.origin.y += ruby_height;
+            expected.size.height -= ruby_height;
+            assert!(ruby_height > px(0.));
+            assert_eq!(highlighted, vec![expected.scale(scale_factor)]);
+        });
+    }
+
+    #[gpui::test]
+    fn test_hit_test_all_ranges(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let text = StyledText::new("hello world\nfoo");
+        let layout = text.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| text);
+
+        let ranges = [0..5, 6..11, 12..15];
+        let line_height = layout.line_height();
+        let world_x = layout.position_for_index(8).unwrap().x;
+
+        let first_line = Bounds::new(point(px(0.), px(0.)), size(px(1000.), line_height / 2.));
+        assert_eq!(
+            layout.hit_test_all_ranges(first_line, &ranges),
+            vec![(0, &ranges[0]), (1, &ranges[1])]
+        );
+
+        let world = Bounds::new(point(world_x, px(0.)), size(px(1.), line_height * 2.));
+        assert_eq!(
+            layout.hit_test_all_ranges(world, &ranges),
+            vec![(1, &ranges[1])]
+        );
+
+        let everything = Bounds::new(point(px(0.), px(0.)), size(px(1000.), px(100.)));
+        assert_eq!(layout.hit_test_all_ranges(everything, &ranges).len(), 3);
+
+        let below = Bounds::new(point(px(0.), line_height * 3.), size(px(1000.), px(10.)));
+        assert!(layout.hit_test_all_ranges(below, &ranges).is_empty());
+
+        assert!(layout.selection_rects(3..3).is_empty());
+        assert_eq!(layout.selection_rects(0..15).len(), 2);
+    }
+
+    #[gpui::test]
+    fn test_row_ranges(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let text = StyledText::new("hello world foo bar");
+        let layout = text.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(40.), px(100.)), |_| text);
+
+        let element_state = layout.lock();
+        let line = &element_state.as_ref().unwrap().lines[0];
+        let rows = line.row_ranges().collect::<Vec<_>>();
+        assert_eq!(rows.len(), line.wrap_boundaries.len() + 1);
+        assert!(rows.len() > 1);
+        assert_eq!(rows.first().unwrap().start, 0);
+        assert_eq!(rows.last().unwrap().end, line.len());
+        for pair in rows.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+    }
+
+    #[gpui::test]
+    fn test_annotate_lines(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let text = StyledText::new("fn main() {\n\n    run();\n}");
+        let layout = text.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| text);
+
+        assert_eq!(
+            layout.annotate_lines(&[(0..11, "a"), (12..24, "b")]),
+            vec![Some("a"), Some("b"), Some("b"), None]
+        );
+        assert_eq!(
+            layout.annotate_lines(&[(15..19, "run")]),
+            vec![None, None, Some("run"), None]
+        );
+        assert_eq!(layout.annotate_lines::<()>(&[]), vec![None; 4]);
+    }
+
+    #[gpui::test]
+    fn test_ruby_annotations_reserve_line_space(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let plain = StyledText::new("漢字\nかんじ");
+        let plain_layout = plain.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| plain);
+
+        let annotated = StyledText::new("漢字\nかんじ")
+            .with_ruby_annotations(vec![(0..6, "かんじ".into()), (7..16, "kanji".into())]);
+        let annotated_layout = annotated.layout().clone();
+        cx.draw(point(px(0.), px(0.)), size(px(1000.), px(100.)), |_| {
+            annotated
+        });
+
+        let line_height = plain_layout.line_height();
+        assert_eq!(annotated_layout.ruby_height(), line_height * 0.5);
+        assert_eq!(annotated_layout.line_height(), line_height * 1.5);
+        assert_eq!(
+            annotated_layout.bounds().size.height,
+            plain_layout.bounds().size.height * 1.5
+        );
+        assert_eq!(
+            annotated_layout.position_for_index(7).unwrap().y,
+            line_height * 1.5
+        );
+    }
+
+    #[gpui::test]
+    fn test_assert_element_text(cx: &mut TestAppContext) {
+        cx.assert_element_text(
+            div().child("Hello, ").child(
+                StyledText::new("world")
+                    .with_highlights(&TextStyle::default(), [(0..5, FontWeight::BOLD.into())]),
+            ),
+            "Hello, world",
+        );
+    }
+}
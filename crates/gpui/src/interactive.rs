@@ -466,9 +466,15 @@ impl PlatformInput {
 #[cfg(test)]
 mod test {
 
+    use std::path::PathBuf;
+
+    use smallvec::smallvec;
+
     use crate::{
-        self as gpui, div, FocusHandle, InteractiveElement, IntoElement, KeyBinding, Keystroke,
-        ParentElement, Render, TestAppContext, ViewContext, VisualContext,
+        self as gpui, canvas, div, point, px, size, AppContext, Bounds, DismissEvent, ElementId,
+        EventEmitter, ExternalPaths, FileDropEvent, FocusHandle, FocusableView, InteractiveElement,
+        IntoElement, KeyBinding, Keystroke, Modifiers, ParentElement, Render, Styled,
+        TestAppContext, ViewContext, VisualContext,
     };
 
     struct TestView {
@@ -535,4 +541,194 @@ mod test {
             })
             .unwrap();
     }
+
+    struct HoverView;
+
+    impl Render for HoverView {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div()
+                .flex()
+                .child(div().id("first").size(px(50.)).cursor_pointer())
+                .child(div().id("second").size(px(50.)).cursor_pointer())
+        }
+    }
+
+    #[gpui::test]
+    fn test_hover_element_id(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| HoverView);
+        cx.run_until_parked();
+
+        cx.simulate_mouse_move(point(px(10.), px(10.)), None, Modifiers::default());
+        assert_eq!(
+            cx.update(|cx| cx.hover_element_id()),
+            Some(ElementId::from("first"))
+        );
+
+        cx.simulate_mouse_move(point(px(60.), px(10.)), None, Modifiers::default());
+        assert_eq!(
+            cx.update(|cx| cx.hover_element_id()),
+            Some(ElementId::from("second"))
+        );
+
+        cx.simulate_mouse_move(point(px(10.), px(80.)), None, Modifiers::default());
+        assert_eq!(cx.update(|cx| cx.hover_element_id()), None);
+    }
+
+    struct FocusCycleView {
+        focus_handles: Vec<FocusHandle>,
+    }
+
+    impl Render for FocusCycleView {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div().children(
+                self.focus_handles
+                    .iter()
+                    .map(|focus_handle| div().size(px(10.)).track_focus(focus_handle)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_focus_next_and_prev(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|cx| FocusCycleView {
+            focus_handles: (0..3).map(|_| cx.focus_handle()).collect(),
+        });
+        cx.run_until_parked();
+        let focus_handles = view.update(cx, |view, _| view.focus_handles.clone());
+
+        cx.update(|cx| cx.focus_next());
+        assert!(cx.update(|cx| focus_handles[0].is_focused(cx)));
+        cx.update(|cx| cx.focus_next());
+        assert!(cx.update(|cx| focus_handles[1].is_focused(cx)));
+
+        cx.update(|cx| cx.focus_prev());
+        cx.update(|cx| cx.focus_prev());
+        assert!(cx.update(|cx| focus_handles[2].is_focused(cx)));
+        cx.update(|cx| cx.focus_next());
+        assert!(cx.update(|cx| focus_handles[0].is_focused(cx)));
+    }
+
+    struct DropView {
+        dropped_paths: Vec<PathBuf>,
+    }
+
+    impl Render for DropView {
+        fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div().id("drop-target").size(px(50.)).on_drop(cx.listener(
+                |this, paths: &ExternalPaths, _| {
+                    this.dropped_paths = paths.paths().to_vec();
+                },
+            ))
+        }
+    }
+
+    #[gpui::test]
+    fn test_emit_system_event(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|_| DropView {
+            dropped_paths: Vec::new(),
+        });
+        cx.run_until_parked();
+
+        let position = point(px(10.), px(10.));
+        let paths = ExternalPaths(smallvec![PathBuf::from("/a.txt")]);
+        cx.update(|cx| {
+            cx.emit_system_event(FileDropEvent::Entered { position, paths });
+            cx.emit_system_event(FileDropEvent::Pending { position });
+            cx.emit_system_event(FileDropEvent::Submit { position });
+        });
+
+        view.update(cx, |view, _| {
+            assert_eq!(view.dropped_paths, vec![PathBuf::from("/a.txt")]);
+        });
+    }
+
+    struct PopupView {
+        focus_handle: FocusHandle,
+    }
+
+    impl EventEmitter<DismissEvent> for PopupView {}
+
+    impl FocusableView for PopupView {
+        fn focus_handle(&self, _cx: &AppContext) -> FocusHandle {
+            self.focus_handle.clone()
+        }
+    }
+
+    impl Render for PopupView {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div().size(px(40.)).track_focus(&self.focus_handle)
+        }
+    }
+
+    struct TextInputRectView;
+
+    impl Render for TextInputRectView {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div().size_full().child(
+                canvas(
+                    |_, _| {},
+                    |_, _, cx| {
+                        cx.set_text_input_rect(Bounds::new(
+                            point(px(12.), px(30.)),
+                            size(px(2.), px(16.)),
+                        ))
+                    },
+                )
+                .size(px(100.)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_text_input_rect(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| TextInputRectView);
+        cx.run_until_parked();
+        assert_eq!(
+            cx.update(|cx| cx.text_input_rect()),
+            Some(Bounds::new(point(px(12.), px(30.)), size(px(2.), px(16.))))
+        );
+    }
+
+    #[gpui::test]
+    fn test_open_popup_at(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|cx| FocusCycleView {
+            focus_handles: vec![cx.focus_handle()],
+        });
+        cx.run_until_parked();
+        let focus_handle = view.update(cx, |view, _| view.focus_handles[0].clone());
+        cx.update(|cx| cx.focus(&focus_handle));
+
+        let open_popup = |cx: &mut gpui::VisualTestContext| {
+            cx.update(|cx| {
+                let popup = cx.new_view(|cx| PopupView {
+                    focus_handle: cx.focus_handle(),
+                });
+                cx.open_popup_at(point(px(10.), px(10.)), popup.clone());
+                popup
+            })
+        };
+
+        let popup = open_popup(cx);
+        cx.run_until_parked();
+        assert!(cx.update(|cx| cx.has_popup()));
+        assert!(cx.update(|cx| popup.read(cx).focus_handle.is_focused(cx)));
+
+        cx.simulate_click(point(px(20.), px(20.)), Modifiers::default());
+        assert!(cx.update(|cx| cx.has_popup()));
+
+        cx.simulate_click(point(px(200.), px(200.)), Modifiers::default());
+        assert!(!cx.update(|cx| cx.has_popup()));
+        assert!(cx.update(|cx| focus_handle.is_focused(cx)));
+
+        open_popup(cx);
+        cx.run_until_parked();
+        cx.simulate_keystrokes("escape");
+        assert!(!cx.update(|cx| cx.has_popup()));
+        assert!(cx.update(|cx| focus_handle.is_focused(cx)));
+
+        let popup = open_popup(cx);
+        cx.run_until_parked();
+        popup.update(cx, |_, cx| cx.emit(DismissEvent));
+        assert!(!cx.update(|cx| cx.has_popup()));
+    }
 }
@@ -519,6 +519,11 @@ impl DispatchTree {
         dispatch_path
     }
 
+    /// Returns the ids of all focusable nodes in the order they were painted.
+    pub fn focus_order(&self) -> Vec<FocusId> {
+        self.nodes.iter().filter_map(|node| node.focus_id).collect()
+    }
+
     pub fn focus_path(&self, focus_id: FocusId) -> SmallVec<[FocusId; 8]> {
         let mut focus_path: SmallVec<[FocusId; 8]> = SmallVec::new();
         let mut current_node_id = self.focusable_node_ids.get(&focus_id).copied();
@@ -175,6 +175,16 @@ pub(crate) trait Platform: 'static {
         options: PathPromptOptions,
     ) -> oneshot::Receiver<Result<Option<Vec<PathBuf>>>>;
     fn prompt_for_new_path(&self, directory: &Path) -> oneshot::Receiver<Result<Option<PathBuf>>>;
+    /// Like [`Platform::prompt_for_new_path`], but also suggests a file name and
+    /// restricts the file types that can be saved. Platforms that don't support
+    /// these options fall back to [`Platform::prompt_for_new_path`].
+    fn prompt_for_save_path(
+        &self,
+        options: SaveDialogOptions,
+    ) -> oneshot::Receiver<Result<Option<PathBuf>>> {
+        let directory = options.initial_directory.unwrap_or_default();
+        self.prompt_for_new_path(&directory)
+    }
     fn reveal_path(&self, path: &Path);
     fn open_with_system(&self, path: &Path);
 
@@ -182,6 +192,12 @@ pub(crate) trait Platform: 'static {
     fn on_reopen(&self, callback: Box<dyn FnMut()>);
     fn on_keyboard_layout_change(&self, callback: Box<dyn FnMut()>);
 
+    fn register_global_hotkey(&self, _id: u32, _keystroke: &Keystroke) -> Result<()> {
+        Err(anyhow!("global hotkeys are not supported on this platform"))
+    }
+    fn unregister_global_hotkey(&self, _id: u32) {}
+    fn on_global_hotkey(&self, _callback: Box<dyn FnMut(u32)>) {}
+
     fn set_menus(&self, menus: Vec<Menu>, keymap: &Keymap);
     fn get_menus(&self) -> Option<Vec<OwnedMenu>> {
         None
@@ -189,6 +205,7 @@ pub(crate) trait Platform: 'static {
 
     fn set_dock_menu(&self, menu: Vec<MenuItem>, keymap: &Keymap);
     fn add_recent_document(&self, _path: &Path) {}
+    fn set_dock_badge(&self, _label: Option<&str>) {}
     fn on_app_menu_action(&self, callback: Box<dyn FnMut(&dyn Action)>);
     fn on_will_open_app_menu(&self, callback: Box<dyn FnMut()>);
     fn on_validate_app_menu_command(&self, callback: Box<dyn FnMut(&dyn Action) -> bool>);
@@ -411,10 +428,18 @@ pub(crate) trait PlatformWindow: HasWindowHandle + HasDisplayHandle {
     fn draw(&self, scene: &Scene);
     fn completed_frame(&self) {}
     fn sprite_atlas(&self) -> Arc<dyn PlatformAtlas>;
+    /// Locks or unlocks the pointer, returning whether it is now locked.
+    fn set_pointer_locked(&self, _locked: bool) -> bool {
+        false
+    }
+    /// Replaces the icon shown for the window by the OS, or restores the default when `None`.
+    /// The bytes are a [`WINDOW_ICON_SIZE`](crate::WINDOW_ICON_SIZE) square of RGBA pixels.
+    fn set_icon(&self, _icon: Option<Arc<[u8]>>) {}
 
     // macOS specific methods
     fn set_edited(&mut self, _edited: bool) {}
     fn show_character_palette(&self) {}
+    fn screen_reader_speak(&self, _message: &str, _priority: SpeechPriority) {}
 
     #[cfg(target_os = "windows")]
     fn get_raw_handle(&self) -> windows::HWND;
@@ -753,7 +778,11 @@ impl PlatformInputHandler {
 
     fn bounds_for_range(&mut self, range_utf16: Range<usize>) -> Option<Bounds<Pixels>> {
         self.cx
-            .update(|cx| self.handler.bounds_for_range(range_utf16, cx))
+            .update(|cx| {
+                self.handler
+                    .bounds_for_range(range_utf16, cx)
+                    .or_else(|| cx.text_input_rect())
+            })
             .ok()
             .flatten()
     }
@@ -768,15 +797,25 @@ impl PlatformInputHandler {
     }
 
     pub fn selected_bounds(&mut self, cx: &mut WindowContext) -> Option<Bounds<Pixels>> {
-        let selection = self.handler.selected_text_range(true, cx)?;
-        self.handler.bounds_for_range(
-            if selection.reversed {
-                selection.range.start..selection.range.start
-            } else {
-                selection.range.end..selection.range.end
-            },
-            cx,
-        )
+        let Some(selection) = self.handler.selected_text_range(true, cx) else {
+            return cx.text_input_rect();
+        };
+        self.handler
+            .bounds_for_range(
+                if selection.reversed {
+                    selection.range.start..selection.range.start
+                } else {
+                    selection.range.end..selection.range.end
+                },
+                cx,
+            )
+            .or_else(|| cx.text_input_rect())
+    }
+
+    /// The text currently marked by an input method, as it composes it.
+    pub fn marked_text(&mut self, cx: &mut WindowContext) -> Option<String> {
+        let range = self.handler.marked_text_range(cx)?;
+        self.handler.text_for_range(range, &mut None, cx)
     }
 }
 
@@ -1060,6 +1099,16 @@ impl Default for WindowAppearance {
     }
 }
 
+/// How urgently a screen reader announcement should be spoken.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SpeechPriority {
+    /// Spoken once the screen reader has finished its current utterance.
+    #[default]
+    Polite,
+    /// Interrupts whatever the screen reader is currently saying.
+    Assertive,
+}
+
 /// The appearance of the background of the window itself, when there is
 /// no content or the content is transparent.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
@@ -1092,6 +1141,18 @@ pub struct PathPromptOptions {
     pub multiple: bool,
 }
 
+/// The options that can be configured for a "Save As" file dialog
+#[derive(Clone, Debug, Default)]
+pub struct SaveDialogOptions {
+    /// The file name initially entered in the dialog
+    pub suggested_filename: Option<SharedString>,
+    /// The directory the dialog initially shows
+    pub initial_directory: Option<PathBuf>,
+    /// The file extensions, without a leading `.`, that can be saved.
+    /// When empty, any file type can be saved.
+    pub allowed_extensions: Vec<SharedString>,
+}
+
 /// What kind of prompt styling to show
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PromptLevel {
@@ -1190,6 +1251,56 @@ pub enum CursorStyle {
     /// A cursor indicating that the operation will result in a context menu
     /// corresponds to the CSS cursor value `context-menu`
     ContextualMenu,
+
+    /// A cursor indicating that something can be zoomed in
+    /// corresponds to the CSS cursor value `zoom-in`
+    ZoomIn,
+
+    /// A cursor indicating that something can be zoomed out
+    /// corresponds to the CSS cursor value `zoom-out`
+    ZoomOut,
+
+    /// A cursor indicating that something can be scrolled in any direction
+    /// corresponds to the CSS cursor value `all-scroll`
+    AllScroll,
+
+    /// A cursor named by its CSS cursor value, such as `help` or `progress`, for cursors
+    /// without a dedicated variant. Platforms without a cursor of that name show the default cursor.
+    Custom(&'static str),
+}
+
+impl CursorStyle {
+    /// Returns the cursor style for the given CSS cursor value. Values without a
+    /// dedicated variant are returned as [`CursorStyle::Custom`].
+    pub fn from_css_name(name: &'static str) -> Self {
+        match name {
+            "default" => Self::Arrow,
+            "text" => Self::IBeam,
+            "crosshair" => Self::Crosshair,
+            "grabbing" => Self::ClosedHand,
+            "grab" => Self::OpenHand,
+            "pointer" => Self::PointingHand,
+            "w-resize" => Self::ResizeLeft,
+            "e-resize" => Self::ResizeRight,
+            "ew-resize" => Self::ResizeLeftRight,
+            "n-resize" => Self::ResizeUp,
+            "s-resize" => Self::ResizeDown,
+            "ns-resize" => Self::ResizeUpDown,
+            "nwse-resize" => Self::ResizeUpLeftDownRight,
+            "nesw-resize" => Self::ResizeUpRightDownLeft,
+            "col-resize" => Self::ResizeColumn,
+            "row-resize" => Self::ResizeRow,
+            "vertical-text" => Self::IBeamCursorForVerticalLayout,
+            "not-allowed" => Self::OperationNotAllowed,
+            "alias" => Self::DragLink,
+            "copy" => Self::DragCopy,
+            "context-menu" => Self::ContextualMenu,
+            "zoom-in" => Self::ZoomIn,
+            "zoom-out" => Self::ZoomOut,
+            "all-scroll" => Self::AllScroll,
+            name => Self::Custom(name),
+        }
+    }
 }
 
 impl Default for CursorStyle {
@@ -27,7 +27,7 @@ use crate::{
     px, Action, AnyWindowHandle, BackgroundExecutor, ClipboardItem, CursorStyle, DisplayId,
     ForegroundExecutor, Keymap, LinuxDispatcher, Menu, MenuItem, OwnedMenu, PathPromptOptions,
     Pixels, Platform, PlatformDisplay, PlatformTextSystem, PlatformWindow, Point, Result,
-    ScreenCaptureSource, Task, WindowAppearance, WindowParams,
+    SaveDialogOptions, ScreenCaptureSource, Task, WindowAppearance, WindowParams,
 };
 #[cfg(any(feature = "wayland", feature = "x11"))]
 pub(crate) const SCROLL_LINES: f32 = 3.0;
@@ -323,48 +323,72 @@ impl<P: LinuxClient + 'static> Platform for P {
     }
 
     fn prompt_for_new_path(&self, directory: &Path) -> oneshot::Receiver<Result<Option<PathBuf>>> {
+        self.prompt_for_save_path(SaveDialogOptions {
+            initial_directory: Some(directory.to_owned()),
+            ..Default::default()
+        })
+    }
+
+    fn prompt_for_save_path(
+        &self,
+        options: SaveDialogOptions,
+    ) -> oneshot::Receiver<Result<Option<PathBuf>>> {
         let (done_tx, done_rx) = oneshot::channel();
 
         #[cfg(not(any(feature = "wayland", feature = "x11")))]
-        let _ = (done_tx.send(Ok(None)), directory);
+        let _ = (done_tx.send(Ok(None)), options);
 
         #[cfg(any(feature = "wayland", feature = "x11"))]
         self.foreground_executor()
-            .spawn({
-                let directory = directory.to_owned();
+            .spawn(async move {
+                use ashpd::desktop::file_chooser::{FileFilter, SaveFileRequest};
 
-                async move {
-                    let request = match ashpd::desktop::file_chooser::SaveFileRequest::default()
-                        .modal(true)
-                        .title("Save File")
+                let mut request = SaveFileRequest::default()
+                    .modal(true)
+                    .title("Save File")
+                    .current_name(options.suggested_filename.as_deref());
+                if let Some(directory) = options.initial_directory {
+                    request = request
                         .current_folder(directory)
-                        .expect("pathbuf should not be nul terminated")
-                        .send()
-                        .await
-                    {
-                        Ok(request) => request,
-                        Err(err) => {
-                            let result = match err {
-                                ashpd::Error::PortalNotFound(_) => {
-                                    anyhow!(FILE_PICKER_PORTAL_MISSING)
-                                }
-                                err => err.into(),
-                            };
-                            let _ = done_tx.send(Err(result));
-                            return;
-                        }
-                    };
-
-                    let result = match request.response() {
-                        Ok(response) => Ok(response
-                            .uris()
-                            .first()
-                            .and_then(|uri| uri.to_file_path().ok())),
-                        Err(ashpd::Error::Response(_)) => Ok(None),
-                        Err(e) => Err(e.into()),
-                    };
-                    let _ = done_tx.send(result);
+                        .expect("pathbuf should not be nul terminated");
                 }
+                if !options.allowed_extensions.is_empty() {
+                    let patterns = options
+                        .allowed_extensions
+                        .iter()
+                        .map(|extension| format!("*.{extension}"))
+                        .collect::<Vec<_>>();
+                    let filter = patterns
+                        .iter()
+                        .fold(FileFilter::new(&patterns.join(", ")), |filter, pattern| {
+                            filter.glob(pattern)
+                        });
+                    request = request.filter(filter);
+                }
+
+                let request = match request.send().await {
+                    Ok(request) => request,
+                    Err(err) => {
+                        let result = match err {
+                            ashpd::Error::PortalNotFound(_) => {
+                                anyhow!(FILE_PICKER_PORTAL_MISSING)
+                            }
+                            err => err.into(),
+                        };
+                        let _ = done_tx.send(Err(result));
+                        return;
+                    }
+                };
+
+                let result = match request.response() {
+                    Ok(response) => Ok(response
+                        .uris()
+                        .first()
+                        .and_then(|uri| uri.to_file_path().ok())),
+                    Err(ashpd::Error::Response(_)) => Ok(None),
+                    Err(e) => Err(e.into()),
+                };
+                let _ = done_tx.send(result);
             })
             .detach();
 
@@ -672,6 +696,10 @@ impl CursorStyle {
             CursorStyle::DragLink => "alias",
             CursorStyle::DragCopy => "copy",
             CursorStyle::ContextualMenu => "context-menu",
+            CursorStyle::ZoomIn => "zoom-in",
+            CursorStyle::ZoomOut => "zoom-out",
+            CursorStyle::AllScroll => "all-scroll",
+            CursorStyle::Custom(name) => name,
         }
         .to_string()
     }
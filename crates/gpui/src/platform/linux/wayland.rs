@@ -35,6 +35,22 @@ impl CursorStyle {
             CursorStyle::DragLink => Shape::Alias,
             CursorStyle::DragCopy => Shape::Copy,
             CursorStyle::ContextualMenu => Shape::ContextMenu,
+            CursorStyle::ZoomIn => Shape::ZoomIn,
+            CursorStyle::ZoomOut => Shape::ZoomOut,
+            CursorStyle::AllScroll => Shape::AllScroll,
+            CursorStyle::Custom(name) => match name {
+                "help" => Shape::Help,
+                "progress" => Shape::Progress,
+                "wait" => Shape::Wait,
+                "cell" => Shape::Cell,
+                "move" => Shape::Move,
+                "no-drop" => Shape::NoDrop,
+                "ne-resize" => Shape::NeResize,
+                "nw-resize" => Shape::NwResize,
+                "se-resize" => Shape::SeResize,
+                "sw-resize" => Shape::SwResize,
+                _ => Shape::Default,
+            },
         }
     }
 }
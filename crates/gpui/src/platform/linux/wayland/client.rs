@@ -43,6 +43,9 @@ use wayland_protocols::wp::cursor_shape::v1::client::{
 use wayland_protocols::wp::fractional_scale::v1::client::{
     wp_fractional_scale_manager_v1, wp_fractional_scale_v1,
 };
+use wayland_protocols::wp::pointer_constraints::zv1::client::{
+    zwp_locked_pointer_v1, zwp_pointer_constraints_v1,
+};
 use wayland_protocols::wp::primary_selection::zv1::client::zwp_primary_selection_offer_v1::{
     self, ZwpPrimarySelectionOfferV1,
 };
@@ -50,6 +53,9 @@ use wayland_protocols::wp::primary_selection::zv1::client::{
     zwp_primary_selection_device_manager_v1, zwp_primary_selection_device_v1,
     zwp_primary_selection_source_v1,
 };
+use wayland_protocols::wp::relative_pointer::zv1::client::{
+    zwp_relative_pointer_manager_v1, zwp_relative_pointer_v1,
+};
 use wayland_protocols::wp::text_input::zv3::client::zwp_text_input_v3::{
     ContentHint, ContentPurpose,
 };
@@ -112,6 +118,9 @@ pub struct Globals {
     pub decoration_manager: Option<zxdg_decoration_manager_v1::ZxdgDecorationManagerV1>,
     pub blur_manager: Option<org_kde_kwin_blur_manager::OrgKdeKwinBlurManager>,
     pub text_input_manager: Option<zwp_text_input_manager_v3::ZwpTextInputManagerV3>,
+    pub relative_pointer_manager:
+        Option<zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1>,
+    pub pointer_constraints: Option<zwp_pointer_constraints_v1::ZwpPointerConstraintsV1>,
     pub executor: ForegroundExecutor,
 }
 
@@ -149,6 +158,8 @@ impl Globals {
             decoration_manager: globals.bind(&qh, 1..=1, ()).ok(),
             blur_manager: globals.bind(&qh, 1..=1, ()).ok(),
             text_input_manager: globals.bind(&qh, 1..=1, ()).ok(),
+            relative_pointer_manager: globals.bind(&qh, 1..=1, ()).ok(),
+            pointer_constraints: globals.bind(&qh, 1..=1, ()).ok(),
             executor,
             qh,
         }
@@ -196,6 +207,10 @@ pub(crate) struct WaylandClientState {
     data_device: Option<wl_data_device::WlDataDevice>,
     primary_selection: Option<zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1>,
     text_input: Option<zwp_text_input_v3::ZwpTextInputV3>,
+    locked_pointer: Option<zwp_locked_pointer_v1::ZwpLockedPointerV1>,
+    relative_pointer: Option<zwp_relative_pointer_v1::ZwpRelativePointerV1>,
+    // The virtual mouse position, accumulated from relative motion while the pointer is locked.
+    pointer_lock_position: Option<Point<Pixels>>,
     pre_edit_text: Option<String>,
     ime_pre_edit: Option<String>,
     composing: bool,
@@ -282,6 +297,56 @@ impl WaylandClientStatePtr {
             Some(PendingActivation::Window(window));
     }
 
+    /// Locks the pointer to the given surface with the pointer constraints protocol, hiding
+    /// the cursor and reporting relative motion instead. Returns whether the pointer is locked,
+    /// which requires the compositor to support both the pointer constraints and relative
+    /// pointer protocols.
+    pub fn set_pointer_locked(&self, surface: &wl_surface::WlSurface, locked: bool) -> bool {
+        let client = self.get_client();
+        let mut state = client.borrow_mut();
+
+        if !locked {
+            if let Some(locked_pointer) = state.locked_pointer.take() {
+                locked_pointer.destroy();
+            }
+            if let Some(relative_pointer) = state.relative_pointer.take() {
+                relative_pointer.destroy();
+            }
+            state.pointer_lock_position = None;
+            // Force the next `set_cursor_style` call to restore the cursor.
+            state.cursor_style = None;
+            return false;
+        }
+
+        if state.locked_pointer.is_some() {
+            return true;
+        }
+        let (Some(pointer_constraints), Some(relative_pointer_manager), Some(wl_pointer)) = (
+            state.globals.pointer_constraints.clone(),
+            state.globals.relative_pointer_manager.clone(),
+            state.wl_pointer.clone(),
+        ) else {
+            return false;
+        };
+
+        let qh = state.globals.qh.clone();
+        state.locked_pointer = Some(pointer_constraints.lock_pointer(
+            surface,
+            &wl_pointer,
+            None,
+            zwp_pointer_constraints_v1::Lifetime::Persistent,
+            &qh,
+            (),
+        ));
+        state.relative_pointer =
+            Some(relative_pointer_manager.get_relative_pointer(&wl_pointer, &qh, ()));
+        state.pointer_lock_position = Some(state.mouse_location.unwrap_or_default());
+
+        let serial = state.serial_tracker.get(SerialKind::MouseEnter);
+        wl_pointer.set_cursor(serial, None, 0, 0);
+        true
+    }
+
     pub fn enable_ime(&self) {
         let client = self.get_client();
         let mut state = client.borrow_mut();
@@ -525,6 +590,9 @@ impl WaylandClient {
             data_device,
             primary_selection,
             text_input: None,
+            locked_pointer: None,
+            relative_pointer: None,
+            pointer_lock_position: None,
             pre_edit_text: None,
             ime_pre_edit: None,
             composing: false,
@@ -645,6 +713,10 @@ impl LinuxClient for WaylandClient {
 
     fn set_cursor_style(&self, style: CursorStyle) {
         let mut state = self.0.borrow_mut();
+        // The cursor stays hidden while the pointer is locked.
+        if state.locked_pointer.is_some() {
+            return;
+        }
 
         let need_update = state
             .cursor_style
@@ -857,6 +929,9 @@ delegate_noop!(WaylandClientStatePtr: ignore wp_fractional_scale_manager_v1::WpF
 delegate_noop!(WaylandClientStatePtr: ignore zxdg_decoration_manager_v1::ZxdgDecorationManagerV1);
 delegate_noop!(WaylandClientStatePtr: ignore org_kde_kwin_blur_manager::OrgKdeKwinBlurManager);
 delegate_noop!(WaylandClientStatePtr: ignore zwp_text_input_manager_v3::ZwpTextInputManagerV3);
+delegate_noop!(WaylandClientStatePtr: ignore zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1);
+delegate_noop!(WaylandClientStatePtr: ignore zwp_pointer_constraints_v1::ZwpPointerConstraintsV1);
+delegate_noop!(WaylandClientStatePtr: ignore zwp_locked_pointer_v1::ZwpLockedPointerV1);
 delegate_noop!(WaylandClientStatePtr: ignore org_kde_kwin_blur::OrgKdeKwinBlur);
 delegate_noop!(WaylandClientStatePtr: ignore wp_viewporter::WpViewporter);
 delegate_noop!(WaylandClientStatePtr: ignore wp_viewport::WpViewport);
@@ -1313,6 +1388,42 @@ impl Dispatch<wl_keyboard::WlKeyboard, ()> for WaylandClientStatePtr {
         }
     }
 }
+impl Dispatch<zwp_relative_pointer_v1::ZwpRelativePointerV1, ()> for WaylandClientStatePtr {
+    fn event(
+        this: &mut Self,
+        _: &zwp_relative_pointer_v1::ZwpRelativePointerV1,
+        event: zwp_relative_pointer_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        let client = this.get_client();
+        let mut state = client.borrow_mut();
+
+        if let zwp_relative_pointer_v1::Event::RelativeMotion { dx, dy, .. } = event {
+            // The locked pointer doesn't move, so report a virtual position built up from
+            // the deltas of each motion instead.
+            let Some(position) = state.pointer_lock_position.as_mut() else {
+                return;
+            };
+            position.x += px(dx as f32);
+            position.y += px(dy as f32);
+            let position = *position;
+            state.mouse_location = Some(position);
+
+            if let Some(window) = state.mouse_focused_window.clone() {
+                let input = PlatformInput::MouseMove(MouseMoveEvent {
+                    position,
+                    pressed_button: state.button_pressed,
+                    modifiers: state.modifiers,
+                });
+                drop(state);
+                window.handle_input(input);
+            }
+        }
+    }
+}
+
 impl Dispatch<zwp_text_input_v3::ZwpTextInputV3, ()> for WaylandClientStatePtr {
     fn event(
         this: &mut Self,
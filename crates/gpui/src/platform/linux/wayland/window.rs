@@ -977,6 +977,11 @@ impl PlatformWindow for WaylandWindow {
         );
     }
 
+    fn set_pointer_locked(&self, locked: bool) -> bool {
+        let state = self.borrow();
+        state.client.set_pointer_locked(&state.surface, locked)
+    }
+
     fn start_window_move(&self) {
         let state = self.borrow();
         let serial = state.client.get_serial(SerialKind::MousePress);
@@ -6,7 +6,7 @@ use crate::{
     Modifiers, Pixels, PlatformAtlas, PlatformDisplay, PlatformInput, PlatformInputHandler,
     PlatformWindow, Point, PromptLevel, RequestFrameOptions, ResizeEdge, ScaledPixels, Scene, Size,
     Tiling, WindowAppearance, WindowBackgroundAppearance, WindowBounds, WindowDecorations,
-    WindowKind, WindowParams, X11ClientStatePtr,
+    WindowKind, WindowParams, X11ClientStatePtr, WINDOW_ICON_SIZE,
 };
 
 use blade_graphics as gpu;
@@ -57,6 +57,7 @@ x11rb::atom_manager! {
         WM_CHANGE_STATE,
         _NET_WM_PID,
         _NET_WM_NAME,
+        _NET_WM_ICON,
         _NET_WM_STATE,
         _NET_WM_STATE_MAXIMIZED_VERT,
         _NET_WM_STATE_MAXIMIZED_HORZ,
@@ -1282,6 +1283,39 @@ impl PlatformWindow for X11Window {
         log::info!("ignoring macOS specific set_edited");
     }
 
+    fn set_icon(&self, icon: Option<Arc<[u8]>>) {
+        let net_wm_icon = self.0.state.borrow().atoms._NET_WM_ICON;
+        match icon {
+            Some(rgba) => {
+                // _NET_WM_ICON is the width and height followed by ARGB pixels, one per CARDINAL.
+                let mut data = vec![WINDOW_ICON_SIZE, WINDOW_ICON_SIZE];
+                data.extend(
+                    rgba.chunks_exact(4)
+                        .map(|pixel| u32::from_be_bytes([pixel[3], pixel[0], pixel[1], pixel[2]])),
+                );
+                check_reply(
+                    || "X11 ChangeProperty32 on _NET_WM_ICON failed.",
+                    self.0.xcb.change_property32(
+                        xproto::PropMode::REPLACE,
+                        self.0.x_window,
+                        net_wm_icon,
+                        xproto::AtomEnum::CARDINAL,
+                        &data,
+                    ),
+                )
+                .log_err();
+            }
+            None => {
+                check_reply(
+                    || "X11 DeleteProperty on _NET_WM_ICON failed.",
+                    self.0.xcb.delete_property(self.0.x_window, net_wm_icon),
+                )
+                .log_err();
+            }
+        }
+        self.flush().log_err();
+    }
+
     fn set_background_appearance(&self, background_appearance: WindowBackgroundAppearance) {
         let mut state = self.0.state.borrow_mut();
         state.background_appearance = background_appearance;
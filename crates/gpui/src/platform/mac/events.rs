@@ -238,7 +238,7 @@ impl PlatformInput {
     }
 }
 
-unsafe fn parse_keystroke(native_event: id) -> Keystroke {
+pub(crate) unsafe fn parse_keystroke(native_event: id) -> Keystroke {
     use cocoa::appkit::*;
 
     let mut characters = native_event
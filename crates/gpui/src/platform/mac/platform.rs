@@ -1,14 +1,14 @@
 use super::{
     attributed_string::{NSAttributedString, NSMutableAttributedString},
-    events::key_to_native,
+    events::{key_to_native, parse_keystroke},
     renderer, screen_capture, BoolExt,
 };
 use crate::{
     hash, Action, AnyWindowHandle, BackgroundExecutor, ClipboardEntry, ClipboardItem,
-    ClipboardString, CursorStyle, ForegroundExecutor, Image, ImageFormat, Keymap, MacDispatcher,
-    MacDisplay, MacWindow, Menu, MenuItem, PathPromptOptions, Platform, PlatformDisplay,
-    PlatformTextSystem, PlatformWindow, Result, ScreenCaptureSource, SemanticVersion, Task,
-    WindowAppearance, WindowParams,
+    ClipboardString, CursorStyle, ForegroundExecutor, Image, ImageFormat, Keymap, Keystroke,
+    MacDispatcher, MacDisplay, MacWindow, Menu, MenuItem, PathPromptOptions, Platform,
+    PlatformDisplay, PlatformTextSystem, PlatformWindow, Result, SaveDialogOptions,
+    ScreenCaptureSource, SemanticVersion, Task, WindowAppearance, WindowParams,
 };
 use anyhow::{anyhow, Context as _};
 use block::ConcreteBlock;
@@ -30,10 +30,14 @@ use core_foundation::{
     boolean::CFBoolean,
     data::CFData,
     dictionary::{CFDictionary, CFDictionaryRef, CFMutableDictionary},
-    runloop::CFRunLoopRun,
+    runloop::{kCFRunLoopCommonModes, CFRunLoop, CFRunLoopRun, CFRunLoopSource},
     string::{CFString, CFStringRef},
 };
+use core_graphics::event::{
+    CGEvent, CGEventTap, CGEventTapLocation, CGEventTapOptions, CGEventTapPlacement, CGEventType,
+};
 use ctor::ctor;
+use foreign_types::ForeignType;
 use futures::channel::oneshot;
 use objc::{
     class,
@@ -163,6 +167,8 @@ pub(crate) struct MacPlatformState {
     will_open_menu: Option<Box<dyn FnMut()>>,
     menu_actions: Vec<Box<dyn Action>>,
     open_urls: Option<Box<dyn FnMut(Vec<String>)>>,
+    global_hotkeys: Arc<Mutex<GlobalHotkeys>>,
+    global_hotkey_tap: Option<(CGEventTap<'static>, CFRunLoopSource)>,
     finish_launching: Option<Box<dyn FnOnce()>>,
     dock_menu: Option<id>,
 }
@@ -199,6 +205,8 @@ impl MacPlatform {
             will_open_menu: None,
             menu_actions: Default::default(),
             open_urls: None,
+            global_hotkeys: Default::default(),
+            global_hotkey_tap: None,
             finish_launching: None,
             dock_menu: None,
             on_keyboard_layout_change: None,
@@ -697,15 +705,38 @@ impl Platform for MacPlatform {
     }
 
     fn prompt_for_new_path(&self, directory: &Path) -> oneshot::Receiver<Result<Option<PathBuf>>> {
-        let directory = directory.to_owned();
+        self.prompt_for_save_path(SaveDialogOptions {
+            initial_directory: Some(directory.to_owned()),
+            ..Default::default()
+        })
+    }
+
+    fn prompt_for_save_path(
+        &self,
+        options: SaveDialogOptions,
+    ) -> oneshot::Receiver<Result<Option<PathBuf>>> {
         let (done_tx, done_rx) = oneshot::channel();
         self.foreground_executor()
             .spawn(async move {
                 unsafe {
                     let panel = NSSavePanel::savePanel(nil);
-                    let path = ns_string(directory.to_string_lossy().as_ref());
-                    let url = NSURL::fileURLWithPath_isDirectory_(nil, path, true.to_objc());
-                    panel.setDirectoryURL(url);
+                    if let Some(directory) = options.initial_directory.as_ref() {
+                        let path = ns_string(directory.to_string_lossy().as_ref());
+                        let url = NSURL::fileURLWithPath_isDirectory_(nil, path, true.to_objc());
+                        panel.setDirectoryURL(url);
+                    }
+                    if let Some(filename) = options.suggested_filename.as_ref() {
+                        let _: () = msg_send![panel, setNameFieldStringValue: ns_string(filename)];
+                    }
+                    if !options.allowed_extensions.is_empty() {
+                        let extensions = options
+                            .allowed_extensions
+                            .iter()
+                            .map(|extension| ns_string(extension))
+                            .collect::<Vec<_>>();
+                        let extensions = NSArray::arrayWithObjects(nil, &extensions);
+                        let _: () = msg_send![panel, setAllowedFileTypes: extensions];
+                    }
 
                     let done_tx = Cell::new(Some(done_tx));
                     let block = ConcreteBlock::new(move |response: NSModalResponse| {
@@ -806,6 +837,67 @@ impl Platform for MacPlatform {
         self.0.lock().on_keyboard_layout_change = Some(callback);
     }
 
+    fn register_global_hotkey(&self, id: u32, keystroke: &Keystroke) -> Result<()> {
+        let mut state = self.0.lock();
+        if state.global_hotkey_tap.is_none() {
+            let global_hotkeys = state.global_hotkeys.clone();
+            // A filtering tap, so that the hotkeys can be swallowed instead of also
+            // reaching the application that has focus.
+            let tap = CGEventTap::new(
+                CGEventTapLocation::Session,
+                CGEventTapPlacement::HeadInsertEventTap,
+                CGEventTapOptions::Default,
+                vec![CGEventType::KeyDown],
+                move |_, _, event| {
+                    if handle_global_hotkey_event(&global_hotkeys, event) {
+                        event.set_type(CGEventType::Null);
+                    }
+                    None
+                },
+            )
+            .map_err(|_| {
+                anyhow!("failed to create an event tap, accessibility access may be required")
+            })?;
+            let source = tap
+                .mach_port
+                .create_runloop_source(0)
+                .map_err(|_| anyhow!("failed to create a run loop source for the event tap"))?;
+            unsafe {
+                CFRunLoop::get_main().add_source(&source, kCFRunLoopCommonModes);
+            }
+            tap.enable();
+            state.global_hotkey_tap = Some((tap, source));
+        }
+        state
+            .global_hotkeys
+            .lock()
+            .hotkeys
+            .push((id, keystroke.clone()));
+        Ok(())
+    }
+
+    fn unregister_global_hotkey(&self, id: u32) {
+        let mut state = self.0.lock();
+        let no_hotkeys_left = {
+            let mut global_hotkeys = state.global_hotkeys.lock();
+            global_hotkeys
+                .hotkeys
+                .retain(|(hotkey_id, _)| *hotkey_id != id);
+            global_hotkeys.hotkeys.is_empty()
+        };
+        if no_hotkeys_left {
+            if let Some((_tap, source)) = state.global_hotkey_tap.take() {
+                unsafe {
+                    CFRunLoop::get_main().remove_source(&source, kCFRunLoopCommonModes);
+                }
+            }
+        }
+    }
+
+    fn on_global_hotkey(&self, callback: Box<dyn FnMut(u32)>) {
+        self.0.lock().global_hotkeys.lock().callback = Some(callback);
+    }
+
     fn on_app_menu_action(&self, callback: Box<dyn FnMut(&dyn Action)>) {
         self.0.lock().menu_command = Some(callback);
     }
@@ -879,6 +971,15 @@ impl Platform for MacPlatform {
         }
     }
 
+    fn set_dock_badge(&self, label: Option<&str>) {
+        unsafe {
+            let app: id = msg_send![APP_CLASS, sharedApplication];
+            let dock_tile: id = msg_send![app, dockTile];
+            let label = label.map_or(nil, |label| ns_string(label));
+            let _: () = msg_send![dock_tile, setBadgeLabel: label];
+        }
+    }
+
     fn path_for_auxiliary_executable(&self, name: &str) -> Result<PathBuf> {
         unsafe {
             let bundle: id = NSBundle::mainBundle();
@@ -934,6 +1035,12 @@ impl Platform for MacPlatform {
                 CursorStyle::DragLink => msg_send![class!(NSCursor), dragLinkCursor],
                 CursorStyle::DragCopy => msg_send![class!(NSCursor), dragCopyCursor],
                 CursorStyle::ContextualMenu => msg_send![class!(NSCursor), contextualMenuCursor],
+
+                // Undocumented, private class methods, so check that they exist first.
+                CursorStyle::ZoomIn => cursor_for_selector(sel!(_zoomInCursor)),
+                CursorStyle::ZoomOut => cursor_for_selector(sel!(_zoomOutCursor)),
+                CursorStyle::AllScroll => cursor_for_selector(sel!(_moveCursor)),
+                CursorStyle::Custom(name) => cursor_for_css_name(name),
             };
 
             let old_cursor: id = msg_send![class!(NSCursor), currentCursor];
@@ -1177,6 +1284,41 @@ impl Platform for MacPlatform {
     }
 }
 
+/// The registered global hotkeys, shared with the event tap that listens for them.
+#[derive(Default)]
+struct GlobalHotkeys {
+    hotkeys: Vec<(u32, Keystroke)>,
+    callback: Option<Box<dyn FnMut(u32)>>,
+}
+
+/// Calls the global hotkey callback if the event is a registered hotkey, returning
+/// whether it was.
+fn handle_global_hotkey_event(global_hotkeys: &Mutex<GlobalHotkeys>, event: &CGEvent) -> bool {
+    let keystroke = unsafe {
+        let native_event: id = msg_send![class!(NSEvent), eventWithCGEvent: event.as_ptr()];
+        if native_event == nil {
+            return false;
+        }
+        parse_keystroke(native_event)
+    };
+
+    let mut lock = global_hotkeys.lock();
+    let Some(id) = lock
+        .hotkeys
+        .iter()
+        .find(|(_, hotkey)| keystroke.should_match(hotkey))
+        .map(|(id, _)| *id)
+    else {
+        return false;
+    };
+    if let Some(mut callback) = lock.callback.take() {
+        drop(lock);
+        callback(id);
+        global_hotkeys.lock().callback.get_or_insert(callback);
+    }
+    true
+}
+
 impl MacPlatform {
     unsafe fn read_string_from_clipboard(
         &self,
@@ -1440,6 +1582,39 @@ extern "C" fn handle_dock_menu(this: &mut Object, _: Sel, _: id) -> id {
     }
 }
 
+/// Returns the cursor returned by the given `NSCursor` class method, or the arrow
+/// cursor if `NSCursor` doesn't respond to it.
+unsafe fn cursor_for_selector(selector: Sel) -> id {
+    let responds: BOOL = msg_send![class!(NSCursor), respondsToSelector: selector];
+    if responds == YES {
+        msg_send![class!(NSCursor), performSelector: selector]
+    } else {
+        msg_send![class!(NSCursor), arrowCursor]
+    }
+}
+
+/// Looks up the cursor for a CSS cursor name following the naming of `NSCursor`'s
+/// class methods, so `zoom-in` is looked up as `zoomInCursor`, then `_zoomInCursor`.
+unsafe fn cursor_for_css_name(name: &str) -> id {
+    let mut method = String::new();
+    for (ix, word) in name.split('-').enumerate() {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) if ix > 0 => method.extend(first.to_uppercase().chain(chars)),
+            _ => method.push_str(word),
+        }
+    }
+    method.push_str("Cursor");
+
+    let selector = Sel::register(&method);
+    let responds: BOOL = msg_send![class!(NSCursor), respondsToSelector: selector];
+    if responds == YES {
+        msg_send![class!(NSCursor), performSelector: selector]
+    } else {
+        cursor_for_selector(Sel::register(&format!("_{method}")))
+    }
+}
+
 unsafe fn ns_string(string: &str) -> id {
     NSString::alloc(nil).init_str(string).autorelease()
 }
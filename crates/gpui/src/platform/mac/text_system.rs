@@ -682,6 +682,8 @@ mod tests {
         let mut style = FontRun {
             font_id,
             len: line.len(),
+            letter_spacing: px(0.),
+            word_spacing: px(0.),
         };
 
         let layout = fonts.layout_line(line, px(16.), &[style]);
@@ -4,8 +4,8 @@ use crate::{
     ExternalPaths, FileDropEvent, ForegroundExecutor, KeyDownEvent, Keystroke, Modifiers,
     ModifiersChangedEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, Pixels,
     PlatformAtlas, PlatformDisplay, PlatformInput, PlatformWindow, Point, PromptLevel,
-    RequestFrameOptions, ScaledPixels, Size, Timer, WindowAppearance, WindowBackgroundAppearance,
-    WindowBounds, WindowKind, WindowParams,
+    RequestFrameOptions, ScaledPixels, Size, SpeechPriority, Timer, WindowAppearance,
+    WindowBackgroundAppearance, WindowBounds, WindowKind, WindowParams, WINDOW_ICON_SIZE,
 };
 use block::ConcreteBlock;
 use cocoa::{
@@ -17,8 +17,8 @@ use cocoa::{
     },
     base::{id, nil},
     foundation::{
-        NSArray, NSAutoreleasePool, NSDictionary, NSFastEnumeration, NSInteger, NSPoint, NSRect,
-        NSSize, NSString, NSUInteger,
+        NSArray, NSAutoreleasePool, NSData, NSDictionary, NSFastEnumeration, NSInteger, NSPoint,
+        NSRect, NSSize, NSString, NSUInteger,
     },
 };
 use core_graphics::display::{CGDirectDisplayID, CGPoint, CGRect};
@@ -88,8 +88,22 @@ extern "C" {
         window_id: NSInteger,
         radius: i64,
     ) -> i32;
+    fn CGAssociateMouseAndMouseCursorPosition(connected: BOOL) -> i32;
 }
 
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {
+    static NSAccessibilityAnnouncementRequestedNotification: id;
+    static NSAccessibilityAnnouncementKey: id;
+    static NSAccessibilityPriorityKey: id;
+    fn NSAccessibilityPostNotificationWithUserInfo(element: id, notification: id, user_info: id);
+}
+
+#[allow(non_upper_case_globals)]
+const NSAccessibilityPriorityMedium: NSInteger = 50;
+#[allow(non_upper_case_globals)]
+const NSAccessibilityPriorityHigh: NSInteger = 90;
+
 #[ctor]
 unsafe fn build_classes() {
     WINDOW_CLASS = build_window_class("GPUIWindow", class!(NSWindow));
@@ -332,6 +346,8 @@ struct MacWindowState {
     // Whether the next left-mouse click is also the focusing click.
     first_mouse: bool,
     fullscreen_restore_bounds: Bounds<Pixels>,
+    // The virtual mouse position, accumulated from deltas while the pointer is locked.
+    pointer_lock_position: Option<Point<Pixels>>,
 }
 
 impl MacWindowState {
@@ -610,6 +626,7 @@ impl MacWindow {
                 external_files_dragged: false,
                 first_mouse: false,
                 fullscreen_restore_bounds: Bounds::default(),
+                pointer_lock_position: None,
             })));
 
             (*native_window).set_ivar(
@@ -1004,6 +1021,15 @@ impl PlatformWindow for MacWindow {
         self.0.lock().move_traffic_light();
     }
 
+    fn set_pointer_locked(&self, locked: bool) -> bool {
+        let position = locked.then(|| self.mouse_position());
+        self.0.lock().pointer_lock_position = position;
+        unsafe {
+            CGAssociateMouseAndMouseCursorPosition(!locked as BOOL);
+        }
+        locked
+    }
+
     fn show_character_palette(&self) {
         let this = self.0.lock();
         let window = this.native_window;
@@ -1017,6 +1043,62 @@ impl PlatformWindow for MacWindow {
             .detach();
     }
 
+    fn screen_reader_speak(&self, message: &str, priority: SpeechPriority) {
+        let priority = match priority {
+            SpeechPriority::Polite => NSAccessibilityPriorityMedium,
+            SpeechPriority::Assertive => NSAccessibilityPriorityHigh,
+        };
+        unsafe {
+            let priority: id = msg_send![class!(NSNumber), numberWithInteger: priority];
+            let user_info = NSDictionary::dictionaryWithObjects_forKeys_(
+                nil,
+                NSArray::arrayWithObjects(nil, &[ns_string(message), priority]),
+                NSArray::arrayWithObjects(
+                    nil,
+                    &[NSAccessibilityAnnouncementKey, NSAccessibilityPriorityKey],
+                ),
+            );
+            // Announcements are posted on the application element so they are spoken
+            // regardless of which element currently has accessibility focus.
+            let app = NSApplication::sharedApplication(nil);
+            NSAccessibilityPostNotificationWithUserInfo(
+                app,
+                NSAccessibilityAnnouncementRequestedNotification,
+                user_info,
+            );
+        }
+    }
+
+    fn set_icon(&self, icon: Option<Arc<[u8]>>) {
+        // AppKit has no per-window icon, so this replaces the application's dock icon.
+        let png = icon.and_then(|rgba| {
+            let image =
+                image::RgbaImage::from_raw(WINDOW_ICON_SIZE, WINDOW_ICON_SIZE, rgba.to_vec())?;
+            let mut png = Vec::new();
+            image
+                .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                .log_err()?;
+            Some(png)
+        });
+        unsafe {
+            let ns_image: id = match png {
+                Some(png) => {
+                    let data = NSData::dataWithBytes_length_(
+                        nil,
+                        png.as_ptr() as *const c_void,
+                        png.len() as u64,
+                    );
+                    let ns_image: id = msg_send![class!(NSImage), alloc];
+                    let ns_image: id = msg_send![ns_image, initWithData: data];
+                    msg_send![ns_image, autorelease]
+                }
+                None => nil,
+            };
+            let app = NSApplication::sharedApplication(nil);
+            let _: () = msg_send![app, setApplicationIconImage: ns_image];
+        }
+    }
+
     fn minimize(&self) {
         let window = self.0.lock().native_window;
         unsafe {
@@ -1367,6 +1449,17 @@ extern "C" fn handle_view_event(this: &Object, _: Sel, native_event: id) {
                 lock.first_mouse = false;
             }
 
+            // While the pointer is locked the cursor doesn't move, so we report
+            // a virtual position built up from the deltas of each mouse move.
+            PlatformInput::MouseMove(event) if lock.pointer_lock_position.is_some() => {
+                let position = lock.pointer_lock_position.as_mut().unwrap();
+                unsafe {
+                    position.x += px(native_event.deltaX() as f32);
+                    position.y += px(native_event.deltaY() as f32);
+                }
+                event.position = *position;
+            }
+
             // Because we map a ctrl-left_down to a right_down -> right_up let's ignore
             // the ctrl-left_up to avoid having a mismatch in button down/up events if the
             // user is still holding ctrl when releasing the left mouse button
@@ -18,6 +18,7 @@ use crate::*;
 
 pub(crate) const CURSOR_STYLE_CHANGED: u32 = WM_USER + 1;
 pub(crate) const CLOSE_ONE_WINDOW: u32 = WM_USER + 2;
+pub(crate) const MENU_COMMAND: u32 = WM_USER + 3;
 
 const SIZE_MOVE_LOOP_TIMER_ID: usize = 1;
 const AUTO_HIDE_TASKBAR_THICKNESS_PX: i32 = 1;
@@ -81,6 +82,7 @@ pub(crate) fn handle_msg(
         WM_SYSKEYDOWN => handle_syskeydown_msg(wparam, lparam, state_ptr),
         WM_SYSKEYUP => handle_syskeyup_msg(wparam, state_ptr),
         WM_SYSCOMMAND => handle_system_command(wparam, state_ptr),
+        WM_COMMAND => handle_command_msg(wparam),
         WM_KEYDOWN => handle_keydown_msg(wparam, lparam, state_ptr),
         WM_KEYUP => handle_keyup_msg(wparam, state_ptr),
         WM_CHAR => handle_char_msg(wparam, lparam, state_ptr),
@@ -1214,6 +1216,24 @@ fn handle_system_command(wparam: WPARAM, state_ptr: Rc<WindowsWindowStatePtr>) -
     None
 }
 
+fn handle_command_msg(wparam: WPARAM) -> Option<isize> {
+    // The high word is zero for commands sent by a menu, and the low word is the item's id.
+    if wparam.hiword() != 0 {
+        return None;
+    }
+    // Menu actions are dispatched by the platform, which owns them.
+    unsafe {
+        PostMessageW(
+            None,
+            MENU_COMMAND,
+            WPARAM(wparam.loword() as usize),
+            LPARAM(0),
+        )
+        .log_err();
+    }
+    Some(0)
+}
+
 fn handle_system_theme_changed(state_ptr: Rc<WindowsWindowStatePtr>) -> Option<isize> {
     let mut callback = state_ptr
         .state
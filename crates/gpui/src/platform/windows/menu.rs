@@ -0,0 +1,160 @@
+use anyhow::Result;
+use windows::{
+    core::{HSTRING, PCWSTR},
+    Win32::UI::WindowsAndMessaging::{
+        AppendMenuW, CreateMenu, CreatePopupMenu, DestroyMenu, HMENU, MF_POPUP, MF_SEPARATOR,
+        MF_STRING,
+    },
+};
+
+use crate::{Action, Keymap, Keystroke, OwnedMenu, OwnedMenuItem};
+
+/// Collects the actions of the given menus in the order their items are assigned command ids,
+/// along with the label of the keybinding to show next to each, if any.
+pub(crate) fn collect_menu_actions(
+    menus: &[OwnedMenu],
+    keymap: &Keymap,
+) -> Vec<(Box<dyn Action>, Option<String>)> {
+    fn collect(
+        items: &[OwnedMenuItem],
+        keymap: &Keymap,
+        actions: &mut Vec<(Box<dyn Action>, Option<String>)>,
+    ) {
+        for item in items {
+            match item {
+                OwnedMenuItem::Separator => {}
+                OwnedMenuItem::Submenu(submenu) => collect(&submenu.items, keymap, actions),
+                OwnedMenuItem::Action { action, .. } => {
+                    let shortcut = keymap
+                        .bindings_for_action(action.as_ref())
+                        .next()
+                        .map(|binding| binding.keystrokes())
+                        .filter(|keystrokes| keystrokes.len() == 1)
+                        .map(|keystrokes| keystroke_label(&keystrokes[0]));
+                    actions.push((action.boxed_clone(), shortcut));
+                }
+            }
+        }
+    }
+
+    let mut actions = Vec::new();
+    for menu in menus {
+        collect(&menu.items, keymap, &mut actions);
+    }
+    actions
+}
+
+/// The command id of the menu item for the action at the given index, as sent with `WM_COMMAND`.
+pub(crate) fn menu_command_id(action_ix: usize) -> usize {
+    action_ix + 1
+}
+
+/// The index of the action for the given menu command id, if it refers to one.
+pub(crate) fn menu_action_index(command_id: usize) -> Option<usize> {
+    command_id.checked_sub(1)
+}
+
+/// Creates a native menu bar for the given menus, where action items are assigned command ids in
+/// the same order as [`collect_menu_actions`] and labelled with the given shortcuts.
+pub(crate) fn create_menu_bar(menus: &[OwnedMenu], shortcuts: &[Option<String>]) -> Result<HMENU> {
+    unsafe fn append_items(
+        menu: HMENU,
+        items: &[OwnedMenuItem],
+        shortcuts: &[Option<String>],
+        action_ix: &mut usize,
+    ) -> Result<()> {
+        for item in items {
+            match item {
+                OwnedMenuItem::Separator => {
+                    AppendMenuW(menu, MF_SEPARATOR, 0, PCWSTR::null())?;
+                }
+                OwnedMenuItem::Submenu(submenu) => {
+                    let popup = create_popup(&submenu.items, shortcuts, action_ix)?;
+                    AppendMenuW(
+                        menu,
+                        MF_POPUP,
+                        popup.0 as usize,
+                        &HSTRING::from(menu_label(&submenu.name)),
+                    )?;
+                }
+                OwnedMenuItem::Action { name, .. } => {
+                    let mut label = menu_label(name);
+                    if let Some(Some(shortcut)) = shortcuts.get(*action_ix) {
+                        label.push('\t');
+                        label.push_str(shortcut);
+                    }
+                    AppendMenuW(
+                        menu,
+                        MF_STRING,
+                        menu_command_id(*action_ix),
+                        &HSTRING::from(label),
+                    )?;
+                    *action_ix += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn create_popup(
+        items: &[OwnedMenuItem],
+        shortcuts: &[Option<String>],
+        action_ix: &mut usize,
+    ) -> Result<HMENU> {
+        let popup = CreatePopupMenu()?;
+        if let Err(error) = append_items(popup, items, shortcuts, action_ix) {
+            DestroyMenu(popup).ok();
+            return Err(error);
+        }
+        Ok(popup)
+    }
+
+    unsafe {
+        let menu_bar = CreateMenu()?;
+        let mut action_ix = 0;
+        for menu in menus {
+            let result = create_popup(&menu.items, shortcuts, &mut action_ix).and_then(|popup| {
+                AppendMenuW(
+                    menu_bar,
+                    MF_POPUP,
+                    popup.0 as usize,
+                    &HSTRING::from(menu_label(&menu.name)),
+                )?;
+                Ok(())
+            });
+            if let Err(error) = result {
+                DestroyMenu(menu_bar).ok();
+                return Err(error);
+            }
+        }
+        Ok(menu_bar)
+    }
+}
+
+/// Escapes ampersands, which Windows would otherwise treat as mnemonic prefixes.
+fn menu_label(name: &str) -> String {
+    name.replace('&', "&&")
+}
+
+/// Formats a keystroke the way Windows menus show shortcuts, such as `Ctrl+Shift+S`.
+fn keystroke_label(keystroke: &Keystroke) -> String {
+    let mut label = String::new();
+    if keystroke.modifiers.control {
+        label.push_str("Ctrl+");
+    }
+    if keystroke.modifiers.alt {
+        label.push_str("Alt+");
+    }
+    if keystroke.modifiers.shift {
+        label.push_str("Shift+");
+    }
+    if keystroke.modifiers.platform {
+        label.push_str("Win+");
+    }
+    let mut chars = keystroke.key.chars();
+    if let Some(first) = chars.next() {
+        label.extend(first.to_uppercase());
+        label.push_str(chars.as_str());
+    }
+    label
+}
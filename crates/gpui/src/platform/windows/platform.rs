@@ -49,6 +49,9 @@ pub(crate) struct WindowsPlatform {
 pub(crate) struct WindowsPlatformState {
     callbacks: PlatformCallbacks,
     menus: Vec<OwnedMenu>,
+    /// The actions of the native menu bar, with the shortcut shown for each, indexed by
+    /// their position in the menus.
+    menu_actions: Vec<(Box<dyn Action>, Option<String>)>,
     // NOTE: standard cursor handles don't need to close.
     pub(crate) current_cursor: HCURSOR,
 }
@@ -61,6 +64,7 @@ struct PlatformCallbacks {
     app_menu_action: Option<Box<dyn FnMut(&dyn Action)>>,
     will_open_app_menu: Option<Box<dyn FnMut()>>,
     validate_app_menu_command: Option<Box<dyn FnMut(&dyn Action) -> bool>>,
+    global_hotkey: Option<Box<dyn FnMut(u32)>>,
 }
 
 impl WindowsPlatformState {
@@ -72,6 +76,7 @@ impl WindowsPlatformState {
             callbacks,
             current_cursor,
             menus: Vec::new(),
+            menu_actions: Vec::new(),
         }
     }
 }
@@ -170,6 +175,80 @@ impl WindowsPlatform {
         lock.is_empty()
     }
 
+    fn handle_global_hotkey(&self, id: u32) {
+        let mut lock = self.state.borrow_mut();
+        if let Some(mut callback) = lock.callbacks.global_hotkey.take() {
+            drop(lock);
+            callback(id);
+            self.state
+                .borrow_mut()
+                .callbacks
+                .global_hotkey
+                .get_or_insert(callback);
+        }
+    }
+
+    fn handle_menu_command(&self, command_id: usize) {
+        let mut lock = self.state.borrow_mut();
+        let Some(action) = menu_action_index(command_id)
+            .and_then(|ix| lock.menu_actions.get(ix))
+            .map(|(action, _)| action.boxed_clone())
+        else {
+            return;
+        };
+        if let Some(mut callback) = lock.callbacks.app_menu_action.take() {
+            drop(lock);
+            callback(action.as_ref());
+            self.state
+                .borrow_mut()
+                .callbacks
+                .app_menu_action
+                .get_or_insert(callback);
+        }
+    }
+
+    /// Attaches a native menu bar for the application's menus to the given window, replacing
+    /// any previous one. Windows that draw their own title bar show the menus there instead.
+    fn attach_menu_bar(&self, hwnd: HWND) {
+        if try_get_window_inner(hwnd).map_or(true, |window| window.hide_title_bar) {
+            return;
+        }
+
+        let menu_bar = {
+            let state = self.state.borrow();
+            if state.menus.is_empty() {
+                None
+            } else {
+                let shortcuts = state
+                    .menu_actions
+                    .iter()
+                    .map(|(_, shortcut)| shortcut.clone())
+                    .collect::<Vec<_>>();
+                let Some(menu_bar) = create_menu_bar(&state.menus, &shortcuts).log_err() else {
+                    return;
+                };
+                Some(menu_bar)
+            }
+        };
+
+        unsafe {
+            let previous_menu_bar = GetMenu(hwnd);
+            if SetMenu(hwnd, menu_bar.unwrap_or_default())
+                .log_err()
+                .is_none()
+            {
+                if let Some(menu_bar) = menu_bar {
+                    DestroyMenu(menu_bar).log_err();
+                }
+                return;
+            }
+            if !previous_menu_bar.is_invalid() {
+                DestroyMenu(previous_menu_bar).log_err();
+            }
+            DrawMenuBar(hwnd).log_err();
+        }
+    }
+
     #[inline]
     fn run_foreground_tasks(&self) {
         for runnable in self.main_receiver.drain() {
@@ -236,6 +315,8 @@ impl Platform for WindowsPlatform {
                         while PeekMessageW(&mut msg, None, 0, 0, PM_REMOVE).as_bool() {
                             match msg.message {
                                 WM_QUIT => break 'a,
+                                WM_HOTKEY => self.handle_global_hotkey(msg.wParam.0 as u32),
+                                MENU_COMMAND => self.handle_menu_command(msg.wParam.0),
                                 CLOSE_ONE_WINDOW => {
                                     if self.close_one_window(
                                         HWND(msg.lParam.0 as _),
@@ -357,6 +438,7 @@ impl Platform for WindowsPlatform {
         )?;
         let handle = window.get_raw_handle();
         self.raw_window_handles.write().push(handle);
+        self.attach_menu_bar(handle);
 
         Ok(Box::new(window))
     }
@@ -451,8 +533,17 @@ impl Platform for WindowsPlatform {
         self.state.borrow_mut().callbacks.reopen = Some(callback);
     }
 
-    fn set_menus(&self, menus: Vec<Menu>, _keymap: &Keymap) {
-        self.state.borrow_mut().menus = menus.into_iter().map(|menu| menu.owned()).collect();
+    fn set_menus(&self, menus: Vec<Menu>, keymap: &Keymap) {
+        {
+            let mut state = self.state.borrow_mut();
+            state.menus = menus.into_iter().map(|menu| menu.owned()).collect();
+            state.menu_actions = collect_menu_actions(&state.menus, keymap);
+        }
+
+        let handles = self.raw_window_handles.read().clone();
+        for handle in handles {
+            self.attach_menu_bar(handle);
+        }
     }
 
     fn get_menus(&self) -> Option<Vec<OwnedMenu>> {
@@ -462,6 +553,34 @@ impl Platform for WindowsPlatform {
     // todo(windows)
     fn set_dock_menu(&self, _menus: Vec<MenuItem>, _keymap: &Keymap) {}
 
+    fn register_global_hotkey(&self, id: u32, keystroke: &Keystroke) -> Result<()> {
+        let vkey = keystroke_to_vkey(keystroke)
+            .with_context(|| format!("unsupported global hotkey: {}", keystroke.key))?;
+        let mut modifiers = MOD_NOREPEAT;
+        if keystroke.modifiers.control {
+            modifiers |= MOD_CONTROL;
+        }
+        if keystroke.modifiers.alt {
+            modifiers |= MOD_ALT;
+        }
+        if keystroke.modifiers.shift {
+            modifiers |= MOD_SHIFT;
+        }
+        if keystroke.modifiers.platform {
+            modifiers |= MOD_WIN;
+        }
+        unsafe { RegisterHotKey(None, id as i32, modifiers, vkey.0 as u32) }
+            .context("failed to register global hotkey")
+    }
+
+    fn unregister_global_hotkey(&self, id: u32) {
+        unsafe { UnregisterHotKey(None, id as i32) }.log_err();
+    }
+
+    fn on_global_hotkey(&self, callback: Box<dyn FnMut(u32)>) {
+        self.state.borrow_mut().callbacks.global_hotkey = Some(callback);
+    }
+
     fn on_app_menu_action(&self, callback: Box<dyn FnMut(&dyn Action)>) {
         self.state.borrow_mut().callbacks.app_menu_action = Some(callback);
     }
@@ -729,6 +848,47 @@ fn should_auto_hide_scrollbars() -> Result<bool> {
     Ok(ui_settings.AutoHideScrollBars()?)
 }
 
+fn keystroke_to_vkey(keystroke: &Keystroke) -> Option<VIRTUAL_KEY> {
+    let vkey = match keystroke.key.as_str() {
+        "backspace" => VK_BACK,
+        "enter" => VK_RETURN,
+        "tab" => VK_TAB,
+        "space" => VK_SPACE,
+        "escape" => VK_ESCAPE,
+        "up" => VK_UP,
+        "down" => VK_DOWN,
+        "left" => VK_LEFT,
+        "right" => VK_RIGHT,
+        "home" => VK_HOME,
+        "end" => VK_END,
+        "pageup" => VK_PRIOR,
+        "pagedown" => VK_NEXT,
+        "insert" => VK_INSERT,
+        "delete" => VK_DELETE,
+        key => {
+            if let Some(number) = key
+                .strip_prefix('f')
+                .and_then(|number| number.parse::<u16>().ok())
+                .filter(|number| (1..=24).contains(number))
+            {
+                return Some(VIRTUAL_KEY(VK_F1.0 + number - 1));
+            }
+
+            let mut chars = key.encode_utf16();
+            let ch = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            let scan = unsafe { VkKeyScanW(ch) };
+            if scan == -1 {
+                return None;
+            }
+            VIRTUAL_KEY((scan & 0xff) as u16)
+        }
+    };
+    Some(vkey)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{ClipboardItem, Platform, WindowsPlatform};
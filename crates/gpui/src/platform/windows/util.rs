@@ -113,6 +113,10 @@ pub(crate) fn load_cursor(style: CursorStyle) -> HCURSOR {
     static SIZEWE: OnceLock<SafeCursor> = OnceLock::new();
     static SIZENS: OnceLock<SafeCursor> = OnceLock::new();
     static NO: OnceLock<SafeCursor> = OnceLock::new();
+    static SIZEALL: OnceLock<SafeCursor> = OnceLock::new();
+    static HELP: OnceLock<SafeCursor> = OnceLock::new();
+    static WAIT: OnceLock<SafeCursor> = OnceLock::new();
+    static APPSTARTING: OnceLock<SafeCursor> = OnceLock::new();
     let (lock, name) = match style {
         CursorStyle::IBeam | CursorStyle::IBeamCursorForVerticalLayout => (&IBEAM, IDC_IBEAM),
         CursorStyle::Crosshair => (&CROSS, IDC_CROSS),
@@ -125,7 +129,11 @@ pub(crate) fn load_cursor(style: CursorStyle) -> HCURSOR {
         | CursorStyle::ResizeDown
         | CursorStyle::ResizeUpDown
         | CursorStyle::ResizeRow => (&SIZENS, IDC_SIZENS),
-        CursorStyle::OperationNotAllowed => (&NO, IDC_NO),
+        CursorStyle::OperationNotAllowed | CursorStyle::Custom("no-drop") => (&NO, IDC_NO),
+        CursorStyle::AllScroll | CursorStyle::Custom("move") => (&SIZEALL, IDC_SIZEALL),
+        CursorStyle::Custom("help") => (&HELP, IDC_HELP),
+        CursorStyle::Custom("wait") => (&WAIT, IDC_WAIT),
+        CursorStyle::Custom("progress") => (&APPSTARTING, IDC_APPSTARTING),
         _ => (&ARROW, IDC_ARROW),
     };
     *(*lock.get_or_init(|| {
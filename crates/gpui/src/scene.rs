@@ -17,6 +17,7 @@ pub(crate) struct Scene {
     pub(crate) paint_operations: Vec<PaintOperation>,
     primitive_bounds: BoundsTree<ScaledPixels>,
     layer_stack: Vec<DrawOrder>,
+    transformation_stack: Vec<TransformationMatrix>,
     pub(crate) shadows: Vec<Shadow>,
     pub(crate) quads: Vec<Quad>,
     pub(crate) paths: Vec<Path<ScaledPixels>>,
@@ -31,6 +32,7 @@ impl Scene {
         self.paint_operations.clear();
         self.primitive_bounds.clear();
         self.layer_stack.clear();
+        self.transformation_stack.clear();
         self.paths.clear();
         self.shadows.clear();
         self.quads.clear();
@@ -67,10 +69,31 @@ impl Scene {
         self.paint_operations.push(PaintOperation::EndLayer);
     }
 
+    /// Applies the given transformation to all primitives inserted until the matching call to
+    /// [`Scene::pop_transformation`], on top of any transformation that is already active.
+    pub fn push_transformation(&mut self, transformation: TransformationMatrix) {
+        let transformation = match self.transformation_stack.last() {
+            Some(parent) => parent.compose(transformation),
+            None => transformation,
+        };
+        self.transformation_stack.push(transformation);
+    }
+
+    pub fn pop_transformation(&mut self) {
+        self.transformation_stack.pop();
+    }
+
     pub fn insert_primitive(&mut self, primitive: impl Into<Primitive>) {
         let mut primitive = primitive.into();
+        if let Some(transformation) = self.transformation_stack.last() {
+            primitive.transform(transformation);
+        }
+        self.insert_transformed_primitive(primitive);
+    }
+
+    fn insert_transformed_primitive(&mut self, mut primitive: Primitive) {
         let clipped_bounds = primitive
-            .bounds()
+            .painted_bounds()
             .intersect(&primitive.content_mask().bounds);
 
         if clipped_bounds.is_empty() {
@@ -120,7 +143,10 @@ impl Scene {
     pub fn replay(&mut self, range: Range<usize>, prev_scene: &Scene) {
         for operation in &prev_scene.paint_operations[range] {
             match operation {
-                PaintOperation::Primitive(primitive) => self.insert_primitive(primitive.clone()),
+                // Replayed primitives were already transformed when they were first inserted.
+                PaintOperation::Primitive(primitive) => {
+                    self.insert_transformed_primitive(primitive.clone())
+                }
                 PaintOperation::StartLayer(bounds) => self.push_layer(*bounds),
                 PaintOperation::EndLayer => self.pop_layer(),
             }
@@ -222,6 +248,82 @@ impl Primitive {
         }
     }
 
+    /// The bounds covered by the primitive once painted, which differ from its bounds for
+    /// sprites that the renderer transforms.
+    fn painted_bounds(&self) -> Bounds<ScaledPixels> {
+        match self {
+            Primitive::MonochromeSprite(sprite) => {
+                sprite.transformation.transform_bounds(&sprite.bounds)
+            }
+            primitive => *primitive.bounds(),
+        }
+    }
+
+    /// Applies a transformation to this primitive.
+    ///
+    /// Monochrome sprites (text and SVG icons) and paths are transformed exactly, including any
+    /// rotation. The renderer can only draw the other primitives as axis-aligned rectangles, so
+    /// they are moved and scaled with the transformation, along with their corner radii, border
+    /// widths and blur, but not rotated.
+    fn transform(&mut self, transformation: &TransformationMatrix) {
+        let (scale_x, scale_y) = transformation.scale_factors();
+        let transform_rect = |bounds: &Bounds<ScaledPixels>| {
+            let size = Size {
+                width: ScaledPixels(bounds.size.width.0 * scale_x),
+                height: ScaledPixels(bounds.size.height.0 * scale_y),
+            };
+            Bounds::centered_at(transformation.apply_scaled(bounds.center()), size)
+        };
+        let scale_length = |length: ScaledPixels| ScaledPixels(length.0 * scale_x.min(scale_y));
+        let scale_corners = |corners: &mut Corners<ScaledPixels>| {
+            *corners = corners.map(|radius| scale_length(*radius));
+        };
+
+        match self {
+            Primitive::Shadow(shadow) => {
+                shadow.bounds = transform_rect(&shadow.bounds);
+                shadow.blur_radius = scale_length(shadow.blur_radius);
+                scale_corners(&mut shadow.corner_radii);
+            }
+            Primitive::Quad(quad) => {
+                quad.bounds = transform_rect(&quad.bounds);
+                quad.border_widths = quad.border_widths.map(|width| scale_length(*width));
+                scale_corners(&mut quad.corner_radii);
+            }
+            Primitive::Underline(underline) => {
+                underline.bounds = transform_rect(&underline.bounds);
+                underline.thickness = scale_length(underline.thickness);
+            }
+            Primitive::PolychromeSprite(sprite) => {
+                sprite.bounds = transform_rect(&sprite.bounds);
+                scale_corners(&mut sprite.corner_radii);
+            }
+            Primitive::Surface(surface) => {
+                surface.bounds = transform_rect(&surface.bounds);
+            }
+            Primitive::MonochromeSprite(sprite) => {
+                sprite.transformation = transformation.compose(sprite.transformation);
+            }
+            Primitive::Path(path) => {
+                for vertex in &mut path.vertices {
+                    vertex.xy_position = transformation.apply_scaled(vertex.xy_position);
+                }
+                path.bounds = transformation.transform_bounds(&path.bounds);
+            }
+        }
+
+        let content_mask = match self {
+            Primitive::Shadow(shadow) => &mut shadow.content_mask,
+            Primitive::Quad(quad) => &mut quad.content_mask,
+            Primitive::Path(path) => &mut path.content_mask,
+            Primitive::Underline(underline) => &mut underline.content_mask,
+            Primitive::MonochromeSprite(sprite) => &mut sprite.content_mask,
+            Primitive::PolychromeSprite(sprite) => &mut sprite.content_mask,
+            Primitive::Surface(surface) => &mut surface.content_mask,
+        };
+        content_mask.bounds = transformation.transform_bounds(&content_mask.bounds);
+    }
+
     pub fn content_mask(&self) -> &ContentMask<ScaledPixels> {
         match self {
             Primitive::Shadow(shadow) => &shadow.content_mask,
@@ -589,6 +691,43 @@ impl TransformationMatrix {
         }
     }
 
+    /// Apply transformation to a point in scaled pixels
+    pub fn apply_scaled(&self, point: Point<ScaledPixels>) -> Point<ScaledPixels> {
+        let [[a, b], [c, d]] = self.rotation_scale;
+        let [tx, ty] = self.translation;
+        Point::new(
+            ScaledPixels(a * point.x.0 + b * point.y.0 + tx),
+            ScaledPixels(c * point.x.0 + d * point.y.0 + ty),
+        )
+    }
+
+    /// The smallest axis-aligned bounds containing the given bounds once transformed
+    pub fn transform_bounds(&self, bounds: &Bounds<ScaledPixels>) -> Bounds<ScaledPixels> {
+        if *self == Self::unit() {
+            return *bounds;
+        }
+
+        let corners = [
+            bounds.origin,
+            bounds.top_right(),
+            bounds.bottom_left(),
+            bounds.bottom_right(),
+        ]
+        .map(|corner| self.apply_scaled(corner));
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for corner in &corners[1..] {
+            min = min.min(corner);
+            max = max.max(corner);
+        }
+        Bounds::from_corners(min, max)
+    }
+
+    /// The factors by which this transformation scales lengths along each axis
+    fn scale_factors(&self) -> (f32, f32) {
+        let [[a, b], [c, d]] = self.rotation_scale;
+        ((a * a + c * c).sqrt(), (b * b + d * d).sqrt())
+    }
+
     /// Apply transformation to a point, mainly useful for debugging
     pub fn apply(&self, point: Point<Pixels>) -> Point<Pixels> {
         let input = [point.x.0, point.y.0];
@@ -805,3 +944,126 @@ impl PathVertex<Pixels> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{px, radians, red, size};
+
+    fn scaled_bounds(x: f32, y: f32, width: f32, height: f32) -> Bounds<ScaledPixels> {
+        Bounds::new(
+            point(ScaledPixels(x), ScaledPixels(y)),
+            size(ScaledPixels(width), ScaledPixels(height)),
+        )
+    }
+
+    fn content_mask() -> ContentMask<ScaledPixels> {
+        ContentMask {
+            bounds: scaled_bounds(0., 0., 1000., 1000.),
+        }
+    }
+
+    fn quad(bounds: Bounds<ScaledPixels>) -> Quad {
+        Quad {
+            order: 0,
+            pad: 0,
+            bounds,
+            content_mask: content_mask(),
+            background: red().into(),
+            border_color: red(),
+            corner_radii: Corners::all(ScaledPixels(4.)),
+            border_widths: Edges::all(ScaledPixels(1.)),
+        }
+    }
+
+    #[test]
+    fn test_transformation_scales_primitive_geometry() {
+        let mut scene = Scene::default();
+        // Scale by 2 around (10, 10), then move right by 5.
+        scene.push_transformation(
+            TransformationMatrix::unit()
+                .translate(point(ScaledPixels(15.), ScaledPixels(10.)))
+                .scale(size(2., 2.))
+                .translate(point(ScaledPixels(-10.), ScaledPixels(-10.))),
+        );
+        scene.insert_primitive(quad(scaled_bounds(10., 10., 20., 10.)));
+        scene.insert_primitive(Shadow {
+            order: 0,
+            blur_radius: ScaledPixels(3.),
+            bounds: scaled_bounds(10., 10., 20., 10.),
+            corner_radii: Corners::all(ScaledPixels(2.)),
+            content_mask: content_mask(),
+            color: red(),
+        });
+        scene.insert_primitive(Underline {
+            order: 0,
+            pad: 0,
+            bounds: scaled_bounds(10., 20., 20., 1.),
+            content_mask: content_mask(),
+            color: red(),
+            thickness: ScaledPixels(1.),
+            wavy: false,
+        });
+        scene.pop_transformation();
+
+        let quad_primitive = &scene.quads[0];
+        assert_eq!(quad_primitive.bounds, scaled_bounds(15., 10., 40., 20.));
+        assert_eq!(quad_primitive.corner_radii, Corners::all(ScaledPixels(8.)));
+        assert_eq!(quad_primitive.border_widths, Edges::all(ScaledPixels(2.)));
+
+        let shadow = &scene.shadows[0];
+        assert_eq!(shadow.bounds, scaled_bounds(15., 10., 40., 20.));
+        assert_eq!(shadow.blur_radius, ScaledPixels(6.));
+        assert_eq!(shadow.corner_radii, Corners::all(ScaledPixels(4.)));
+
+        let underline = &scene.underlines[0];
+        assert_eq!(underline.bounds, scaled_bounds(15., 30., 40., 2.));
+        assert_eq!(underline.thickness, ScaledPixels(2.));
+
+        // Primitives inserted after the transformation is popped are left untouched.
+        scene.insert_primitive(quad(scaled_bounds(10., 10., 20., 10.)));
+        assert_eq!(scene.quads[1].bounds, scaled_bounds(10., 10., 20., 10.));
+    }
+
+    #[test]
+    fn test_rotation_applies_to_paths_only() {
+        let mut scene = Scene::default();
+        // Rotate by 180 degrees around (20, 20).
+        scene.push_transformation(
+            TransformationMatrix::unit()
+                .translate(point(ScaledPixels(20.), ScaledPixels(20.)))
+                .rotate(radians(std::f32::consts::PI))
+                .translate(point(ScaledPixels(-20.), ScaledPixels(-20.))),
+        );
+        let mut path = Path::new(point(px(10.), px(10.)));
+        path.line_to(point(px(20.), px(10.)));
+        path.line_to(point(px(20.), px(15.)));
+        let mut path = path.scale(1.);
+        path.content_mask = content_mask();
+        scene.insert_primitive(path);
+        scene.insert_primitive(quad(scaled_bounds(10., 10., 10., 5.)));
+        scene.pop_transformation();
+
+        let rotated_vertices = scene.paths[0]
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let position = vertex.xy_position;
+                (position.x.0.round(), position.y.0.round())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rotated_vertices, vec![(30., 30.), (20., 30.), (20., 25.)]);
+
+        // Quads stay axis-aligned, so only their position follows the rotation.
+        let quad_bounds = scene.quads[0].bounds;
+        assert_eq!(
+            (
+                quad_bounds.origin.x.0.round(),
+                quad_bounds.origin.y.0.round(),
+                quad_bounds.size.width.0.round(),
+                quad_bounds.size.height.0.round()
+            ),
+            (20., 25., 10., 5.)
+        );
+    }
+}
@@ -5,11 +5,11 @@ use std::{
 };
 
 use crate::{
-    black, phi, point, quad, rems, size, AbsoluteLength, Background, BackgroundTag, Bounds,
-    ContentMask, Corners, CornersRefinement, CursorStyle, DefiniteLength, DevicePixels, Edges,
-    EdgesRefinement, Font, FontFallbacks, FontFeatures, FontStyle, FontWeight, Hsla, Length,
-    Pixels, Point, PointRefinement, Rgba, SharedString, Size, SizeRefinement, Styled, TextRun,
-    WindowContext,
+    black, phi, point, quad, rems, size, transparent_black, AbsoluteLength, AvailableSpace,
+    Background, BackgroundTag, Bounds, ContentMask, Corners, CornersRefinement, CursorStyle,
+    DefiniteLength, DevicePixels, Edges, EdgesRefinement, Font, FontFallbacks, FontFeatures,
+    FontStyle, FontWeight, Hsla, Length, Pixels, Point, PointRefinement, Rgba, SharedString, Size,
+    SizeRefinement, Styled, TextRun, WindowContext,
 };
 use collections::HashSet;
 use refineable::Refineable;
@@ -179,6 +179,8 @@ pub struct Style {
     pub max_size: Size<Length>,
     /// Sets the preferred aspect ratio for the item. The ratio is calculated as width divided by height.
     pub aspect_ratio: Option<f32>,
+    /// Sizes the item to fit its content horizontally, overriding its width
+    pub intrinsic_width: Option<IntrinsicSize>,
 
     // Spacing Properties
     /// How large should the margin be on each side?
@@ -222,6 +224,9 @@ pub struct Style {
     /// The border color of this element
     pub border_color: Option<Hsla>,
 
+    /// A gradient to paint the border with, taking precedence over the border color
+    pub border_gradient: Option<Background>,
+
     /// The radius of the corners of this element
     #[refineable]
     pub corner_radii: Corners<AbsoluteLength>,
@@ -235,6 +240,9 @@ pub struct Style {
     /// The mouse cursor style shown when the mouse pointer is over an element.
     pub mouse_cursor: Option<CursorStyle>,
 
+    /// Whether this element can be the target of mouse events
+    pub pointer_events: PointerEvents,
+
     /// The opacity of this element
     pub opacity: Option<f32>,
 
@@ -263,6 +271,35 @@ pub enum Visibility {
     Hidden,
 }
 
+/// The value of the pointer-events property, similar to the CSS property `pointer-events`
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PointerEvents {
+    /// The element receives mouse events as normal.
+    #[default]
+    Auto,
+    /// The element doesn't receive mouse events, which pass through to the elements beneath it.
+    /// Its children still receive mouse events unless they opt out as well.
+    None,
+}
+
+/// An intrinsic size keyword, similar to the CSS `min-content` and `max-content` sizes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IntrinsicSize {
+    /// The smallest size the content can take without overflowing, wrapping wherever possible.
+    MinContent,
+    /// The size the content takes when it is laid out without wrapping.
+    MaxContent,
+}
+
+impl From<IntrinsicSize> for AvailableSpace {
+    fn from(size: IntrinsicSize) -> Self {
+        match size {
+            IntrinsicSize::MinContent => AvailableSpace::MinContent,
+            IntrinsicSize::MaxContent => AvailableSpace::MaxContent,
+        }
+    }
+}
+
 /// The possible values of the box-shadow property
 #[derive(Clone, Debug)]
 pub struct BoxShadow {
@@ -276,6 +313,17 @@ pub struct BoxShadow {
     pub spread_radius: Pixels,
 }
 
+/// A shadow painted behind text, like the text-shadow CSS property
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextShadow {
+    /// How should the shadow be offset from the text?
+    pub offset: Point<Pixels>,
+    /// How much should the shadow be blurred?
+    pub blur_radius: Pixels,
+    /// What color should the shadow have?
+    pub color: Hsla,
+}
+
 /// How to handle whitespace in text
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum WhiteSpace {
@@ -294,6 +342,9 @@ pub enum Truncate {
     Truncate,
     /// Truncate the text with an ellipsis
     Ellipsis,
+    /// Truncate the text with an ellipsis after the given number of characters, regardless of
+    /// the width of the element, keeping it to a single line
+    AtChars(usize),
 }
 
 /// The properties that can be used to style text in GPUI
@@ -338,6 +389,15 @@ pub struct TextStyle {
 
     /// The text should be truncated if it overflows the width of the element
     pub truncate: Option<Truncate>,
+
+    /// Extra space added after every character of the text
+    pub letter_spacing: Option<Pixels>,
+
+    /// Extra space added after every space character of the text
+    pub word_spacing: Option<Pixels>,
+
+    /// The shadows painted behind the text
+    pub shadows: SmallVec<[TextShadow; 1]>,
 }
 
 impl Default for TextStyle {
@@ -363,6 +423,9 @@ impl Default for TextStyle {
             strikethrough: None,
             white_space: WhiteSpace::Normal,
             truncate: None,
+            letter_spacing: None,
+            word_spacing: None,
+            shadows: SmallVec::new(),
         }
     }
 }
@@ -432,6 +495,8 @@ impl TextStyle {
             background_color: self.background_color,
             underline: self.underline,
             strikethrough: self.strikethrough,
+            letter_spacing: self.letter_spacing,
+            word_spacing: self.word_spacing,
         }
     }
 }
@@ -511,10 +576,7 @@ impl Style {
                 let mut min = bounds.origin;
                 let mut max = bounds.bottom_right();
 
-                if self
-                    .border_color
-                    .map_or(false, |color| !color.is_transparent())
-                {
+                if self.has_border_fill() {
                     min.x += self.border_widths.left.to_pixels(rem_size);
                     max.x -= self.border_widths.right.to_pixels(rem_size);
                     min.y += self.border_widths.top.to_pixels(rem_size);
@@ -619,43 +681,63 @@ impl Style {
                 bottom_bounds.top_right(),
             );
 
-            let mut background = self.border_color.unwrap_or_default();
-            background.a = 0.;
-            let quad = quad(
-                bounds,
-                corner_radii,
-                background,
-                border_widths,
-                self.border_color.unwrap_or_default(),
-            );
-
-            cx.with_content_mask(Some(ContentMask { bounds: top_bounds }), |cx| {
-                cx.paint_quad(quad.clone());
-            });
-            cx.with_content_mask(
-                Some(ContentMask {
-                    bounds: right_bounds,
-                }),
-                |cx| {
-                    cx.paint_quad(quad.clone());
-                },
-            );
-            cx.with_content_mask(
-                Some(ContentMask {
-                    bounds: bottom_bounds,
-                }),
-                |cx| {
+            if let Some(gradient) = self.border_gradient_for(&corner_radii) {
+                // Fill the whole element with the gradient, but only let it show through
+                // the strips covered by each border edge.
+                let quad = quad(
+                    bounds,
+                    corner_radii,
+                    gradient,
+                    Edges::default(),
+                    transparent_black(),
+                );
+                for bounds in border_edge_bounds(bounds, &border_widths) {
+                    if bounds.size.width > Pixels::ZERO && bounds.size.height > Pixels::ZERO {
+                        cx.with_content_mask(Some(ContentMask { bounds }), |cx| {
+                            cx.paint_quad(quad.clone());
+                        });
+                    }
+                }
+            } else {
+                let border_color = self.solid_border_color();
+                let mut background = border_color;
+                background.a = 0.;
+                let quad = quad(
+                    bounds,
+                    corner_radii,
+                    background,
+                    border_widths,
+                    border_color,
+                );
+
+                cx.with_content_mask(Some(ContentMask { bounds: top_bounds }), |cx| {
                     cx.paint_quad(quad.clone());
-                },
-            );
-            cx.with_content_mask(
-                Some(ContentMask {
-                    bounds: left_bounds,
-                }),
-                |cx| {
-                    cx.paint_quad(quad);
-                },
-            );
+                });
+                cx.with_content_mask(
+                    Some(ContentMask {
+                        bounds: right_bounds,
+                    }),
+                    |cx| {
+                        cx.paint_quad(quad.clone());
+                    },
+                );
+                cx.with_content_mask(
+                    Some(ContentMask {
+                        bounds: bottom_bounds,
+                    }),
+                    |cx| {
+                        cx.paint_quad(quad.clone());
+                    },
+                );
+                cx.with_content_mask(
+                    Some(ContentMask {
+                        bounds: left_bounds,
+                    }),
+                    |cx| {
+                        cx.paint_quad(quad);
+                    },
+                );
+            }
         }
 
         #[cfg(debug_assertions)]
@@ -664,11 +746,63 @@ impl Style {
         }
     }
 
+    /// Returns the gradient to paint the border with, if any. The gradient is clipped to a
+    /// strip along each edge, which can't follow rounded corners, so it is only used for
+    /// elements with square corners.
+    fn border_gradient_for(&self, corner_radii: &Corners<Pixels>) -> Option<Background> {
+        if corner_radii.max() > Pixels::ZERO {
+            None
+        } else {
+            self.border_gradient
+        }
+    }
+
+    /// The color to paint a solid border with. Rounded elements with a border gradient use
+    /// the gradient's first color.
+    fn solid_border_color(&self) -> Hsla {
+        match (self.border_gradient, self.border_color) {
+            (Some(gradient), _) => match gradient.tag {
+                BackgroundTag::Solid => gradient.solid,
+                BackgroundTag::LinearGradient => gradient.colors[0].color,
+            },
+            (None, color) => color.unwrap_or_default(),
+        }
+    }
+
     fn is_border_visible(&self) -> bool {
-        self.border_color
-            .map_or(false, |color| !color.is_transparent())
-            && self.border_widths.any(|length| !length.is_zero())
+        self.has_border_fill() && self.border_widths.any(|length| !length.is_zero())
     }
+
+    fn has_border_fill(&self) -> bool {
+        match self.border_gradient {
+            Some(gradient) => !gradient.is_transparent(),
+            None => self
+                .border_color
+                .map_or(false, |color| !color.is_transparent()),
+        }
+    }
+}
+
+/// Splits the border of an element into a strip along each edge. The top and bottom strips
+/// span the full width, so the strips don't overlap at the corners.
+fn border_edge_bounds(
+    bounds: Bounds<Pixels>,
+    border_widths: &Edges<Pixels>,
+) -> [Bounds<Pixels>; 4] {
+    let top = bounds.top() + border_widths.top;
+    let bottom = bounds.bottom() - border_widths.bottom;
+    [
+        Bounds::from_corners(bounds.origin, point(bounds.right(), top)),
+        Bounds::from_corners(
+            point(bounds.right() - border_widths.right, top),
+            point(bounds.right(), bottom),
+        ),
+        Bounds::from_corners(point(bounds.left(), bottom), bounds.bottom_right()),
+        Bounds::from_corners(
+            point(bounds.left(), top),
+            point(bounds.left() + border_widths.left, bottom),
+        ),
+    ]
 }
 
 impl Default for Style {
@@ -691,6 +825,7 @@ impl Default for Style {
             min_size: Size::auto(),
             max_size: Size::auto(),
             aspect_ratio: None,
+            intrinsic_width: None,
             gap: Size::default(),
             // Alignment
             align_items: None,
@@ -705,10 +840,12 @@ impl Default for Style {
             flex_basis: Length::Auto,
             background: None,
             border_color: None,
+            border_gradient: None,
             corner_radii: Corners::default(),
             box_shadow: Default::default(),
             text: TextStyleRefinement::default(),
             mouse_cursor: None,
+            pointer_events: PointerEvents::default(),
             opacity: None,
 
             #[cfg(debug_assertions)]
@@ -729,8 +866,43 @@ pub struct UnderlineStyle {
     /// The color of the underline.
     pub color: Option<Hsla>,
 
-    /// Whether the underline should be wavy, like in a spell checker.
-    pub wavy: bool,
+    /// The pattern the underline is drawn with.
+    pub pattern: UnderlinePattern,
+}
+
+/// The pattern of an underline.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub enum UnderlinePattern {
+    /// A continuous line.
+    #[default]
+    Solid,
+    /// A line broken into dashes, like for deprecated symbols.
+    Dashed {
+        /// The length of each dash.
+        dash_length: Pixels,
+        /// The length of the gap between dashes.
+        gap_length: Pixels,
+    },
+    /// A wavy line, like in a spell checker.
+    Wavy {
+        /// How far the wave extends above and below the line's thickness.
+        amplitude: Pixels,
+    },
+}
+
+impl UnderlinePattern {
+    /// A dashed pattern with dashes and gaps of the given length.
+    pub fn dashed(length: Pixels) -> Self {
+        Self::Dashed {
+            dash_length: length,
+            gap_length: length,
+        }
+    }
+
+    /// A wavy pattern with the given amplitude.
+    pub fn wavy(amplitude: Pixels) -> Self {
+        Self::Wavy { amplitude }
+    }
 }
 
 /// The properties that can be applied to a strikethrough.
@@ -937,7 +1109,7 @@ pub fn combine_highlights(
 
 #[cfg(test)]
 mod tests {
-    use crate::{blue, green, red, yellow};
+    use crate::{self as gpui, blue, green, px, red, yellow};
 
     use super::*;
 
@@ -1029,4 +1201,74 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_border_edge_bounds() {
+        let bounds = Bounds::new(point(px(10.), px(20.)), size(px(100.), px(50.)));
+        let border_widths = Edges {
+            top: px(2.),
+            right: px(3.),
+            bottom: px(4.),
+            left: px(5.),
+        };
+        let edges = border_edge_bounds(bounds, &border_widths);
+        assert_eq!(
+            edges,
+            [
+                Bounds::new(point(px(10.), px(20.)), size(px(100.), px(2.))),
+                Bounds::new(point(px(107.), px(22.)), size(px(3.), px(44.))),
+                Bounds::new(point(px(10.), px(66.)), size(px(100.), px(4.))),
+                Bounds::new(point(px(10.), px(22.)), size(px(5.), px(44.))),
+            ]
+        );
+
+        // Overlapping strips would paint a translucent gradient twice at the corners.
+        for (ix, a) in edges.iter().enumerate() {
+            for b in &edges[ix + 1..] {
+                let overlap = a.intersect(b);
+                assert!(overlap.size.width <= px(0.) || overlap.size.height <= px(0.));
+            }
+        }
+    }
+
+    #[test]
+    fn test_border_gradient_requires_square_corners() {
+        let gradient = crate::linear_gradient(
+            90.,
+            crate::linear_color_stop(red(), 0.),
+            crate::linear_color_stop(blue(), 1.),
+        );
+        let style = Style {
+            border_gradient: Some(gradient),
+            border_color: Some(green()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            style.border_gradient_for(&Corners::default()),
+            Some(gradient)
+        );
+        assert_eq!(style.border_gradient_for(&Corners::all(px(4.))), None);
+        assert_eq!(style.solid_border_color(), red());
+    }
+
+    #[gpui::test]
+    fn test_intrinsic_width(cx: &mut gpui::TestAppContext) {
+        use crate::{div, ParentElement as _};
+
+        let cx = cx.add_empty_window();
+        let available_space = size(
+            AvailableSpace::Definite(px(200.)),
+            AvailableSpace::MinContent,
+        );
+        let content = || div().w(px(50.)).h(px(10.));
+
+        let measured = cx.update(|cx| cx.measure_element(div().child(content()), available_space));
+        assert_eq!(measured.width, px(200.));
+
+        let measured = cx.update(|cx| {
+            cx.measure_element(div().w_max_content().child(content()), available_space)
+        });
+        assert_eq!(measured, size(px(50.), px(10.)));
+    }
 }
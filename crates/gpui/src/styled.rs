@@ -1,7 +1,8 @@
 use crate::{
-    self as gpui, px, relative, rems, AbsoluteLength, AlignItems, CursorStyle, DefiniteLength,
-    Fill, FlexDirection, FlexWrap, Font, FontStyle, FontWeight, Hsla, JustifyContent, Length,
-    SharedString, StrikethroughStyle, StyleRefinement, WhiteSpace,
+    self as gpui, px, relative, rems, AbsoluteLength, AlignItems, Background, CursorStyle,
+    DefiniteLength, Fill, FlexDirection, FlexWrap, Font, FontStyle, FontWeight, Hsla,
+    IntrinsicSize, JustifyContent, Length, Pixels, PointerEvents, SharedString, StrikethroughStyle,
+    StyleRefinement, TextShadow, UnderlinePattern, WhiteSpace,
 };
 use crate::{TextStyleRefinement, Truncate};
 pub use gpui_macros::{
@@ -9,6 +10,7 @@ pub use gpui_macros::{
     overflow_style_methods, padding_style_methods, position_style_methods,
     visibility_style_methods,
 };
+use smallvec::SmallVec;
 use taffy::style::{AlignContent, Display};
 
 /// A trait for elements that can be styled.
@@ -77,6 +79,15 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the text to be truncated with an ellipsis (…) after the given number of characters,
+    /// such as to keep long file names short in a list. The text is kept to a single line.
+    fn truncate_at_chars(mut self, max_chars: usize) -> Self {
+        self.text_style()
+            .get_or_insert_with(Default::default)
+            .truncate = Some(Truncate::AtChars(max_chars));
+        self
+    }
+
     /// Sets the flex direction of the element to `column`.
     /// [Docs](https://tailwindcss.com/docs/flex-direction#column)
     fn flex_col(mut self) -> Self {
@@ -140,6 +151,28 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the width of the element to the smallest width its content can take without overflowing.
+    /// [Docs](https://tailwindcss.com/docs/width#fit-to-the-content)
+    fn w_min_content(mut self) -> Self {
+        self.style().intrinsic_width = Some(IntrinsicSize::MinContent);
+        self
+    }
+
+    /// Sets the width of the element to the width of its content when laid out without wrapping.
+    /// [Docs](https://tailwindcss.com/docs/width#fit-to-the-content)
+    fn w_max_content(mut self) -> Self {
+        self.style().intrinsic_width = Some(IntrinsicSize::MaxContent);
+        self
+    }
+
+    /// Sets the ratio of the element's width to its height, used to compute one
+    /// dimension when only the other is known.
+    /// [Docs](https://tailwindcss.com/docs/aspect-ratio)
+    fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.style().aspect_ratio = Some(ratio);
+        self
+    }
+
     /// Sets the initial size of flex items for this element.
     /// [Docs](https://tailwindcss.com/docs/flex-basis)
     fn flex_basis(mut self, basis: impl Into<Length>) -> Self {
@@ -337,6 +370,17 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the shadows painted behind the text of this element.
+    /// [Docs](https://developer.mozilla.org/en-US/docs/Web/CSS/text-shadow)
+    ///
+    /// This value cascades to its child elements.
+    fn text_shadow(mut self, shadows: impl Into<SmallVec<[TextShadow; 1]>>) -> Self {
+        self.text_style()
+            .get_or_insert_with(Default::default)
+            .shadows = Some(shadows.into());
+        self
+    }
+
     /// Sets the font weight of this element
     ///
     /// This value cascades to its child elements.
@@ -347,6 +391,26 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Sets the extra space added after every character of this element's text.
+    ///
+    /// This value cascades to its child elements.
+    fn letter_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.text_style()
+            .get_or_insert_with(Default::default)
+            .letter_spacing = Some(spacing.into());
+        self
+    }
+
+    /// Sets the extra space added after every space character of this element's text.
+    ///
+    /// This value cascades to its child elements.
+    fn word_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.text_style()
+            .get_or_insert_with(Default::default)
+            .word_spacing = Some(spacing.into());
+        self
+    }
+
     /// Sets the background color of this element.
     ///
     /// This value cascades to its child elements.
@@ -482,7 +546,16 @@ pub trait Styled: Sized {
     fn text_decoration_solid(mut self) -> Self {
         let style = self.text_style().get_or_insert_with(Default::default);
         let underline = style.underline.get_or_insert_with(Default::default);
-        underline.wavy = false;
+        underline.pattern = UnderlinePattern::Solid;
+        self
+    }
+
+    /// Sets the text decoration style to a dashed line.
+    /// [Docs](https://tailwindcss.com/docs/text-decoration-style)
+    fn text_decoration_dashed(mut self) -> Self {
+        let style = self.text_style().get_or_insert_with(Default::default);
+        let underline = style.underline.get_or_insert_with(Default::default);
+        underline.pattern = UnderlinePattern::dashed(px(3.));
         self
     }
 
@@ -491,7 +564,7 @@ pub trait Styled: Sized {
     fn text_decoration_wavy(mut self) -> Self {
         let style = self.text_style().get_or_insert_with(Default::default);
         let underline = style.underline.get_or_insert_with(Default::default);
-        underline.wavy = true;
+        underline.pattern = UnderlinePattern::wavy(px(1.));
         self
     }
 
@@ -576,12 +649,28 @@ pub trait Styled: Sized {
         self
     }
 
+    /// Paints the border of this element with the given gradient instead of a solid color.
+    /// See [`linear_gradient`](crate::linear_gradient) for building one.
+    ///
+    /// Elements with rounded corners paint a solid border in the gradient's first color.
+    fn border_gradient(mut self, gradient: Background) -> Self {
+        self.style().border_gradient = Some(gradient);
+        self
+    }
+
     /// Sets the opacity of this element and its children.
     fn opacity(mut self, opacity: f32) -> Self {
         self.style().opacity = Some(opacity);
         self
     }
 
+    /// Lets mouse events pass through this element to the elements beneath it.
+    /// [Docs](https://tailwindcss.com/docs/pointer-events)
+    fn pointer_events_none(mut self) -> Self {
+        self.style().pointer_events = Some(PointerEvents::None);
+        self
+    }
+
     /// Draws a debug border around this element.
     #[cfg(debug_assertions)]
     fn debug(mut self) -> Self {
@@ -596,3 +685,88 @@ pub trait Styled: Sized {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as gpui, div, point, px, ElementId, InteractiveElement, IntoElement, Modifiers,
+        ParentElement, Render, TestAppContext, ViewContext,
+    };
+
+    use super::*;
+
+    struct AspectRatioView;
+
+    impl Render for AspectRatioView {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div().flex().items_start().child(
+                div()
+                    .debug_selector(|| "thumbnail".into())
+                    .w(px(100.))
+                    .aspect_ratio(16. / 9.),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_aspect_ratio(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| AspectRatioView);
+        cx.run_until_parked();
+
+        let bounds = cx.debug_bounds("thumbnail").unwrap();
+        assert_eq!(bounds.size.width, px(100.));
+        // Layout is rounded to whole pixels.
+        assert!((bounds.size.height - px(56.25)).abs() <= px(0.5));
+    }
+
+    struct OverlayView;
+
+    impl Render for OverlayView {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            div()
+                .relative()
+                .size(px(100.))
+                .child(div().id("below").size_full().cursor_pointer())
+                .child(
+                    div()
+                        .id("overlay")
+                        .absolute()
+                        .size_full()
+                        .occlude()
+                        .pointer_events_none(),
+                )
+        }
+    }
+
+    #[gpui::test]
+    fn test_pointer_events_none(cx: &mut TestAppContext) {
+        let (_, cx) = cx.add_window_view(|_| OverlayView);
+        cx.run_until_parked();
+
+        cx.simulate_mouse_move(point(px(50.), px(50.)), None, Modifiers::default());
+        assert_eq!(
+            cx.update(|cx| cx.hover_element_id()),
+            Some(ElementId::from("below"))
+        );
+    }
+
+    #[gpui::test]
+    fn test_text_shadow(cx: &mut TestAppContext) {
+        let shadow = TextShadow {
+            offset: point(px(1.), px(2.)),
+            blur_radius: px(4.),
+            color: crate::black().opacity(0.5),
+        };
+        let mut element = div().text_shadow([shadow]);
+        assert_eq!(
+            element
+                .text_style()
+                .as_ref()
+                .and_then(|text| text.shadows.clone()),
+            Some(SmallVec::from([shadow]))
+        );
+
+        // Shadows are painted behind the text without being reported as text of their own.
+        cx.assert_element_text(element.child("shadowed"), "shadowed");
+    }
+}
@@ -1,13 +1,17 @@
 use crate::{
-    AbsoluteLength, Bounds, DefiniteLength, Edges, Length, Pixels, Point, Size, Style,
-    WindowContext,
+    AbsoluteLength, Bounds, DefiniteLength, Edges, IntrinsicSize, Length, Pixels, Point, Size,
+    Style, WindowContext,
 };
 use collections::{FxHashMap, FxHashSet};
 use smallvec::SmallVec;
 use std::fmt::Debug;
 use taffy::{
     geometry::{Point as TaffyPoint, Rect as TaffyRect, Size as TaffySize},
-    style::AvailableSpace as TaffyAvailableSpace,
+    style::{
+        AvailableSpace as TaffyAvailableSpace, Dimension, LengthPercentageAuto,
+        MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction,
+        TrackSizingFunction,
+    },
     tree::NodeId,
     TaffyTree, TraversePartialTree as _,
 };
@@ -22,6 +26,8 @@ pub struct TaffyLayoutEngine {
     taffy: TaffyTree<NodeContext>,
     absolute_layout_bounds: FxHashMap<LayoutId, Bounds<Pixels>>,
     computed_layouts: FxHashSet<LayoutId>,
+    /// Maps the grid nodes that size elements with an intrinsic width to the element's own node.
+    intrinsic_width_nodes: FxHashMap<LayoutId, LayoutId>,
 }
 
 const EXPECT_MESSAGE: &str = "we should avoid taffy layout errors by construction if possible";
@@ -32,6 +38,7 @@ impl TaffyLayoutEngine {
             taffy: TaffyTree::new(),
             absolute_layout_bounds: FxHashMap::default(),
             computed_layouts: FxHashSet::default(),
+            intrinsic_width_nodes: FxHashMap::default(),
         }
     }
 
@@ -39,6 +46,7 @@ impl TaffyLayoutEngine {
         self.taffy.clear();
         self.absolute_layout_bounds.clear();
         self.computed_layouts.clear();
+        self.intrinsic_width_nodes.clear();
     }
 
     pub fn request_layout(
@@ -48,7 +56,7 @@ impl TaffyLayoutEngine {
         children: &[LayoutId],
     ) -> LayoutId {
         let taffy_style = style.to_taffy(rem_size);
-        let layout_id = if children.is_empty() {
+        let layout_id: LayoutId = if children.is_empty() {
             self.taffy
                 .new_leaf(taffy_style)
                 .expect(EXPECT_MESSAGE)
@@ -64,7 +72,10 @@ impl TaffyLayoutEngine {
                 .into();
             parent_id
         };
-        layout_id
+        match style.intrinsic_width {
+            Some(intrinsic_width) => self.size_to_intrinsic_width(layout_id, intrinsic_width),
+            None => layout_id,
+        }
     }
 
     pub fn request_measured_layout(
@@ -86,7 +97,79 @@ impl TaffyLayoutEngine {
             )
             .expect(EXPECT_MESSAGE)
             .into();
-        layout_id
+        match style.intrinsic_width {
+            Some(intrinsic_width) => self.size_to_intrinsic_width(layout_id, intrinsic_width),
+            None => layout_id,
+        }
+    }
+
+    /// Taffy has no min-content or max-content widths, so the node is placed in a single column
+    /// grid whose column is sized to its content, which taffy resolves in the normal layout pass.
+    /// The node's placement within its parent moves to the grid, and the grid's id is returned in
+    /// place of the node's so that the parent lays out the grid instead.
+    fn size_to_intrinsic_width(
+        &mut self,
+        layout_id: LayoutId,
+        intrinsic_width: IntrinsicSize,
+    ) -> LayoutId {
+        let mut style = self
+            .taffy
+            .style(layout_id.into())
+            .expect(EXPECT_MESSAGE)
+            .clone();
+        let column = match intrinsic_width {
+            IntrinsicSize::MinContent => NonRepeatedTrackSizingFunction {
+                min: MinTrackSizingFunction::MinContent,
+                max: MaxTrackSizingFunction::MinContent,
+            },
+            IntrinsicSize::MaxContent => NonRepeatedTrackSizingFunction {
+                min: MinTrackSizingFunction::MaxContent,
+                max: MaxTrackSizingFunction::MaxContent,
+            },
+        };
+        let grid_style = taffy::style::Style {
+            display: match style.display {
+                taffy::style::Display::None => taffy::style::Display::None,
+                _ => taffy::style::Display::Grid,
+            },
+            position: style.position,
+            inset: style.inset,
+            margin: style.margin,
+            align_self: style.align_self,
+            flex_basis: style.flex_basis,
+            flex_grow: style.flex_grow,
+            flex_shrink: style.flex_shrink,
+            grid_template_columns: vec![TrackSizingFunction::Single(column)],
+            justify_items: Some(taffy::style::JustifyItems::Start),
+            ..Default::default()
+        };
+
+        style.position = taffy::style::Position::Relative;
+        style.inset = TaffyRect {
+            left: LengthPercentageAuto::Auto,
+            right: LengthPercentageAuto::Auto,
+            top: LengthPercentageAuto::Auto,
+            bottom: LengthPercentageAuto::Auto,
+        };
+        style.margin = TaffyRect {
+            left: LengthPercentageAuto::Length(0.),
+            right: LengthPercentageAuto::Length(0.),
+            top: LengthPercentageAuto::Length(0.),
+            bottom: LengthPercentageAuto::Length(0.),
+        };
+        style.align_self = None;
+        style.size.width = Dimension::Auto;
+        self.taffy
+            .set_style(layout_id.into(), style)
+            .expect(EXPECT_MESSAGE);
+
+        let grid_id: LayoutId = self
+            .taffy
+            .new_with_children(grid_style, &[layout_id.into()])
+            .expect(EXPECT_MESSAGE)
+            .into();
+        self.intrinsic_width_nodes.insert(grid_id, layout_id);
+        grid_id
     }
 
     // Used to understand performance
@@ -193,6 +276,13 @@ impl TaffyLayoutEngine {
     }
 
     pub fn layout_bounds(&mut self, id: LayoutId) -> Bounds<Pixels> {
+        match self.intrinsic_width_nodes.get(&id) {
+            Some(node_id) => self.node_bounds(*node_id),
+            None => self.node_bounds(id),
+        }
+    }
+
+    fn node_bounds(&mut self, id: LayoutId) -> Bounds<Pixels> {
         if let Some(layout) = self.absolute_layout_bounds.get(&id).cloned() {
             return layout;
         }
@@ -204,7 +294,7 @@ impl TaffyLayoutEngine {
         };
 
         if let Some(parent_id) = self.taffy.parent(id.0) {
-            let parent_bounds = self.layout_bounds(parent_id.into());
+            let parent_bounds = self.node_bounds(parent_id.into());
             bounds.origin += parent_bounds.origin;
         }
         self.absolute_layout_bounds.insert(id, bounds);
@@ -492,3 +582,64 @@ impl From<Size<Pixels>> for Size<AvailableSpace> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as gpui, px, size, Display, FlexDirection, TestAppContext};
+
+    fn fixed_size_style(width: Pixels, height: Pixels) -> Style {
+        Style {
+            size: size(width.into(), height.into()),
+            ..Style::default()
+        }
+    }
+
+    #[gpui::test]
+    fn test_intrinsic_width_is_resolved_by_parent_layout(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        cx.update(|cx| {
+            for flex_direction in [FlexDirection::Row, FlexDirection::Column] {
+                let mut engine = TaffyLayoutEngine::new();
+                let rem_size = px(16.);
+
+                let content =
+                    engine.request_layout(fixed_size_style(px(50.), px(10.)), rem_size, &[]);
+                let mut intrinsic_style = Style {
+                    intrinsic_width: Some(IntrinsicSize::MaxContent),
+                    ..Style::default()
+                };
+                intrinsic_style.margin.left = px(5.).into();
+                let intrinsic = engine.request_layout(intrinsic_style, rem_size, &[content]);
+
+                let mut sibling_style = fixed_size_style(px(20.), px(10.));
+                sibling_style.flex_grow = 1.;
+                let sibling = engine.request_layout(sibling_style, rem_size, &[]);
+
+                let parent_style = Style {
+                    display: Display::Flex,
+                    flex_direction,
+                    ..Style::default()
+                };
+                let parent = engine.request_layout(parent_style, rem_size, &[intrinsic, sibling]);
+
+                engine.compute_layout(
+                    parent,
+                    size(
+                        AvailableSpace::Definite(px(200.)),
+                        AvailableSpace::Definite(px(100.)),
+                    ),
+                    cx,
+                );
+
+                let intrinsic_bounds = engine.layout_bounds(intrinsic);
+                assert_eq!(intrinsic_bounds.size.width, px(50.), "{flex_direction:?}");
+                assert_eq!(intrinsic_bounds.origin.x, px(5.), "{flex_direction:?}");
+                assert_eq!(engine.layout_bounds(content).size.width, px(50.));
+                if flex_direction == FlexDirection::Row {
+                    assert_eq!(engine.layout_bounds(sibling).origin.x, px(55.));
+                }
+            }
+        });
+    }
+}
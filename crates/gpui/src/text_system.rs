@@ -3,6 +3,7 @@ mod font_features;
 mod line;
 mod line_layout;
 mod line_wrapper;
+mod shaped_text_cache;
 
 pub use font_fallbacks::*;
 pub use font_features::*;
@@ -11,6 +12,7 @@ pub use line_layout::*;
 pub use line_wrapper::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use shaped_text_cache::*;
 
 use crate::{
     px, Bounds, DevicePixels, Hsla, Pixels, PlatformTextSystem, Point, Result, SharedString, Size,
@@ -294,6 +296,7 @@ impl TextSystem {
 #[derive(Deref)]
 pub struct WindowTextSystem {
     line_layout_cache: LineLayoutCache,
+    shaped_text_cache: Mutex<ShapedTextCache>,
     #[deref]
     text_system: Arc<TextSystem>,
 }
@@ -302,6 +305,7 @@ impl WindowTextSystem {
     pub(crate) fn new(text_system: Arc<TextSystem>) -> Self {
         Self {
             line_layout_cache: LineLayoutCache::new(text_system.platform_text_system.clone()),
+            shaped_text_cache: Mutex::new(ShapedTextCache::new(SHAPED_TEXT_CACHE_CAPACITY)),
             text_system,
         }
     }
@@ -368,12 +372,32 @@ impl WindowTextSystem {
     /// Shape a multi line string of text, at the given font_size, for painting to the screen.
     /// Subsets of the text can be styled independently with the `runs` parameter.
     /// If `wrap_width` is provided, the line breaks will be adjusted to fit within the given width.
+    ///
+    /// Recently shaped texts are cached, so shaping the same text with the same styling again
+    /// returns the previous result without reshaping it.
     pub fn shape_text(
         &self,
         text: SharedString,
         font_size: Pixels,
         runs: &[TextRun],
         wrap_width: Option<Pixels>,
+    ) -> Result<SmallVec<[WrappedLine; 1]>> {
+        let key = ShapedTextCacheKey::new(text.clone(), font_size, runs, wrap_width);
+        if let Some(lines) = self.shaped_text_cache.lock().get(&key) {
+            return Ok(lines);
+        }
+
+        let lines = self.shape_text_uncached(text, font_size, runs, wrap_width)?;
+        self.shaped_text_cache.lock().insert(key, lines.clone());
+        Ok(lines)
+    }
+
+    fn shape_text_uncached(
+        &self,
+        text: SharedString,
+        font_size: Pixels,
+        runs: &[TextRun],
+        wrap_width: Option<Pixels>,
     ) -> Result<SmallVec<[WrappedLine; 1]>> {
         let mut runs = runs.iter().filter(|run| run.len > 0).cloned().peekable();
         let mut font_runs = self.font_runs_pool.lock().pop().unwrap_or_default();
@@ -393,14 +417,23 @@ impl WindowTextSystem {
                 };
 
                 let run_len_within_line = cmp::min(line_end, run_start + run.len) - run_start;
-
-                if last_font == Some(run.font.clone()) {
+                let letter_spacing = run.letter_spacing.unwrap_or_default();
+                let word_spacing = run.word_spacing.unwrap_or_default();
+
+                if last_font == Some(run.font.clone())
+                    && font_runs.last().map_or(false, |last_run| {
+                        last_run.letter_spacing == letter_spacing
+                            && last_run.word_spacing == word_spacing
+                    })
+                {
                     font_runs.last_mut().unwrap().len += run_len_within_line;
                 } else {
                     last_font = Some(run.font.clone());
                     font_runs.push(FontRun {
                         len: run_len_within_line,
                         font_id: self.resolve_font(&run.font),
+                        letter_spacing,
+                        word_spacing,
                     });
                 }
 
@@ -496,8 +529,13 @@ impl WindowTextSystem {
         let mut font_runs = self.font_runs_pool.lock().pop().unwrap_or_default();
         for run in runs.iter() {
             let font_id = self.resolve_font(&run.font);
+            let letter_spacing = run.letter_spacing.unwrap_or_default();
+            let word_spacing = run.word_spacing.unwrap_or_default();
             if let Some(last_run) = font_runs.last_mut() {
-                if last_run.font_id == font_id {
+                if last_run.font_id == font_id
+                    && last_run.letter_spacing == letter_spacing
+                    && last_run.word_spacing == word_spacing
+                {
                     last_run.len += run.len;
                     continue;
                 }
@@ -505,6 +543,8 @@ impl WindowTextSystem {
             font_runs.push(FontRun {
                 len: run.len,
                 font_id,
+                letter_spacing,
+                word_spacing,
             });
         }
 
@@ -534,7 +574,8 @@ pub struct LineWrapperHandle {
 impl Drop for LineWrapperHandle {
     fn drop(&mut self) {
         let mut state = self.text_system.wrapper_pool.lock();
-        let wrapper = self.wrapper.take().unwrap();
+        let mut wrapper = self.wrapper.take().unwrap();
+        wrapper.set_spacing(Pixels::ZERO, Pixels::ZERO);
         state
             .get_mut(&FontIdWithSize {
                 font_id: wrapper.font_id,
@@ -632,7 +673,7 @@ impl Display for FontStyle {
 }
 
 /// A styled run of text, for use in [`TextLayout`].
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TextRun {
     /// A number of utf8 bytes
     pub len: usize,
@@ -646,6 +687,10 @@ pub struct TextRun {
     pub underline: Option<UnderlineStyle>,
     /// The strikethrough style (if any)
     pub strikethrough: Option<StrikethroughStyle>,
+    /// Extra space added after every character (if any)
+    pub letter_spacing: Option<Pixels>,
+    /// Extra space added after every space character (if any)
+    pub word_spacing: Option<Pixels>,
 }
 
 /// An identifier for a specific glyph, as returned by [`TextSystem::layout_line`].
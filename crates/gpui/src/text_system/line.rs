@@ -1,11 +1,11 @@
 use crate::{
     black, fill, point, px, size, Bounds, Half, Hsla, LineLayout, Pixels, Point, Result,
-    SharedString, StrikethroughStyle, UnderlineStyle, WindowContext, WrapBoundary,
+    SharedString, StrikethroughStyle, TextShadow, UnderlineStyle, WindowContext, WrapBoundary,
     WrappedLineLayout,
 };
 use derive_more::{Deref, DerefMut};
 use smallvec::SmallVec;
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 /// Set the text decoration for a run of text.
 #[derive(Debug, Clone)]
@@ -75,6 +75,9 @@ impl ShapedLine {
             cx,
         )?;
 
+        #[cfg(any(test, feature = "test-support"))]
+        cx.window.next_frame.painted_text.push(self.text.clone());
+
         Ok(())
     }
 }
@@ -97,6 +100,24 @@ impl WrappedLine {
         self.layout.len()
     }
 
+    /// The byte range of each display row of this line, with wrapped lines split into a row
+    /// per wrap boundary.
+    pub fn row_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        let mut row_start_ix = 0;
+        self.wrap_boundaries
+            .iter()
+            .map(|wrap_boundary| {
+                self.unwrapped_layout.runs[wrap_boundary.run_ix].glyphs[wrap_boundary.glyph_ix]
+                    .index
+            })
+            .chain(Some(self.len()))
+            .map(move |row_end_ix| {
+                let row = row_start_ix..row_end_ix;
+                row_start_ix = row_end_ix;
+                row
+            })
+    }
+
     /// Paint this line of text to the window.
     pub fn paint(
         &self,
@@ -113,8 +134,68 @@ impl WrappedLine {
             cx,
         )?;
 
+        #[cfg(any(test, feature = "test-support"))]
+        cx.window.next_frame.painted_text.push(self.text.clone());
+
         Ok(())
     }
+
+    /// Paint a shadow of this line of text, which should be done before painting the line itself.
+    ///
+    /// Glyphs can't be blurred when they're rasterized, so blur is approximated by painting
+    /// translucent copies of the line spread across the blur radius.
+    pub fn paint_shadow(
+        &self,
+        origin: Point<Pixels>,
+        line_height: Pixels,
+        shadow: &TextShadow,
+        cx: &mut WindowContext,
+    ) -> Result<()> {
+        let sample_offsets = shadow_sample_offsets(shadow.blur_radius);
+        // Overlapping samples should add up to the shadow's opacity where they all cover the glyph.
+        let sample_alpha = 1. - (1. - shadow.color.a).powf(1. / sample_offsets.len() as f32);
+        let decoration_runs = self
+            .decoration_runs
+            .iter()
+            .map(|run| DecorationRun {
+                len: run.len,
+                color: Hsla {
+                    a: sample_alpha,
+                    ..shadow.color
+                },
+                background_color: None,
+                underline: None,
+                strikethrough: None,
+            })
+            .collect::<SmallVec<[DecorationRun; 32]>>();
+
+        for sample_offset in sample_offsets {
+            paint_line(
+                origin + shadow.offset + sample_offset,
+                &self.layout.unwrapped_layout,
+                line_height,
+                &decoration_runs,
+                &self.wrap_boundaries,
+                cx,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the offsets at which to paint copies of a text shadow to approximate the given blur.
+fn shadow_sample_offsets(blur_radius: Pixels) -> SmallVec<[Point<Pixels>; 9]> {
+    let mut offsets = SmallVec::new();
+    offsets.push(Point::default());
+    if blur_radius > Pixels::ZERO {
+        let radius = blur_radius.half();
+        for step in 0..8 {
+            let angle = step as f32 * std::f32::consts::FRAC_PI_4;
+            offsets.push(point(radius * angle.cos(), radius * angle.sin()));
+        }
+    }
+    offsets
 }
 
 fn paint_line(
@@ -243,7 +324,7 @@ fn paint_line(
                                 UnderlineStyle {
                                     color: Some(run_underline.color.unwrap_or(style_run.color)),
                                     thickness: run_underline.thickness,
-                                    wavy: run_underline.wavy,
+                                    pattern: run_underline.pattern,
                                 },
                             ));
                         }
@@ -54,6 +54,36 @@ pub struct ShapedGlyph {
 }
 
 impl LineLayout {
+    /// Moves glyphs to the right to make room for the letter and word spacing of each run.
+    fn apply_spacing(&mut self, text: &str, runs: &[FontRun]) {
+        if runs
+            .iter()
+            .all(|run| run.letter_spacing == Pixels::ZERO && run.word_spacing == Pixels::ZERO)
+        {
+            return;
+        }
+
+        let mut runs = runs.iter().peekable();
+        let mut run_end = runs.peek().map_or(0, |run| run.len);
+        let mut offset = Pixels::ZERO;
+        for glyph in self.runs.iter_mut().flat_map(|run| run.glyphs.iter_mut()) {
+            glyph.position.x += offset;
+
+            while glyph.index >= run_end && runs.len() > 1 {
+                runs.next();
+                run_end += runs.peek().map_or(0, |run| run.len);
+            }
+            let Some(run) = runs.peek() else {
+                break;
+            };
+            offset += run.letter_spacing;
+            if text[glyph.index..].starts_with(' ') {
+                offset += run.word_spacing;
+            }
+        }
+        self.width += offset;
+    }
+
     /// The index for the character at the given x coordinate
     pub fn index_for_x(&self, x: Pixels) -> Option<usize> {
         if x >= self.width {
@@ -511,10 +541,11 @@ impl LineLayoutCache {
             layout
         } else {
             let text = SharedString::from(text);
-            let layout = Arc::new(
-                self.platform_text_system
-                    .layout_line(&text, font_size, runs),
-            );
+            let mut layout = self
+                .platform_text_system
+                .layout_line(&text, font_size, runs);
+            layout.apply_spacing(&text, runs);
+            let layout = Arc::new(layout);
             let key = Arc::new(CacheKey {
                 text,
                 font_size,
@@ -533,6 +564,11 @@ impl LineLayoutCache {
 pub struct FontRun {
     pub(crate) len: usize,
     pub(crate) font_id: FontId,
+    /// Extra space added after every glyph. Applied after shaping, so platform text systems
+    /// can ignore it.
+    pub(crate) letter_spacing: Pixels,
+    /// Extra space added after every space character.
+    pub(crate) word_spacing: Pixels,
 }
 
 trait AsCacheKeyRef {
@@ -603,3 +639,45 @@ impl<'a> AsCacheKeyRef for CacheKeyRef<'a> {
         *self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_apply_spacing() {
+        let glyph = |index: usize, x: f32| ShapedGlyph {
+            id: GlyphId(0),
+            position: point(px(x), px(0.)),
+            index,
+            is_emoji: false,
+        };
+        let mut layout = LineLayout {
+            width: px(30.),
+            len: 3,
+            runs: vec![ShapedRun {
+                font_id: FontId(0),
+                glyphs: smallvec![glyph(0, 0.), glyph(1, 10.), glyph(2, 20.)],
+            }],
+            ..Default::default()
+        };
+        layout.apply_spacing(
+            "a b",
+            &[FontRun {
+                len: 3,
+                font_id: FontId(0),
+                letter_spacing: px(1.),
+                word_spacing: px(5.),
+            }],
+        );
+
+        let positions = layout.runs[0]
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.position.x)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, [px(0.), px(11.), px(27.)]);
+        assert_eq!(layout.width, px(38.));
+    }
+}
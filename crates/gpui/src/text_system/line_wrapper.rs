@@ -7,6 +7,8 @@ pub struct LineWrapper {
     platform_text_system: Arc<dyn PlatformTextSystem>,
     pub(crate) font_id: FontId,
     pub(crate) font_size: Pixels,
+    letter_spacing: Pixels,
+    word_spacing: Pixels,
     cached_ascii_char_widths: [Option<Pixels>; 128],
     cached_other_char_widths: HashMap<char, Pixels>,
 }
@@ -24,11 +26,20 @@ impl LineWrapper {
             platform_text_system: text_system,
             font_id,
             font_size,
+            letter_spacing: Pixels::ZERO,
+            word_spacing: Pixels::ZERO,
             cached_ascii_char_widths: [None; 128],
             cached_other_char_widths: HashMap::default(),
         }
     }
 
+    /// Sets the extra space added after each character, and after each space character, when
+    /// measuring text. This is reset when the wrapper is returned to the text system.
+    pub fn set_spacing(&mut self, letter_spacing: Pixels, word_spacing: Pixels) {
+        self.letter_spacing = letter_spacing;
+        self.word_spacing = word_spacing;
+    }
+
     /// Wrap a line of text to the given width with this wrapper's font and font size.
     pub fn wrap_line<'a>(
         &'a mut self,
@@ -66,7 +77,7 @@ impl LineWrapper {
                     first_non_whitespace_ix = Some(ix);
                 }
 
-                let char_width = self.width_for_char(c);
+                let char_width = self.advance_for_char(c);
                 width += char_width;
                 if width > wrap_width && ix > last_wrap_ix {
                     if let (None, Some(first_non_whitespace_ix)) = (indent, first_non_whitespace_ix)
@@ -86,7 +97,7 @@ impl LineWrapper {
                     }
 
                     if let Some(indent) = indent {
-                        width += self.width_for_char(' ') * indent as f32;
+                        width += self.advance_for_char(' ') * indent as f32;
                     }
 
                     return Some(Boundary::new(last_wrap_ix, indent.unwrap_or(0)));
@@ -110,7 +121,7 @@ impl LineWrapper {
         let mut ellipsis_width = px(0.);
         if let Some(ellipsis) = ellipsis {
             for c in ellipsis.chars() {
-                ellipsis_width += self.width_for_char(c);
+                ellipsis_width += self.advance_for_char(c);
             }
         }
 
@@ -121,7 +132,7 @@ impl LineWrapper {
                 truncate_ix = ix;
             }
 
-            let char_width = self.width_for_char(c);
+            let char_width = self.advance_for_char(c);
             width += char_width;
 
             if width.floor() > truncate_width {
@@ -161,6 +172,17 @@ impl LineWrapper {
         matches!(c, '⋯')
     }
 
+    /// The width of the character including letter and word spacing, matching how far
+    /// the shaped text advances past it.
+    #[inline(always)]
+    fn advance_for_char(&mut self, c: char) -> Pixels {
+        let mut advance = self.width_for_char(c) + self.letter_spacing;
+        if c == ' ' {
+            advance += self.word_spacing;
+        }
+        advance
+    }
+
     #[inline(always)]
     fn width_for_char(&mut self, c: char) -> Pixels {
         if (c as u32) < 128 {
@@ -190,12 +212,39 @@ impl LineWrapper {
                 &[FontRun {
                     len: buffer.len(),
                     font_id: self.font_id,
+                    letter_spacing: Pixels::ZERO,
+                    word_spacing: Pixels::ZERO,
                 }],
             )
             .width
     }
 }
 
+/// Truncates a line after the given number of characters, or before its first newline if
+/// sooner, appending the ellipsis if any text was cut off.
+pub fn truncate_line_at_chars(
+    line: SharedString,
+    max_chars: usize,
+    ellipsis: &str,
+    runs: &mut Vec<TextRun>,
+) -> SharedString {
+    let newline_ix = line.find('\n');
+    let truncate_ix = line
+        .char_indices()
+        .nth(max_chars)
+        .map(|(ix, _)| ix)
+        .into_iter()
+        .chain(newline_ix)
+        .min();
+
+    let Some(truncate_ix) = truncate_ix else {
+        return line;
+    };
+    let result = SharedString::from(format!("{}{}", &line[..truncate_ix], ellipsis));
+    update_runs_after_truncation(&result, ellipsis, runs);
+    result
+}
+
 fn update_runs_after_truncation(result: &str, ellipsis: &str, runs: &mut Vec<TextRun>) {
     let mut truncate_at = result.len() - ellipsis.len();
     let mut run_end = None;
@@ -268,10 +317,40 @@ mod tests {
                 background_color: None,
                 underline: None,
                 strikethrough: None,
+                letter_spacing: None,
+                word_spacing: None,
             })
             .collect()
     }
 
+    #[test]
+    fn test_wrap_line_with_spacing() {
+        let mut wrapper = build_wrapper();
+        let line = "aa bbb cccc ddddd eeee";
+        let unspaced = wrapper.wrap_line(line, px(72.25)).collect::<Vec<_>>();
+
+        // Letter spacing as wide as each glyph doubles every advance, so the line wraps
+        // as it would without spacing at half the width.
+        let char_width = wrapper.width_for_char('a');
+        wrapper.set_spacing(char_width, Pixels::ZERO);
+        assert_eq!(
+            wrapper.wrap_line(line, px(144.5)).collect::<Vec<_>>(),
+            unspaced
+        );
+
+        // Word spacing only widens the spaces, so the first word no longer fits with the next.
+        wrapper.set_spacing(Pixels::ZERO, char_width);
+        assert_eq!(
+            wrapper.wrap_line(line, px(72.25)).collect::<Vec<_>>(),
+            &[
+                Boundary::new(3, 0),
+                Boundary::new(7, 0),
+                Boundary::new(12, 0),
+                Boundary::new(18, 0)
+            ],
+        );
+    }
+
     #[test]
     fn test_wrap_line() {
         let mut wrapper = build_wrapper();
@@ -368,6 +447,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_truncate_line_at_chars() {
+        let mut runs = generate_test_runs(&[6, 8]);
+        let result = truncate_line_at_chars("héllo wörld".into(), 7, "…", &mut runs);
+        assert_eq!(result, "héllo w…");
+        assert_eq!(runs.iter().map(|run| run.len).collect::<Vec<_>>(), [6, 5]);
+
+        let mut runs = generate_test_runs(&[10]);
+        let result = truncate_line_at_chars("first\nnext".into(), 8, "…", &mut runs);
+        assert_eq!(result, "first…");
+        assert_eq!(runs[0].len, result.len());
+
+        let mut runs = generate_test_runs(&[5]);
+        let result = truncate_line_at_chars("short".into(), 5, "…", &mut runs);
+        assert_eq!(result, "short");
+        assert_eq!(runs[0].len, 5);
+    }
+
     #[test]
     fn test_truncate_multiple_runs() {
         let mut wrapper = build_wrapper();
@@ -533,6 +630,8 @@ mod tests {
                 underline: Default::default(),
                 strikethrough: None,
                 background_color: None,
+                letter_spacing: None,
+                word_spacing: None,
             };
             let bold = TextRun {
                 len: 0,
@@ -541,6 +640,8 @@ mod tests {
                 underline: Default::default(),
                 strikethrough: None,
                 background_color: None,
+                letter_spacing: None,
+                word_spacing: None,
             };
 
             impl TextRun {
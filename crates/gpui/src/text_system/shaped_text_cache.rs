@@ -0,0 +1,143 @@
+use crate::{Pixels, SharedString, TextRun, WrappedLine};
+use collections::{BTreeMap, FxHashMap};
+use smallvec::SmallVec;
+
+/// The maximum number of shaped texts retained by a [`ShapedTextCache`].
+pub(crate) const SHAPED_TEXT_CACHE_CAPACITY: usize = 256;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct ShapedTextCacheKey {
+    text: SharedString,
+    font_size: Pixels,
+    runs: SmallVec<[TextRun; 1]>,
+    wrap_width: Option<Pixels>,
+}
+
+impl ShapedTextCacheKey {
+    pub(crate) fn new(
+        text: SharedString,
+        font_size: Pixels,
+        runs: &[TextRun],
+        wrap_width: Option<Pixels>,
+    ) -> Self {
+        Self {
+            text,
+            font_size,
+            runs: SmallVec::from_slice(runs),
+            wrap_width,
+        }
+    }
+}
+
+/// A least-recently-used cache of shaped text, so that rendering the same text
+/// many times with identical styling only shapes it once.
+pub(crate) struct ShapedTextCache {
+    entries: FxHashMap<ShapedTextCacheKey, (SmallVec<[WrappedLine; 1]>, u64)>,
+    /// The cached keys, ordered from least to most recently used.
+    recency: BTreeMap<u64, ShapedTextCacheKey>,
+    capacity: usize,
+    clock: u64,
+}
+
+impl ShapedTextCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            recency: BTreeMap::default(),
+            capacity,
+            clock: 0,
+        }
+    }
+
+    pub(crate) fn get(&mut self, key: &ShapedTextCacheKey) -> Option<SmallVec<[WrappedLine; 1]>> {
+        let (lines, last_used) = self.entries.get_mut(key)?;
+        self.clock += 1;
+        let key = self.recency.remove(last_used)?;
+        *last_used = self.clock;
+        self.recency.insert(self.clock, key);
+        Some(lines.clone())
+    }
+
+    pub(crate) fn insert(&mut self, key: ShapedTextCacheKey, lines: SmallVec<[WrappedLine; 1]>) {
+        self.clock += 1;
+        if let Some((_, last_used)) = self.entries.get(&key) {
+            self.recency.remove(last_used);
+        } else if self.entries.len() >= self.capacity {
+            if let Some((_, least_recently_used)) = self.recency.pop_first() {
+                self.entries.remove(&least_recently_used);
+            }
+        }
+        self.recency.insert(self.clock, key.clone());
+        self.entries.insert(key, (lines, self.clock));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::px;
+    use smallvec::smallvec;
+
+    fn key(text: &'static str) -> ShapedTextCacheKey {
+        ShapedTextCacheKey::new(text.into(), px(14.), &[], None)
+    }
+
+    #[test]
+    fn test_shaped_text_cache_evicts_least_recently_used() {
+        let mut cache = ShapedTextCache::new(2);
+        cache.insert(key("a"), smallvec![WrappedLine::default()]);
+        cache.insert(key("b"), smallvec![WrappedLine::default()]);
+
+        // Touch "a" so that "b" becomes the least recently used entry.
+        assert!(cache.get(&key("a")).is_some());
+        cache.insert(key("c"), smallvec![WrappedLine::default()]);
+
+        assert!(cache.get(&key("a")).is_some());
+        assert!(cache.get(&key("b")).is_none());
+        assert!(cache.get(&key("c")).is_some());
+    }
+
+    #[test]
+    fn test_shaped_text_cache_key_includes_wrap_width() {
+        let mut cache = ShapedTextCache::new(SHAPED_TEXT_CACHE_CAPACITY);
+        cache.insert(key("loading…"), smallvec![WrappedLine::default()]);
+
+        let wrapped = ShapedTextCacheKey::new("loading…".into(), px(14.), &[], Some(px(100.)));
+        assert!(cache.get(&wrapped).is_none());
+        assert!(cache.get(&key("loading…")).is_some());
+    }
+
+    #[test]
+    fn test_shaped_text_cache_key_compares_runs() {
+        let run = |len| TextRun {
+            len,
+            font: crate::font("Helvetica"),
+            color: crate::black(),
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+            letter_spacing: None,
+            word_spacing: None,
+        };
+
+        let mut cache = ShapedTextCache::new(SHAPED_TEXT_CACHE_CAPACITY);
+        let one_run = ShapedTextCacheKey::new("ab".into(), px(14.), &[run(2)], None);
+        let two_runs = ShapedTextCacheKey::new("ab".into(), px(14.), &[run(1), run(1)], None);
+        cache.insert(one_run.clone(), smallvec![WrappedLine::default()]);
+
+        assert!(cache.get(&two_runs).is_none());
+        assert!(cache.get(&one_run).is_some());
+    }
+
+    #[test]
+    fn test_shaped_text_cache_reinsert_does_not_evict() {
+        let mut cache = ShapedTextCache::new(2);
+        cache.insert(key("a"), smallvec![WrappedLine::default()]);
+        cache.insert(key("b"), smallvec![WrappedLine::default()]);
+        cache.insert(key("a"), smallvec![WrappedLine::default()]);
+
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.recency.len(), 2);
+        assert!(cache.get(&key("b")).is_some());
+    }
+}
@@ -1,19 +1,20 @@
 use crate::{
-    point, prelude::*, px, size, transparent_black, Action, AnyDrag, AnyElement, AnyTooltip,
+    div, point, prelude::*, px, size, transparent_black, Action, AnyDrag, AnyElement, AnyTooltip,
     AnyView, AppContext, Arena, Asset, AsyncWindowContext, AvailableSpace, Background, Bounds,
     BoxShadow, Context, Corners, CursorStyle, Decorations, DevicePixels, DispatchActionListener,
     DispatchNodeId, DispatchTree, DisplayId, Edges, Effect, Entity, EntityId, EventEmitter,
-    FileDropEvent, Flatten, FontId, Global, GlobalElementId, GlyphId, GpuSpecs, Hsla, InputHandler,
-    IsZero, KeyBinding, KeyContext, KeyDownEvent, KeyEvent, Keystroke, KeystrokeEvent,
-    KeystrokeObserver, LayoutId, LineLayoutIndex, Model, ModelContext, Modifiers,
+    FileDropEvent, Flatten, FontId, Global, GlobalElementId, GlyphId, GpuSpecs, Hsla, InputEvent,
+    InputHandler, IsZero, KeyBinding, KeyContext, KeyDownEvent, KeyEvent, Keystroke,
+    KeystrokeEvent, KeystrokeObserver, LayoutId, LineLayoutIndex, Model, ModelContext, Modifiers,
     ModifiersChangedEvent, MonochromeSprite, MouseButton, MouseEvent, MouseMoveEvent, MouseUpEvent,
     Path, Pixels, PlatformAtlas, PlatformDisplay, PlatformInput, PlatformInputHandler,
     PlatformWindow, Point, PolychromeSprite, PromptLevel, Quad, Render, RenderGlyphParams,
-    RenderImage, RenderImageParams, RenderSvgParams, Replay, ResizeEdge, ScaledPixels, Scene,
-    Shadow, SharedString, Size, StrikethroughStyle, Style, SubscriberSet, Subscription,
-    TaffyLayoutEngine, Task, TextStyle, TextStyleRefinement, TransformationMatrix, Underline,
-    UnderlineStyle, View, VisualContext, WeakView, WindowAppearance, WindowBackgroundAppearance,
-    WindowBounds, WindowControls, WindowDecorations, WindowOptions, WindowParams, WindowTextSystem,
+    RenderImage, RenderImageParams, RenderSvgParams, Replay, ResizeEdge, SaveDialogOptions,
+    ScaledPixels, Scene, Shadow, SharedString, Size, SpeechPriority, StrikethroughStyle, Style,
+    SubscriberSet, Subscription, TaffyLayoutEngine, Task, TextStyle, TextStyleRefinement,
+    Transformation, TransformationMatrix, Underline, UnderlinePattern, UnderlineStyle, View,
+    VisualContext, WeakView, WindowAppearance, WindowBackgroundAppearance, WindowBounds,
+    WindowControls, WindowDecorations, WindowOptions, WindowParams, WindowTextSystem,
     SUBPIXEL_VARIANTS,
 };
 use anyhow::{anyhow, Context as _, Result};
@@ -38,6 +39,7 @@ use std::{
     marker::PhantomData,
     mem,
     ops::Range,
+    path::PathBuf,
     rc::Rc,
     sync::{
         atomic::{AtomicUsize, Ordering::SeqCst},
@@ -49,12 +51,17 @@ use util::post_inc;
 use util::{measure, ResultExt};
 use uuid::Uuid;
 
+mod notifications;
 mod prompts;
 
+pub use notifications::*;
 pub use prompts::*;
 
 pub(crate) const DEFAULT_WINDOW_SIZE: Size<Pixels> = size(px(1024.), px(700.));
 
+/// The width and height, in pixels, of icons passed to [`WindowContext::set_window_icon`].
+pub const WINDOW_ICON_SIZE: u32 = 64;
+
 /// Represents the two different phases when dispatching events.
 #[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
 pub enum DispatchPhase {
@@ -345,6 +352,9 @@ pub struct Hitbox {
     pub content_mask: ContentMask<Pixels>,
     /// Whether the hitbox occludes other hitboxes inserted prior.
     pub opaque: bool,
+    /// The id of the innermost element with an id that was being prepainted
+    /// when the hitbox was inserted.
+    pub element_id: Option<ElementId>,
 }
 
 impl Hitbox {
@@ -378,6 +388,15 @@ pub(crate) struct TooltipBounds {
     bounds: Bounds<Pixels>,
 }
 
+/// A view shown above the window's contents with [`WindowContext::open_popup_at`].
+struct WindowPopup {
+    view: AnyView,
+    position: Point<Pixels>,
+    focus_handle: FocusHandle,
+    previous_focus: Option<FocusHandle>,
+    _dismiss_subscription: Subscription,
+}
+
 #[derive(Clone)]
 pub(crate) struct TooltipRequest {
     id: TooltipId,
@@ -406,10 +425,14 @@ pub(crate) struct Frame {
     pub(crate) hitboxes: Vec<Hitbox>,
     pub(crate) deferred_draws: Vec<DeferredDraw>,
     pub(crate) input_handlers: Vec<Option<PlatformInputHandler>>,
+    pub(crate) text_input_rect: Option<Bounds<Pixels>>,
     pub(crate) tooltip_requests: Vec<Option<TooltipRequest>>,
     pub(crate) cursor_styles: Vec<CursorStyleRequest>,
     #[cfg(any(test, feature = "test-support"))]
     pub(crate) debug_bounds: FxHashMap<String, Bounds<Pixels>>,
+    /// The text of every line painted this frame, in paint order.
+    #[cfg(any(test, feature = "test-support"))]
+    pub(crate) painted_text: Vec<SharedString>,
 }
 
 #[derive(Clone, Default)]
@@ -445,11 +468,14 @@ impl Frame {
             hitboxes: Vec::new(),
             deferred_draws: Vec::new(),
             input_handlers: Vec::new(),
+            text_input_rect: None,
             tooltip_requests: Vec::new(),
             cursor_styles: Vec::new(),
 
             #[cfg(any(test, feature = "test-support"))]
             debug_bounds: FxHashMap::default(),
+            #[cfg(any(test, feature = "test-support"))]
+            painted_text: Vec::new(),
         }
     }
 
@@ -460,11 +486,15 @@ impl Frame {
         self.dispatch_tree.clear();
         self.scene.clear();
         self.input_handlers.clear();
+        self.text_input_rect = None;
         self.tooltip_requests.clear();
         self.cursor_styles.clear();
         self.hitboxes.clear();
         self.deferred_draws.clear();
         self.focus = None;
+
+        #[cfg(any(test, feature = "test-support"))]
+        self.painted_text.clear();
     }
 
     pub(crate) fn hit_test(&self, position: Point<Pixels>) -> HitTest {
@@ -543,6 +573,7 @@ pub struct Window {
     appearance_observers: SubscriberSet<(), AnyObserver>,
     active: Rc<Cell<bool>>,
     hovered: Rc<Cell<bool>>,
+    pointer_locked: bool,
     pub(crate) dirty: Rc<Cell<bool>>,
     pub(crate) needs_present: Rc<Cell<bool>>,
     pub(crate) last_input_timestamp: Rc<Cell<Instant>>,
@@ -555,6 +586,9 @@ pub struct Window {
     pending_modifier: ModifierState,
     pending_input_observers: SubscriberSet<(), AnyObserver>,
     prompt: Option<RenderablePromptHandle>,
+    popup: Option<WindowPopup>,
+    pub(crate) notifications: Vec<ActiveNotification>,
+    next_notification_id: usize,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -604,6 +638,32 @@ fn default_bounds(display_id: Option<DisplayId>, cx: &mut AppContext) -> Bounds<
         })
 }
 
+/// Places a popup of the given size at the given position. On each axis where it would
+/// overflow the viewport, the popup is flipped to the other side of the position, or moved
+/// against the edge of the viewport if it doesn't fit there either.
+fn popup_bounds(
+    position: Point<Pixels>,
+    popup_size: Size<Pixels>,
+    viewport_size: Size<Pixels>,
+) -> Bounds<Pixels> {
+    let mut origin = position;
+    if position.x + popup_size.width > viewport_size.width {
+        origin.x = if position.x >= popup_size.width {
+            position.x - popup_size.width
+        } else {
+            cmp::max(Pixels::ZERO, viewport_size.width - popup_size.width)
+        };
+    }
+    if position.y + popup_size.height > viewport_size.height {
+        origin.y = if position.y >= popup_size.height {
+            position.y - popup_size.height
+        } else {
+            cmp::max(Pixels::ZERO, viewport_size.height - popup_size.height)
+        };
+    }
+    Bounds::new(origin, popup_size)
+}
+
 impl Window {
     pub(crate) fn new(
         handle: AnyWindowHandle,
@@ -749,6 +809,9 @@ impl Window {
                 handle
                     .update(&mut cx, |_, cx| {
                         cx.window.active.set(active);
+                        if !active {
+                            cx.unlock_pointer();
+                        }
                         cx.window
                             .activation_observers
                             .clone()
@@ -820,6 +883,7 @@ impl Window {
             appearance_observers: SubscriberSet::new(),
             active,
             hovered,
+            pointer_locked: false,
             dirty,
             needs_present,
             last_input_timestamp,
@@ -832,6 +896,9 @@ impl Window {
             pending_modifier: ModifierState::default(),
             pending_input_observers: SubscriberSet::new(),
             prompt: None,
+            popup: None,
+            notifications: Vec::new(),
+            next_notification_id: 0,
         })
     }
     fn new_focus_listener(&self, value: AnyWindowFocusListener) -> (Subscription, impl FnOnce()) {
@@ -947,6 +1014,43 @@ impl<'a> WindowContext<'a> {
         self.refresh();
     }
 
+    /// Move focus to the next focusable element in paint order, wrapping around
+    /// to the first one after the last.
+    pub fn focus_next(&mut self) {
+        self.focus_adjacent(1);
+    }
+
+    /// Move focus to the previous focusable element in paint order, wrapping around
+    /// to the last one before the first.
+    pub fn focus_prev(&mut self) {
+        self.focus_adjacent(-1);
+    }
+
+    fn focus_adjacent(&mut self, direction: isize) {
+        if !self.window.focus_enabled {
+            return;
+        }
+
+        let focus_order = self.window.rendered_frame.dispatch_tree.focus_order();
+        if focus_order.is_empty() {
+            return;
+        }
+        let len = focus_order.len() as isize;
+        let next_ix = match self
+            .window
+            .focus
+            .and_then(|focus_id| focus_order.iter().position(|id| *id == focus_id))
+        {
+            Some(ix) => (ix as isize + direction).rem_euclid(len),
+            None if direction > 0 => 0,
+            None => len - 1,
+        };
+
+        self.window.focus = Some(focus_order[next_ix as usize]);
+        self.clear_pending_keystrokes();
+        self.refresh();
+    }
+
     /// Remove focus from all elements within this context's window.
     pub fn blur(&mut self) {
         if !self.window.focus_enabled {
@@ -1183,6 +1287,23 @@ impl<'a> WindowContext<'a> {
             .spawn(|app| f(AsyncWindowContext::new(app, self.window.handle)))
     }
 
+    /// Run CPU-intensive or blocking work, such as parsing or image decoding, on rayon's thread pool
+    /// rather than on the async executors, where it would starve other futures.
+    /// The returned task resolves to the work's result on the main thread.
+    pub fn spawn_blocking<T>(&self, work: impl FnOnce() -> T + Send + 'static) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        rayon::spawn(move || {
+            tx.send(work()).ok();
+        });
+        self.app.foreground_executor().spawn(async move {
+            rx.await
+                .expect("blocking work was dropped before it completed")
+        })
+    }
+
     fn bounds_changed(&mut self) {
         self.window.scale_factor = self.window.platform_window.scale_factor();
         self.window.viewport_size = self.window.platform_window.content_size();
@@ -1316,6 +1437,37 @@ impl<'a> WindowContext<'a> {
         self.window.platform_window.show_character_palette();
     }
 
+    /// Replace the icon the OS shows for this window, for example to draw progress on it,
+    /// or restore the default icon when `None`. The bytes are a [`WINDOW_ICON_SIZE`] square
+    /// of RGBA pixels.
+    ///
+    /// This is implemented on macOS, where it sets the application's dock icon, and on X11.
+    /// It does nothing on Wayland, which has no window icon protocol in gpui yet, or on Windows.
+    pub fn set_window_icon(&mut self, icon: Option<Arc<[u8]>>) {
+        if let Some(icon) = &icon {
+            debug_assert_eq!(
+                icon.len(),
+                (WINDOW_ICON_SIZE * WINDOW_ICON_SIZE * 4) as usize,
+                "window icons must be {WINDOW_ICON_SIZE}x{WINDOW_ICON_SIZE} RGBA images"
+            );
+        }
+        self.window.platform_window.set_icon(icon);
+    }
+
+    /// Ask the screen reader to announce the given message, even though focus hasn't moved.
+    /// Use this for state changes that happen in the background, such as a finished task or
+    /// new search results. Does nothing on platforms without screen reader support.
+    pub fn screen_reader_speak(
+        &mut self,
+        message: impl Into<SharedString>,
+        priority: SpeechPriority,
+    ) {
+        let message = message.into();
+        self.window
+            .platform_window
+            .screen_reader_speak(&message, priority);
+    }
+
     /// The scale factor of the display associated with the window. For example, it could
     /// return 2.0 for a "retina" display, indicating that each logical pixel should actually
     /// be rendered as two pixels on screen.
@@ -1380,6 +1532,34 @@ impl<'a> WindowContext<'a> {
         self.window.default_prevented
     }
 
+    /// Locks the pointer to the window, hiding its movement from the rest of the system.
+    /// While locked, mouse move events report a virtual position accumulated from the
+    /// movement deltas, which is useful for camera controls and similar interactions.
+    /// The pointer is unlocked automatically when the window loses focus.
+    ///
+    /// Returns whether the pointer is locked. Locking isn't supported on every platform,
+    /// and on Wayland it requires the compositor to support the pointer constraints and
+    /// relative pointer protocols.
+    pub fn lock_pointer(&mut self) -> bool {
+        if !self.window.pointer_locked && self.is_window_active() {
+            self.window.pointer_locked = self.window.platform_window.set_pointer_locked(true);
+        }
+        self.window.pointer_locked
+    }
+
+    /// Releases a pointer lock acquired with [`Self::lock_pointer`].
+    pub fn unlock_pointer(&mut self) {
+        if self.window.pointer_locked {
+            self.window.pointer_locked = false;
+            self.window.platform_window.set_pointer_locked(false);
+        }
+    }
+
+    /// Returns whether the pointer is currently locked to this window.
+    pub fn is_pointer_locked(&self) -> bool {
+        self.window.pointer_locked
+    }
+
     /// Determine whether the given action is available along the dispatch path to the currently focused element.
     pub fn is_action_available(&self, action: &dyn Action) -> bool {
         let target = self
@@ -1402,6 +1582,27 @@ impl<'a> WindowContext<'a> {
         self.window.mouse_position
     }
 
+    /// The id of the element that owns the top-most hovered hitbox, if any.
+    ///
+    /// This lets a parent element drive the hover state of its children without
+    /// registering a mouse move listener on each of them.
+    pub fn hover_element_id(&self) -> Option<ElementId> {
+        // While painting, the hit test reflects the frame that is being drawn.
+        let frame = if self.window.draw_phase == DrawPhase::Paint {
+            &self.window.next_frame
+        } else {
+            &self.window.rendered_frame
+        };
+        self.window.mouse_hit_test.0.iter().find_map(|hitbox_id| {
+            frame
+                .hitboxes
+                .iter()
+                .find(|hitbox| hitbox.id == *hitbox_id)?
+                .element_id
+                .clone()
+        })
+    }
+
     /// The current state of the keyboard's modifiers
     pub fn modifiers(&self) -> Modifiers {
         self.window.modifiers
@@ -1514,6 +1715,14 @@ impl<'a> WindowContext<'a> {
         sorted_deferred_draws.sort_by_key(|ix| self.window.next_frame.deferred_draws[*ix].priority);
         self.prepaint_deferred_draws(&sorted_deferred_draws);
 
+        let mut notifications_element = (!self.window.notifications.is_empty()).then(|| {
+            let mut element = render_notifications(self);
+            element.prepaint_as_root(Point::default(), self.window.viewport_size.into(), self);
+            element
+        });
+
+        let popup_element = self.prepaint_popup();
+
         let mut prompt_element = None;
         let mut active_drag_element = None;
         let mut tooltip_element = None;
@@ -1540,6 +1749,14 @@ impl<'a> WindowContext<'a> {
 
         self.paint_deferred_draws(&sorted_deferred_draws);
 
+        if let Some(notifications_element) = notifications_element.as_mut() {
+            notifications_element.paint(self);
+        }
+
+        if let Some(mut popup_element) = popup_element {
+            popup_element.paint(self);
+        }
+
         if let Some(mut prompt_element) = prompt_element {
             prompt_element.paint(self);
         } else if let Some(mut drag_element) = active_drag_element {
@@ -1549,6 +1766,24 @@ impl<'a> WindowContext<'a> {
         }
     }
 
+    fn prepaint_popup(&mut self) -> Option<AnyElement> {
+        let popup = self.window.popup.as_ref()?;
+        let position = popup.position;
+        let mut element = div()
+            .on_mouse_down_out(|_, cx| cx.dismiss_popup())
+            .on_key_down(|event, cx| {
+                if event.keystroke.key == "escape" {
+                    cx.dismiss_popup();
+                }
+            })
+            .child(popup.view.clone())
+            .into_any_element();
+        let popup_size = element.layout_as_root(AvailableSpace::min_size(), self);
+        let popup_bounds = popup_bounds(position, popup_size, self.viewport_size());
+        self.with_absolute_element_offset(popup_bounds.origin, |cx| element.prepaint(cx));
+        Some(element)
+    }
+
     fn prepaint_tooltip(&mut self) -> Option<AnyElement> {
         let tooltip_request = self.window.next_frame.tooltip_requests.last().cloned()?;
         let tooltip_request = tooltip_request.unwrap();
@@ -1864,6 +2099,48 @@ impl<'a> WindowContext<'a> {
         }
     }
 
+    /// Invoke the given function with painting clipped to the given bounds, in addition to
+    /// any clipping that is already active. Unlike `overflow_hidden`, the bounds don't need
+    /// to match the layout of any element. This method should only be called during element drawing.
+    pub fn with_clip_bounds<R>(
+        &mut self,
+        clip: Bounds<Pixels>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        self.with_content_mask(Some(ContentMask { bounds: clip }), f)
+    }
+
+    /// Invoke the given function with the given transformation applied to everything it paints,
+    /// on top of any transformation that is already active. Scaling and rotation happen around
+    /// `center`. This only affects painting, so hitboxes and mouse events are not transformed.
+    ///
+    /// Text, SVG icons and paths are rotated with the transformation. The renderer draws quads,
+    /// shadows, underlines, images and surfaces as axis-aligned rectangles, so those are moved
+    /// and scaled with the transformation but not rotated.
+    ///
+    /// This method should only be called as part of the paint phase of element drawing.
+    pub fn with_transformation<R>(
+        &mut self,
+        transformation: Transformation,
+        center: Point<Pixels>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::Paint,
+            "this method can only be called during paint"
+        );
+
+        let transformation = transformation.into_matrix(center, self.scale_factor());
+        self.window
+            .next_frame
+            .scene
+            .push_transformation(transformation);
+        let result = f(self);
+        self.window.next_frame.scene.pop_transformation();
+        result
+    }
+
     /// Updates the global element offset relative to the current offset. This is used to implement
     /// scrolling. This method should only be called during the prepaint phase of element drawing.
     pub fn with_element_offset<R>(
@@ -1909,10 +2186,17 @@ impl<'a> WindowContext<'a> {
         opacity: Option<f32>,
         f: impl FnOnce(&mut Self) -> R,
     ) -> R {
-        if opacity.is_none() {
-            return f(self);
+        match opacity {
+            Some(opacity) => self.with_opacity(opacity, f),
+            None => f(self),
         }
+    }
 
+    /// Invoke the given function with everything it paints drawn at the given opacity,
+    /// multiplied with any opacity that is already active. Animating the opacity, for example
+    /// with [`AnimationExt::with_animation`](crate::AnimationExt::with_animation), fades the
+    /// whole subtree in or out. This method should only be called during prepaint or paint.
+    pub fn with_opacity<R>(&mut self, opacity: f32, f: impl FnOnce(&mut Self) -> R) -> R {
         debug_assert!(
             matches!(
                 self.window.draw_phase,
@@ -1920,9 +2204,11 @@ impl<'a> WindowContext<'a> {
             ),
             "this method can only be called during prepaint, or paint"
         );
-        self.window_mut().element_opacity = opacity;
+        let previous_opacity = self.window.element_opacity;
+        self.window_mut().element_opacity =
+            Some(previous_opacity.unwrap_or(1.0) * opacity.clamp(0., 1.));
         let result = f(self);
-        self.window_mut().element_opacity = None;
+        self.window_mut().element_opacity = previous_opacity;
         result
     }
 
@@ -2362,28 +2648,54 @@ impl<'a> WindowContext<'a> {
             "this method can only be called during paint"
         );
 
-        let scale_factor = self.scale_factor();
-        let height = if style.wavy {
-            style.thickness * 3.
-        } else {
-            style.thickness
-        };
-        let bounds = Bounds {
-            origin,
-            size: size(width, height),
-        };
-        let content_mask = self.content_mask();
-        let element_opacity = self.element_opacity();
+        let color = style.color.unwrap_or_default();
+        match style.pattern {
+            UnderlinePattern::Dashed {
+                dash_length,
+                gap_length,
+            } if dash_length > px(0.) => {
+                let end = origin.x + width;
+                let mut dash_start = origin.x;
+                while dash_start < end {
+                    let dash_end = (dash_start + dash_length).min(end);
+                    self.paint_quad(fill(
+                        Bounds::from_corners(
+                            point(dash_start, origin.y),
+                            point(dash_end, origin.y + style.thickness),
+                        ),
+                        color,
+                    ));
+                    dash_start = dash_end + gap_length.max(px(0.));
+                }
+            }
+            pattern => {
+                // Wavy underlines are drawn by the underline shader, which fits the wave to
+                // the height of the underline's bounds.
+                let (height, wavy) = match pattern {
+                    UnderlinePattern::Wavy { amplitude } if amplitude > px(0.) => {
+                        (style.thickness + amplitude * 2., true)
+                    }
+                    _ => (style.thickness, false),
+                };
+                let scale_factor = self.scale_factor();
+                let bounds = Bounds {
+                    origin,
+                    size: size(width, height),
+                };
+                let content_mask = self.content_mask();
+                let element_opacity = self.element_opacity();
 
-        self.window.next_frame.scene.insert_primitive(Underline {
-            order: 0,
-            pad: 0,
-            bounds: bounds.scale(scale_factor),
-            content_mask: content_mask.scale(scale_factor),
-            color: style.color.unwrap_or_default().opacity(element_opacity),
-            thickness: style.thickness.scale(scale_factor),
-            wavy: style.wavy,
-        });
+                self.window.next_frame.scene.insert_primitive(Underline {
+                    order: 0,
+                    pad: 0,
+                    bounds: bounds.scale(scale_factor),
+                    content_mask: content_mask.scale(scale_factor),
+                    color: color.opacity(element_opacity),
+                    thickness: style.thickness.scale(scale_factor),
+                    wavy,
+                });
+            }
+        }
     }
 
     /// Paint a strikethrough into the scene for the next frame at the current z-index.
@@ -2788,6 +3100,44 @@ impl<'a> WindowContext<'a> {
         self.window.layout_engine = Some(layout_engine);
     }
 
+    /// Measure the size the given element would occupy within the available space, without painting it.
+    /// Useful for layout decisions that depend on an element's size before it is drawn, such as whether
+    /// a popover fits above or below its anchor.
+    ///
+    /// The element is laid out in a scratch layout tree and prepainted against empty element state, and
+    /// everything it records along the way is discarded, so measuring has no effect on the frame being drawn.
+    pub fn measure_element(
+        &mut self,
+        element: impl IntoElement,
+        available_space: Size<AvailableSpace>,
+    ) -> Size<Pixels> {
+        let prev_draw_phase = mem::replace(&mut self.window.draw_phase, DrawPhase::Prepaint);
+        let prev_layout_engine = self.window.layout_engine.replace(TaffyLayoutEngine::new());
+        let prev_requested_autoscroll = self.window.requested_autoscroll.take();
+        let prev_next_element_states = mem::take(&mut self.window.next_frame.element_states);
+        let prev_rendered_element_states =
+            mem::take(&mut self.window.rendered_frame.element_states);
+
+        let mut element = element.into_any_element();
+        let size = self
+            .transact(|cx| {
+                let size = element.layout_as_root(available_space, cx);
+                element.prepaint_at(Point::default(), cx);
+                // Returning an error discards the side effects of the prepaint.
+                Err::<(), _>(size)
+            })
+            .unwrap_err();
+        drop(element);
+
+        self.window.rendered_frame.element_states = prev_rendered_element_states;
+        self.window.next_frame.element_states = prev_next_element_states;
+        self.window.requested_autoscroll = prev_requested_autoscroll;
+        self.window.layout_engine = prev_layout_engine;
+        self.window.draw_phase = prev_draw_phase;
+
+        size
+    }
+
     /// Obtain the bounds computed for the given LayoutId relative to the window. This method will usually be invoked by
     /// GPUI itself automatically in order to pass your element its `Bounds` automatically.
     ///
@@ -2831,6 +3181,7 @@ impl<'a> WindowContext<'a> {
             bounds,
             content_mask,
             opaque,
+            element_id: window.element_id_stack.last().cloned(),
         };
         window.next_frame.hitboxes.push(hitbox.clone());
         hitbox
@@ -2914,6 +3265,26 @@ impl<'a> WindowContext<'a> {
         }
     }
 
+    /// Reports the bounds of the caret of the focused text input for the upcoming frame, in window
+    /// coordinates. The platform positions input method (IME) candidate windows next to these
+    /// bounds when the input handler can't provide bounds for the composed text itself.
+    ///
+    /// This method should only be called as part of the paint phase of element drawing.
+    pub fn set_text_input_rect(&mut self, rect: Bounds<Pixels>) {
+        debug_assert_eq!(
+            self.window.draw_phase,
+            DrawPhase::Paint,
+            "this method can only be called during paint"
+        );
+
+        self.window.next_frame.text_input_rect = Some(rect);
+    }
+
+    /// The caret bounds reported with [`Self::set_text_input_rect`] for the current frame, if any.
+    pub fn text_input_rect(&self) -> Option<Bounds<Pixels>> {
+        self.window.rendered_frame.text_input_rect
+    }
+
     /// Register a mouse event listener on the window for the next frame. The type of event
     /// is determined by the first parameter of the given listener. When the next frame is rendered
     /// the listener will be cleared.
@@ -3189,6 +3560,13 @@ impl<'a> WindowContext<'a> {
         }
     }
 
+    /// Injects an input event into the window as if it had come from the platform, such as a
+    /// [`FileDropEvent`] or [`KeyDownEvent`]. This is mostly useful for simulating platform
+    /// interaction in tests.
+    pub fn emit_system_event<E: InputEvent>(&mut self, event: E) -> DispatchEventResult {
+        self.dispatch_event(event.to_platform_input())
+    }
+
     fn dispatch_mouse_event(&mut self, event: &dyn Any) {
         let hit_test = self.window.rendered_frame.hit_test(self.mouse_position());
         if hit_test != self.window.mouse_hit_test {
@@ -3627,6 +4005,18 @@ impl<'a> WindowContext<'a> {
         self.window.platform_window.toggle_fullscreen();
     }
 
+    /// Returns the text being composed with an input method (IME) in the focused input, such
+    /// as the reading of a word that's being typed in Japanese, if a composition is in progress.
+    ///
+    /// The composed text is provided by the input handler registered with
+    /// [`Self::handle_input`], which usually shows it inline until it's committed.
+    pub fn ime_pre_edit_text(&mut self) -> Option<SharedString> {
+        let mut input_handler = self.window.platform_window.take_input_handler()?;
+        let text = input_handler.marked_text(self);
+        self.window.platform_window.set_input_handler(input_handler);
+        text.map(SharedString::from)
+    }
+
     /// Updates the IME panel position suggestions for languages like japanese, chinese.
     pub fn invalidate_character_coordinates(&self) {
         self.on_next_frame(|cx| {
@@ -3674,6 +4064,93 @@ impl<'a> WindowContext<'a> {
         receiver
     }
 
+    /// Shows a transient notification in the bottom-right corner of the window.
+    /// It is dismissed when the user closes it, or once its duration elapses.
+    pub fn push_notification(&mut self, notification: Notification) -> NotificationId {
+        let id = NotificationId(post_inc(&mut self.window.next_notification_id));
+        if let Some(duration) = notification.duration {
+            self.spawn(|mut cx| async move {
+                cx.background_executor().timer(duration).await;
+                cx.update(|cx| cx.dismiss_notification(id)).ok();
+            })
+            .detach();
+        }
+
+        self.window
+            .notifications
+            .push(ActiveNotification { id, notification });
+        self.refresh();
+        id
+    }
+
+    /// Removes a notification shown with [`Self::push_notification`], if it is still shown.
+    pub fn dismiss_notification(&mut self, id: NotificationId) {
+        let notification_count = self.window.notifications.len();
+        self.window.notifications.retain(|active| active.id != id);
+        if self.window.notifications.len() != notification_count {
+            self.refresh();
+        }
+    }
+
+    /// Shows the given view above the window's contents at the given position, such as a
+    /// context menu at the point that was right-clicked, replacing any popup already shown.
+    ///
+    /// The popup is focused and kept within the window's bounds. It is dismissed when the view
+    /// emits a [`DismissEvent`], or when the user clicks outside of it or presses `escape`,
+    /// at which point focus is restored to where it was before the popup was opened.
+    pub fn open_popup_at<V: ManagedView>(&mut self, position: Point<Pixels>, view: View<V>) {
+        let previous_focus = match self.window.popup.take() {
+            Some(popup) => popup.previous_focus,
+            None => self.focused(),
+        };
+        let _dismiss_subscription = self.subscribe(&view, |view, _: &DismissEvent, cx| {
+            let is_open = cx
+                .window
+                .popup
+                .as_ref()
+                .map_or(false, |popup| popup.view.entity_id() == view.entity_id());
+            if is_open {
+                cx.dismiss_popup();
+            }
+        });
+        let focus_handle = view.read(self).focus_handle(self);
+        self.focus(&focus_handle);
+
+        self.window.popup = Some(WindowPopup {
+            view: view.into(),
+            position,
+            focus_handle,
+            previous_focus,
+            _dismiss_subscription,
+        });
+        self.refresh();
+    }
+
+    /// Dismisses the popup shown with [`Self::open_popup_at`], if one is shown.
+    pub fn dismiss_popup(&mut self) {
+        let Some(popup) = self.window.popup.take() else {
+            return;
+        };
+        if popup.focus_handle.contains_focused(self) {
+            if let Some(previous_focus) = popup.previous_focus {
+                self.focus(&previous_focus);
+            }
+        }
+        self.refresh();
+    }
+
+    /// Returns whether a popup shown with [`Self::open_popup_at`] is currently shown.
+    pub fn has_popup(&self) -> bool {
+        self.window.popup.is_some()
+    }
+
+    /// Opens the platform's native "Save As" dialog, resolving to the chosen path,
+    /// or `None` if the dialog was cancelled or couldn't be opened.
+    pub fn open_save_dialog(&mut self, options: SaveDialogOptions) -> Task<Option<PathBuf>> {
+        let receiver = self.app.prompt_for_save_path(options);
+        self.spawn(|_| async move { receiver.await.ok()?.log_err()? })
+    }
+
     fn build_custom_prompt(
         &mut self,
         prompt_builder: &PromptBuilder,
@@ -0,0 +1,230 @@
+use std::{rc::Rc, time::Duration};
+
+use crate::{
+    black, blue, div, green, opaque_grey, red, white, yellow, AnyElement, ElementId, FontWeight,
+    Hsla, InteractiveElement, IntoElement, ParentElement, SharedString, StatefulInteractiveElement,
+    Styled, WindowContext,
+};
+
+/// How long a [`Notification`] is shown for by default.
+const DEFAULT_NOTIFICATION_DURATION: Duration = Duration::from_secs(5);
+
+/// The severity of a [`Notification`], which determines its accent color.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NotificationLevel {
+    /// A notification about something that happened, such as a file being saved.
+    #[default]
+    Info,
+    /// A notification that an operation completed successfully.
+    Success,
+    /// A notification about a potential problem.
+    Warning,
+    /// A notification that an operation failed.
+    Error,
+}
+
+impl NotificationLevel {
+    fn color(self) -> Hsla {
+        match self {
+            Self::Info => blue(),
+            Self::Success => green(),
+            Self::Warning => yellow(),
+            Self::Error => red(),
+        }
+    }
+}
+
+/// A transient notification, shown in the bottom-right corner of a window
+/// with [`WindowContext::push_notification`](crate::WindowContext::push_notification).
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// The text shown in bold at the top of the notification.
+    pub title: SharedString,
+    /// Additional text shown below the title.
+    pub body: Option<SharedString>,
+    /// The severity of the notification.
+    pub level: NotificationLevel,
+    /// How long the notification is shown for before being dismissed.
+    /// If `None`, it is shown until the user dismisses it.
+    pub duration: Option<Duration>,
+}
+
+impl Notification {
+    /// Creates an info notification with the given title, dismissed after five seconds.
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            body: None,
+            level: NotificationLevel::Info,
+            duration: Some(DEFAULT_NOTIFICATION_DURATION),
+        }
+    }
+
+    /// Sets the text shown below the title.
+    pub fn body(mut self, body: impl Into<SharedString>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the severity of the notification.
+    pub fn level(mut self, level: NotificationLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets how long the notification is shown for, or `None` to show it until it is dismissed.
+    pub fn duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// Identifies a notification shown in a window, so that it can be dismissed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotificationId(pub(crate) usize);
+
+pub(crate) struct ActiveNotification {
+    pub(crate) id: NotificationId,
+    pub(crate) notification: Notification,
+}
+
+pub(crate) type NotificationRenderer =
+    Rc<dyn Fn(&Notification, NotificationId, &mut WindowContext) -> AnyElement>;
+
+impl From<NotificationId> for ElementId {
+    fn from(id: NotificationId) -> Self {
+        ElementId::NamedInteger("notification".into(), id.0)
+    }
+}
+
+/// Renders the window's notifications as a stack of cards in its bottom-right corner,
+/// newest at the bottom, using the renderer set with
+/// [`AppContext::set_notification_renderer`](crate::AppContext::set_notification_renderer).
+pub(crate) fn render_notifications(cx: &mut WindowContext) -> AnyElement {
+    let renderer = cx.app.notification_renderer.clone();
+    let notifications = cx
+        .window
+        .notifications
+        .iter()
+        .map(|active| (active.id, active.notification.clone()))
+        .collect::<Vec<_>>();
+
+    div()
+        .size_full()
+        .flex()
+        .flex_col()
+        .justify_end()
+        .items_end()
+        .gap_2()
+        .p_4()
+        .children(
+            notifications
+                .into_iter()
+                .map(|(id, notification)| match renderer.as_ref() {
+                    Some(renderer) => renderer(&notification, id, cx),
+                    None => fallback_notification_renderer(&notification, id),
+                }),
+        )
+        .into_any_element()
+}
+
+/// The default GPUI rendering of a notification, used until a renderer is set with
+/// [`AppContext::set_notification_renderer`](crate::AppContext::set_notification_renderer).
+fn fallback_notification_renderer(notification: &Notification, id: NotificationId) -> AnyElement {
+    div()
+        .id(id)
+        .occlude()
+        .w_80()
+        .flex()
+        .flex_row()
+        .gap_2()
+        .p_3()
+        .bg(white())
+        .text_color(black())
+        .rounded_lg()
+        .border_1()
+        .border_color(opaque_grey(0.2, 0.5))
+        .shadow_md()
+        .child(
+            div()
+                .w_1()
+                .flex_none()
+                .rounded_sm()
+                .bg(notification.level.color()),
+        )
+        .child(
+            div()
+                .flex_1()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .overflow_hidden()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .child(notification.title.clone()),
+                )
+                .children(
+                    notification
+                        .body
+                        .clone()
+                        .map(|body| div().text_xs().text_color(opaque_grey(0.3, 1.)).child(body)),
+                ),
+        )
+        .child(
+            div()
+                .id("dismiss-notification")
+                .flex_none()
+                .cursor_pointer()
+                .text_sm()
+                .text_color(opaque_grey(0.4, 1.))
+                .child("×")
+                .on_click(move |_, cx| cx.dismiss_notification(id)),
+        )
+        .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{self as gpui, TestAppContext};
+
+    use super::*;
+
+    #[gpui::test]
+    fn test_notifications(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+
+        let transient = cx.update(|cx| {
+            cx.push_notification(
+                Notification::new("File saved").duration(Some(Duration::from_secs(1))),
+            )
+        });
+        let sticky = cx.update(|cx| {
+            cx.push_notification(
+                Notification::new("Kernel failed to start")
+                    .level(NotificationLevel::Error)
+                    .duration(None),
+            )
+        });
+        assert_ne!(transient, sticky);
+        cx.update(|cx| assert_eq!(cx.window.notifications.len(), 2));
+
+        cx.executor().advance_clock(Duration::from_secs(2));
+        cx.run_until_parked();
+        cx.update(|cx| {
+            let ids = cx
+                .window
+                .notifications
+                .iter()
+                .map(|active| active.id)
+                .collect::<Vec<_>>();
+            assert_eq!(ids, vec![sticky]);
+
+            cx.dismiss_notification(sticky);
+            assert!(cx.window.notifications.is_empty());
+        });
+    }
+}
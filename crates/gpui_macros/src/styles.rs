@@ -326,6 +326,27 @@ pub fn cursor_style_methods(input: TokenStream) -> TokenStream {
             self.style().mouse_cursor = Some(gpui::CursorStyle::ResizeLeft);
             self
         }
+
+        /// Sets cursor style when hovering over an element to `zoom-in`.
+        /// [Docs](https://tailwindcss.com/docs/cursor)
+        #visibility fn cursor_zoom_in(mut self) -> Self {
+            self.style().mouse_cursor = Some(gpui::CursorStyle::ZoomIn);
+            self
+        }
+
+        /// Sets cursor style when hovering over an element to `zoom-out`.
+        /// [Docs](https://tailwindcss.com/docs/cursor)
+        #visibility fn cursor_zoom_out(mut self) -> Self {
+            self.style().mouse_cursor = Some(gpui::CursorStyle::ZoomOut);
+            self
+        }
+
+        /// Sets cursor style when hovering over an element to `all-scroll`.
+        /// [Docs](https://tailwindcss.com/docs/cursor)
+        #visibility fn cursor_all_scroll(mut self) -> Self {
+            self.style().mouse_cursor = Some(gpui::CursorStyle::AllScroll);
+            self
+        }
     };
 
     output.into()
@@ -415,6 +415,9 @@ impl ConfigurationView {
             strikethrough: None,
             white_space: WhiteSpace::Normal,
             truncate: None,
+            letter_spacing: None,
+            word_spacing: None,
+            shadows: Default::default(),
         };
         EditorElement::new(
             &self.api_key_editor,
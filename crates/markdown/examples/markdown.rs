@@ -141,7 +141,7 @@ pub fn main() {
                         underline: Some(gpui::UnderlineStyle {
                             thickness: px(1.),
                             color: Some(Color::Accent.color(cx)),
-                            wavy: false,
+                            ..Default::default()
                         }),
                         ..Default::default()
                     },
@@ -14,6 +14,12 @@ use util::ResultExt;
 
 pub fn init(client: Arc<Client>, user_store: Model<UserStore>, cx: &mut AppContext) {
     let notification_store = cx.new_model(|cx| NotificationStore::new(client, user_store, cx));
+    // Keep the dock badge in sync with unread notifications, such as channel message mentions.
+    cx.subscribe(&notification_store, |notification_store, _, cx| {
+        let unread_count = notification_store.read(cx).unread_notification_count();
+        cx.set_badge_count(Some(unread_count));
+    })
+    .detach();
     cx.set_global(GlobalNotificationStore(notification_store));
 }
 
@@ -53,9 +53,9 @@ use std::{
 };
 use theme::ThemeSettings;
 use ui::{
-    prelude::*, v_flex, ContextMenu, DecoratedIcon, Icon, IconDecoration, IconDecorationKind,
-    IndentGuideColors, IndentGuideLayout, KeyBinding, Label, ListItem, Scrollbar, ScrollbarState,
-    Tooltip,
+    prelude::*, v_flex, ContextMenu, DecoratedIcon, EmptyState, Icon, IconDecoration,
+    IconDecorationKind, IndentGuideColors, IndentGuideLayout, Label, ListItem, Scrollbar,
+    ScrollbarState, Tooltip,
 };
 use util::{maybe, paths::compare_paths, ResultExt, TakeUntilExt, TryFutureExt};
 use workspace::{
@@ -3268,11 +3268,8 @@ impl ProjectPanel {
             item_colors.default
         };
 
-        let bg_hover_color = if self.mouse_down || is_marked || is_active {
-            item_colors.marked_active
-        } else {
-            item_colors.hover
-        };
+        // Hovering a row, selected or not, shows ListItem's hover background.
+        let bg_hover_color = item_colors.hover;
 
         let border_color =
             if !self.mouse_down && is_active && self.focus_handle.contains_focused(cx) {
@@ -3288,11 +3285,9 @@ impl ProjectPanel {
             .group(GROUP_NAME)
             .cursor_pointer()
             .rounded_none()
-            .bg(default_color)
             .border_1()
             .border_r_2()
             .border_color(border_color)
-            .hover(|style| style.bg(bg_hover_color))
             .when(is_local, |div| {
                 div.on_drag_move::<ExternalPaths>(cx.listener(
                     move |this, event: &DragMoveEvent<ExternalPaths>, cx| {
@@ -3432,7 +3427,7 @@ impl ProjectPanel {
                 ListItem::new(entry_id.to_proto() as usize)
                     .indent_level(depth)
                     .indent_step_size(px(settings.indent_size))
-                    .selectable(false)
+                    .toggle_state(is_marked || is_active)
                     .when_some(canonical_path, |this, path| {
                         this.end_slot::<AnyElement>(
                             div()
@@ -4177,17 +4172,11 @@ impl Render for ProjectPanel {
             v_flex()
                 .id("empty-project_panel")
                 .size_full()
-                .p_4()
                 .track_focus(&self.focus_handle(cx))
                 .child(
-                    Button::new("open_project", "Open a project")
-                        .full_width()
-                        .key_binding(KeyBinding::for_action(&workspace::Open, cx))
-                        .on_click(cx.listener(|this, _, cx| {
-                            this.workspace
-                                .update(cx, |_, cx| cx.dispatch_action(Box::new(workspace::Open)))
-                                .log_err();
-                        })),
+                    EmptyState::new(IconName::Folder, "No Project Open")
+                        .description("Open a folder to browse its files here.")
+                        .action("Open a project", Box::new(workspace::Open)),
                 )
                 .when(is_local, |div| {
                     div.drag_over::<ExternalPaths>(|style, _, cx| {
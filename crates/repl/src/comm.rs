@@ -0,0 +1,105 @@
+//! # Jupyter Comms
+//!
+//! Comms are channels between the kernel and the frontend, opened with `comm_open`,
+//! carrying `comm_msg` updates in either direction, and closed with `comm_close`.
+//! IPyWidgets use them to keep each widget's model in sync with the kernel.
+//!
+//! A widget is displayed by a `display_data` message whose
+//! `application/vnd.jupyter.widget-view+json` entry names the comm of its model.
+//!
+//! Only `IntProgress` and `FloatProgress` widgets are rendered at this time.
+//! Comms for other targets and widget models are tracked but not displayed.
+
+use collections::HashMap;
+use gpui::{View, WindowContext};
+use runtimelib::{JupyterMessageContent, MimeBundle, MimeType};
+use serde_json::{Map, Value};
+
+use crate::outputs::ProgressWidget;
+
+/// The comm target used by `ipywidgets` for widget models.
+const WIDGET_COMM_TARGET: &str = "jupyter.widget";
+
+/// Tracks the comms the kernel has opened, along with the widgets they back.
+#[derive(Default)]
+pub struct CommManager {
+    comms: HashMap<String, Comm>,
+}
+
+struct Comm {
+    #[allow(unused)]
+    target_name: String,
+    widget: Option<View<ProgressWidget>>,
+}
+
+impl CommManager {
+    /// Applies a `comm_open`, `comm_msg` or `comm_close` message, returning
+    /// whether the message was a comm message.
+    pub fn handle_message(
+        &mut self,
+        content: &JupyterMessageContent,
+        cx: &mut WindowContext,
+    ) -> bool {
+        match content {
+            JupyterMessageContent::CommOpen(open) => {
+                let widget = (open.target_name == WIDGET_COMM_TARGET)
+                    .then(|| widget_state(&open.data))
+                    .flatten()
+                    .filter(|state| ProgressWidget::supports_model(state))
+                    .map(|state| cx.new_view(|_| ProgressWidget::new(state)));
+
+                self.comms.insert(
+                    open.comm_id.0.clone(),
+                    Comm {
+                        target_name: open.target_name.clone(),
+                        widget,
+                    },
+                );
+                true
+            }
+            JupyterMessageContent::CommMsg(message) => {
+                let widget = self
+                    .comms
+                    .get(&message.comm_id.0)
+                    .and_then(|comm| comm.widget.as_ref());
+                let is_update =
+                    message.data.get("method").and_then(Value::as_str) == Some("update");
+
+                if let (Some(widget), Some(state), true) =
+                    (widget, widget_state(&message.data), is_update)
+                {
+                    widget.update(cx, |widget, cx| {
+                        widget.apply_state(state);
+                        cx.notify();
+                    });
+                }
+                true
+            }
+            JupyterMessageContent::CommClose(close) => {
+                self.comms.remove(&close.comm_id.0);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the widget displayed by the given `display_data` bundle, if it
+    /// displays a widget that can be rendered.
+    pub fn widget_for_display_data(&self, data: &MimeBundle) -> Option<View<ProgressWidget>> {
+        data.content.iter().find_map(|media| match media {
+            MimeType::WidgetView(view) => self
+                .comms
+                .get(&view.model_id)
+                .and_then(|comm| comm.widget.clone()),
+            _ => None,
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.comms.clear();
+    }
+}
+
+fn widget_state(data: &Map<String, Value>) -> Option<&Map<String, Value>> {
+    data.get("state").and_then(Value::as_object)
+}
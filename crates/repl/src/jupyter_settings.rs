@@ -9,6 +9,8 @@ use settings::{Settings, SettingsSources};
 #[derive(Debug, Default)]
 pub struct JupyterSettings {
     pub kernel_selections: HashMap<String, String>,
+    pub execution_timeout_seconds: Option<u64>,
+    pub stream_output_max_lines: usize,
 }
 
 impl JupyterSettings {
@@ -26,12 +28,23 @@ pub struct JupyterSettingsContent {
     ///
     /// Default: `{}`
     pub kernel_selections: Option<HashMap<String, String>>,
+    /// Interrupt executions that take longer than this many seconds.
+    ///
+    /// Default: `null` (never interrupt)
+    pub execution_timeout_seconds: Option<u64>,
+    /// The number of lines of printed output to show before it becomes scrollable,
+    /// following new output as it arrives. When 0, printed output grows to fit all of its lines.
+    ///
+    /// Default: `16`
+    pub stream_output_max_lines: Option<usize>,
 }
 
 impl Default for JupyterSettingsContent {
     fn default() -> Self {
         JupyterSettingsContent {
             kernel_selections: Some(HashMap::new()),
+            execution_timeout_seconds: None,
+            stream_output_max_lines: None,
         }
     }
 }
@@ -56,6 +69,12 @@ impl Settings for JupyterSettings {
                     settings.kernel_selections.insert(k.clone(), v.clone());
                 }
             }
+            if let Some(timeout) = value.execution_timeout_seconds {
+                settings.execution_timeout_seconds = Some(timeout);
+            }
+            if let Some(max_lines) = value.stream_output_max_lines {
+                settings.stream_output_max_lines = max_lines;
+            }
         }
 
         Ok(settings)
@@ -1,5 +1,9 @@
 mod native_kernel;
-use std::{fmt::Debug, future::Future, path::PathBuf};
+use std::{
+    fmt::Debug,
+    future::Future,
+    path::{Path, PathBuf},
+};
 
 use futures::{
     channel::mpsc::{self, Receiver},
@@ -148,6 +152,8 @@ pub trait RunningKernel: Send + Debug {
     fn set_execution_state(&mut self, state: ExecutionState);
     fn kernel_info(&self) -> Option<&KernelInfoReply>;
     fn set_kernel_info(&mut self, info: KernelInfoReply);
+    /// The connection file of a kernel running on this machine.
+    fn connection_file(&self) -> Option<&Path>;
     fn force_shutdown(&mut self, cx: &mut WindowContext) -> Task<anyhow::Result<()>>;
 }
 
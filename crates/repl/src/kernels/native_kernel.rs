@@ -5,7 +5,7 @@ use futures::{
     stream::{SelectAll, StreamExt},
     AsyncBufReadExt as _, SinkExt as _,
 };
-use gpui::{EntityId, Task, View, WindowContext};
+use gpui::{AsyncWindowContext, EntityId, Task, View, WindowContext};
 use jupyter_protocol::{
     connection_info::{ConnectionInfo, Transport},
     ExecutionState, JupyterKernelspec, JupyterMessage, JupyterMessageContent, KernelInfoReply,
@@ -17,7 +17,7 @@ use std::{
     env,
     fmt::Debug,
     net::{IpAddr, Ipv4Addr, SocketAddr},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::Arc,
 };
 use uuid::Uuid;
@@ -42,6 +42,56 @@ impl PartialEq for LocalKernelSpecification {
 impl Eq for LocalKernelSpecification {}
 
 impl LocalKernelSpecification {
+    /// Checks that the kernel can be started, so that a stale kernelspec, such as one
+    /// pointing into a deleted virtualenv, reports why instead of failing to connect.
+    pub fn validate(&self) -> Result<()> {
+        let program = self
+            .kernelspec
+            .argv
+            .first()
+            .with_context(|| format!("Empty argv in kernelspec {}", self.name))?;
+
+        let program_path = find_program(program)
+            .with_context(|| format!("Kernel binary `{program}` not found on PATH"))?;
+        let metadata = std::fs::metadata(&program_path).map_err(|_| {
+            anyhow::anyhow!("Kernel binary not found at {}", program_path.display())
+        })?;
+        anyhow::ensure!(
+            metadata.is_file(),
+            "Kernel binary at {} is not a file",
+            program_path.display()
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            anyhow::ensure!(
+                metadata.permissions().mode() & 0o111 != 0,
+                "Kernel binary at {} is not executable",
+                program_path.display()
+            );
+        }
+
+        // Kernelspecs found in Python environments are generated rather than read from disk.
+        if self.path.is_dir() {
+            let kernel_json_path = self.path.join("kernel.json");
+            let kernel_json = std::fs::read(&kernel_json_path)
+                .with_context(|| format!("Failed to read {}", kernel_json_path.display()))?;
+            serde_json::from_slice::<JupyterKernelspec>(&kernel_json)
+                .with_context(|| format!("Invalid kernelspec at {}", kernel_json_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the kernelspecs installed in a conda environment, under
+    /// `share/jupyter/kernels` in the environment's prefix.
+    pub async fn from_conda_env(env_path: &Path, fs: &dyn Fs) -> Result<Vec<Self>> {
+        let kernels_dir = env_path.join("share").join("jupyter").join("kernels");
+        read_kernels_dir(kernels_dir, fs)
+            .await
+            .with_context(|| format!("Failed to read kernels of conda environment {env_path:?}"))
+    }
+
     #[must_use]
     fn command(&self, connection_path: &PathBuf) -> Result<Command> {
         let argv = &self.kernelspec.argv;
@@ -72,6 +122,31 @@ impl LocalKernelSpecification {
     }
 }
 
+/// Resolves the program the way the kernel process will, looking up bare program
+/// names like `python3` on the `PATH`.
+fn find_program(program: &str) -> Option<PathBuf> {
+    let program = Path::new(program);
+    if program.components().count() > 1 {
+        return Some(program.to_path_buf());
+    }
+
+    let paths = env::var_os("PATH")?;
+    env::split_paths(&paths).find_map(|dir| {
+        let candidate = dir.join(program);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        #[cfg(windows)]
+        {
+            let candidate = candidate.with_extension("exe");
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+        None
+    })
+}
+
 // Find a set of open ports. This creates a listener with port set to 0. The listener will be closed at the end when it goes out of scope.
 // There's a race condition between closing the ports and usage by a kernel, but it's inherent to the Jupyter protocol.
 async fn peek_ports(ip: IpAddr) -> Result<[u16; 5]> {
@@ -87,11 +162,16 @@ async fn peek_ports(ip: IpAddr) -> Result<[u16; 5]> {
 }
 
 pub struct NativeRunningKernel {
-    pub process: smol::process::Child,
+    /// The kernel process, if it was launched by Zed. `None` for kernels that
+    /// were started elsewhere and reconnected to via their connection file.
+    pub process: Option<smol::process::Child>,
+    /// The process id of a reconnected kernel, when it is known, so that it can
+    /// still be killed when it doesn't respond to a shutdown request.
+    reconnected_pid: Option<u32>,
     _shell_task: Task<Result<()>>,
     _control_task: Task<Result<()>>,
     _routing_task: Task<Result<()>>,
-    connection_path: PathBuf,
+    connection_path: Option<PathBuf>,
     _process_status_task: Option<Task<()>>,
     pub working_directory: PathBuf,
     pub request_tx: mpsc::Sender<JupyterMessage>,
@@ -107,6 +187,123 @@ impl Debug for NativeRunningKernel {
     }
 }
 
+/// The sockets and routing tasks for talking to a kernel over ZMQ.
+struct KernelConnection {
+    request_tx: mpsc::Sender<JupyterMessage>,
+    shell_task: Task<Result<()>>,
+    control_task: Task<Result<()>>,
+    routing_task: Task<Result<()>>,
+}
+
+impl KernelConnection {
+    async fn connect(
+        connection_info: &ConnectionInfo,
+        session: View<Session>,
+        cx: &AsyncWindowContext,
+    ) -> Result<Self> {
+        let session_id = Uuid::new_v4().to_string();
+
+        let mut iopub_socket =
+            runtimelib::create_client_iopub_connection(connection_info, "", &session_id).await?;
+        let mut shell_socket =
+            runtimelib::create_client_shell_connection(connection_info, &session_id).await?;
+        let mut control_socket =
+            runtimelib::create_client_control_connection(connection_info, &session_id).await?;
+
+        let (request_tx, mut request_rx) = futures::channel::mpsc::channel::<JupyterMessage>(100);
+
+        let (mut control_reply_tx, control_reply_rx) = futures::channel::mpsc::channel(100);
+        let (mut shell_reply_tx, shell_reply_rx) = futures::channel::mpsc::channel(100);
+
+        let mut messages_rx = SelectAll::new();
+        messages_rx.push(control_reply_rx);
+        messages_rx.push(shell_reply_rx);
+
+        cx.spawn({
+            let session = session.clone();
+
+            |mut cx| async move {
+                while let Some(message) = messages_rx.next().await {
+                    session
+                        .update(&mut cx, |session, cx| {
+                            session.route(&message, cx);
+                        })
+                        .ok();
+                }
+                anyhow::Ok(())
+            }
+        })
+        .detach();
+
+        // iopub task
+        cx.spawn({
+            let session = session.clone();
+
+            |mut cx| async move {
+                while let Ok(message) = iopub_socket.read().await {
+                    session
+                        .update(&mut cx, |session, cx| {
+                            session.route(&message, cx);
+                        })
+                        .ok();
+                }
+                anyhow::Ok(())
+            }
+        })
+        .detach();
+
+        let (mut control_request_tx, mut control_request_rx) = futures::channel::mpsc::channel(100);
+        let (mut shell_request_tx, mut shell_request_rx) = futures::channel::mpsc::channel(100);
+
+        let routing_task = cx.background_executor().spawn({
+            async move {
+                while let Some(message) = request_rx.next().await {
+                    match message.content {
+                        JupyterMessageContent::DebugRequest(_)
+                        | JupyterMessageContent::InterruptRequest(_)
+                        | JupyterMessageContent::ShutdownRequest(_) => {
+                            control_request_tx.send(message).await?;
+                        }
+                        _ => {
+                            shell_request_tx.send(message).await?;
+                        }
+                    }
+                }
+                anyhow::Ok(())
+            }
+        });
+
+        let shell_task = cx.background_executor().spawn({
+            async move {
+                while let Some(message) = shell_request_rx.next().await {
+                    shell_socket.send(message).await.ok();
+                    let reply = shell_socket.read().await?;
+                    shell_reply_tx.send(reply).await?;
+                }
+                anyhow::Ok(())
+            }
+        });
+
+        let control_task = cx.background_executor().spawn({
+            async move {
+                while let Some(message) = control_request_rx.next().await {
+                    control_socket.send(message).await.ok();
+                    let reply = control_socket.read().await?;
+                    control_reply_tx.send(reply).await?;
+                }
+                anyhow::Ok(())
+            }
+        });
+
+        Ok(Self {
+            request_tx,
+            shell_task,
+            control_task,
+            routing_task,
+        })
+    }
+}
+
 impl NativeRunningKernel {
     pub fn new(
         kernel_specification: LocalKernelSpecification,
@@ -118,6 +315,8 @@ impl NativeRunningKernel {
         cx: &mut WindowContext,
     ) -> Task<Result<Box<dyn RunningKernel>>> {
         cx.spawn(|cx| async move {
+            kernel_specification.validate()?;
+
             let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
             let ports = peek_ports(ip).await?;
 
@@ -153,102 +352,8 @@ impl NativeRunningKernel {
                 .spawn()
                 .context("failed to start the kernel process")?;
 
-            let session_id = Uuid::new_v4().to_string();
-
-            let mut iopub_socket =
-                runtimelib::create_client_iopub_connection(&connection_info, "", &session_id)
-                    .await?;
-            let mut shell_socket =
-                runtimelib::create_client_shell_connection(&connection_info, &session_id).await?;
-            let mut control_socket =
-                runtimelib::create_client_control_connection(&connection_info, &session_id).await?;
-
-            let (request_tx, mut request_rx) =
-                futures::channel::mpsc::channel::<JupyterMessage>(100);
-
-            let (mut control_reply_tx, control_reply_rx) = futures::channel::mpsc::channel(100);
-            let (mut shell_reply_tx, shell_reply_rx) = futures::channel::mpsc::channel(100);
-
-            let mut messages_rx = SelectAll::new();
-            messages_rx.push(control_reply_rx);
-            messages_rx.push(shell_reply_rx);
-
-            cx.spawn({
-                let session = session.clone();
-
-                |mut cx| async move {
-                    while let Some(message) = messages_rx.next().await {
-                        session
-                            .update(&mut cx, |session, cx| {
-                                session.route(&message, cx);
-                            })
-                            .ok();
-                    }
-                    anyhow::Ok(())
-                }
-            })
-            .detach();
-
-            // iopub task
-            cx.spawn({
-                let session = session.clone();
-
-                |mut cx| async move {
-                    while let Ok(message) = iopub_socket.read().await {
-                        session
-                            .update(&mut cx, |session, cx| {
-                                session.route(&message, cx);
-                            })
-                            .ok();
-                    }
-                    anyhow::Ok(())
-                }
-            })
-            .detach();
-
-            let (mut control_request_tx, mut control_request_rx) =
-                futures::channel::mpsc::channel(100);
-            let (mut shell_request_tx, mut shell_request_rx) = futures::channel::mpsc::channel(100);
-
-            let routing_task = cx.background_executor().spawn({
-                async move {
-                    while let Some(message) = request_rx.next().await {
-                        match message.content {
-                            JupyterMessageContent::DebugRequest(_)
-                            | JupyterMessageContent::InterruptRequest(_)
-                            | JupyterMessageContent::ShutdownRequest(_) => {
-                                control_request_tx.send(message).await?;
-                            }
-                            _ => {
-                                shell_request_tx.send(message).await?;
-                            }
-                        }
-                    }
-                    anyhow::Ok(())
-                }
-            });
-
-            let shell_task = cx.background_executor().spawn({
-                async move {
-                    while let Some(message) = shell_request_rx.next().await {
-                        shell_socket.send(message).await.ok();
-                        let reply = shell_socket.read().await?;
-                        shell_reply_tx.send(reply).await?;
-                    }
-                    anyhow::Ok(())
-                }
-            });
-
-            let control_task = cx.background_executor().spawn({
-                async move {
-                    while let Some(message) = control_request_rx.next().await {
-                        control_socket.send(message).await.ok();
-                        let reply = control_socket.read().await?;
-                        control_reply_tx.send(reply).await?;
-                    }
-                    anyhow::Ok(())
-                }
-            });
+            let connection =
+                KernelConnection::connect(&connection_info, session.clone(), &cx).await?;
 
             let stderr = process.stderr.take();
 
@@ -307,14 +412,54 @@ impl NativeRunningKernel {
             });
 
             anyhow::Ok(Box::new(Self {
-                process,
-                request_tx,
+                process: Some(process),
+                reconnected_pid: None,
+                request_tx: connection.request_tx,
                 working_directory,
                 _process_status_task: Some(process_status_task),
-                _shell_task: shell_task,
-                _control_task: control_task,
-                _routing_task: routing_task,
-                connection_path,
+                _shell_task: connection.shell_task,
+                _control_task: connection.control_task,
+                _routing_task: connection.routing_task,
+                connection_path: Some(connection_path),
+                execution_state: ExecutionState::Idle,
+                kernel_info: None,
+            }) as Box<dyn RunningKernel>)
+        })
+    }
+
+    /// Connects to a kernel that is already running, such as one left behind
+    /// when Zed quit or crashed, using the connection file the kernel was
+    /// started with.
+    ///
+    /// The kernel process is not owned by Zed, so it is left running when the
+    /// connection is dropped. Shutting it down sends a shutdown request, and
+    /// kills it by its process id when that is known.
+    pub fn reconnect(
+        kernel: RunningKernelInfo,
+        working_directory: PathBuf,
+        session: View<Session>,
+        cx: &mut WindowContext,
+    ) -> Task<Result<Box<dyn RunningKernel>>> {
+        cx.spawn(|cx| async move {
+            let connection = KernelConnection::connect(&kernel.connection_info, session, &cx)
+                .await
+                .with_context(|| {
+                    format!(
+                        "failed to reconnect to the kernel at {:?}",
+                        kernel.connection_file
+                    )
+                })?;
+
+            anyhow::Ok(Box::new(Self {
+                process: None,
+                reconnected_pid: kernel.pid,
+                request_tx: connection.request_tx,
+                working_directory,
+                _process_status_task: None,
+                _shell_task: connection.shell_task,
+                _control_task: connection.control_task,
+                _routing_task: connection.routing_task,
+                connection_path: Some(kernel.connection_file),
                 execution_state: ExecutionState::Idle,
                 kernel_info: None,
             }) as Box<dyn RunningKernel>)
@@ -347,11 +492,33 @@ impl RunningKernel for NativeRunningKernel {
         self.kernel_info = Some(info);
     }
 
-    fn force_shutdown(&mut self, _cx: &mut WindowContext) -> Task<anyhow::Result<()>> {
+    fn connection_file(&self) -> Option<&Path> {
+        self.connection_path.as_deref()
+    }
+
+    fn force_shutdown(&mut self, cx: &mut WindowContext) -> Task<anyhow::Result<()>> {
+        let Some(process) = self.process.as_mut() else {
+            // A reconnected kernel isn't a child process of Zed, so it is killed by its
+            // process id, leaving the channel open for the shutdown request to be sent.
+            let Some(pid) = self.reconnected_pid else {
+                return Task::ready(Err(anyhow::anyhow!(
+                    "The kernel's process id is unknown, so it can only be shut down by request"
+                )));
+            };
+            let connection_path = self.connection_path.clone();
+            return cx.background_executor().spawn(async move {
+                kill_process(pid).await?;
+                if let Some(connection_path) = connection_path {
+                    smol::fs::remove_file(connection_path).await.ok();
+                }
+                Ok(())
+            });
+        };
+
         self._process_status_task.take();
         self.request_tx.close_channel();
 
-        Task::ready(match self.process.kill() {
+        Task::ready(match process.kill() {
             Ok(_) => Ok(()),
             Err(error) => Err(anyhow::anyhow!(
                 "Failed to kill the kernel process: {}",
@@ -363,12 +530,123 @@ impl RunningKernel for NativeRunningKernel {
 
 impl Drop for NativeRunningKernel {
     fn drop(&mut self) {
-        std::fs::remove_file(&self.connection_path).ok();
         self.request_tx.close_channel();
-        self.process.kill().ok();
+        // A reconnected kernel keeps running, along with its connection file.
+        if let Some(process) = self.process.as_mut() {
+            if let Some(connection_path) = &self.connection_path {
+                std::fs::remove_file(connection_path).ok();
+            }
+            process.kill().ok();
+        }
     }
 }
 
+async fn kill_process(pid: u32) -> Result<()> {
+    #[cfg(windows)]
+    let mut command = {
+        let mut command = util::command::new_smol_command("taskkill");
+        command.args(["/F", "/PID", &pid.to_string()]);
+        command
+    };
+    #[cfg(not(windows))]
+    let mut command = {
+        let mut command = util::command::new_smol_command("kill");
+        command.args(["-9", &pid.to_string()]);
+        command
+    };
+
+    let output = command
+        .output()
+        .await
+        .with_context(|| format!("Failed to kill the kernel process {pid}"))?;
+    anyhow::ensure!(
+        output.status.success(),
+        "Failed to kill the kernel process {pid}: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(())
+}
+
+/// A kernel with a connection file in the Jupyter runtime directory, which may
+/// be reconnected to with [`NativeRunningKernel::reconnect`].
+#[derive(Debug, Clone)]
+pub struct RunningKernelInfo {
+    /// The process id of the kernel, when it can be derived from the name of
+    /// the connection file (as in `kernel-1234.json`).
+    pub pid: Option<u32>,
+    pub connection_file: PathBuf,
+    pub connection_info: ConnectionInfo,
+}
+
+impl RunningKernelInfo {
+    pub async fn read(connection_file: PathBuf, fs: &dyn Fs) -> Result<Self> {
+        let content = fs.load(&connection_file).await?;
+        let connection_info = serde_json::from_str::<ConnectionInfo>(&content)
+            .with_context(|| format!("Invalid kernel connection file {connection_file:?}"))?;
+
+        let pid = connection_file
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.strip_prefix("kernel-"))
+            .and_then(|id| id.parse().ok());
+
+        Ok(Self {
+            pid,
+            connection_file,
+            connection_info,
+        })
+    }
+
+    /// A label for the kernel, naming its kernelspec and process id when known.
+    pub fn label(&self) -> String {
+        let kernel_name = self.kernel_name().unwrap_or("kernel");
+        match self.pid {
+            Some(pid) => format!("{kernel_name} ({pid})"),
+            None => kernel_name.to_string(),
+        }
+    }
+
+    /// The name of the kernelspec the kernel was started from, if recorded in
+    /// its connection file.
+    pub fn kernel_name(&self) -> Option<&str> {
+        let kernel_name = self.connection_info.kernel_name.as_deref()?;
+        Some(kernel_name.strip_prefix("zed-").unwrap_or(kernel_name))
+    }
+}
+
+/// Lists the kernels with connection files in the Jupyter runtime directory.
+pub async fn list_running_kernels(fs: Arc<dyn Fs>) -> Result<Vec<RunningKernelInfo>> {
+    read_running_kernels(dirs::runtime_dir(), fs.as_ref()).await
+}
+
+async fn read_running_kernels(runtime_dir: PathBuf, fs: &dyn Fs) -> Result<Vec<RunningKernelInfo>> {
+    let mut paths = fs.read_dir(&runtime_dir).await?;
+
+    let mut running_kernels = Vec::new();
+    while let Some(path) = paths.next().await {
+        match path {
+            Ok(path) => {
+                let is_connection_file = path.extension().map_or(false, |ext| ext == "json")
+                    && path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .map_or(false, |name| name.starts_with("kernel-"));
+                if !is_connection_file {
+                    continue;
+                }
+
+                match RunningKernelInfo::read(path, fs).await {
+                    Ok(kernel) => running_kernels.push(kernel),
+                    Err(err) => log::warn!("Error reading kernel connection file: {err:?}"),
+                }
+            }
+            Err(err) => log::warn!("Error reading jupyter runtime directory: {err:?}"),
+        }
+    }
+
+    Ok(running_kernels)
+}
+
 async fn read_kernelspec_at(
     // Path should be a directory to a jupyter kernelspec, as in
     // /usr/local/share/jupyter/kernels/python3
@@ -388,7 +666,13 @@ async fn read_kernelspec_at(
 
     let expected_kernel_json = path.join("kernel.json");
     let spec = fs.load(expected_kernel_json.as_path()).await?;
-    let spec = serde_json::from_str::<JupyterKernelspec>(&spec)?;
+    let mut spec = serde_json::from_str::<JupyterKernelspec>(&spec)?;
+
+    // Kernels may ship resources, such as a launcher script, next to their kernel.json
+    let resource_dir = path.to_string_lossy();
+    for arg in &mut spec.argv {
+        *arg = arg.replace("{resource_dir}", &resource_dir);
+    }
 
     Ok(LocalKernelSpecification {
         name: kernel_name,
@@ -421,13 +705,6 @@ async fn read_kernels_dir(path: PathBuf, fs: &dyn Fs) -> Result<Vec<LocalKernelS
 pub async fn local_kernel_specifications(fs: Arc<dyn Fs>) -> Result<Vec<LocalKernelSpecification>> {
     let mut data_dirs = dirs::data_dirs();
 
-    // Pick up any kernels from conda or conda environment
-    if let Ok(conda_prefix) = env::var("CONDA_PREFIX") {
-        let conda_prefix = PathBuf::from(conda_prefix);
-        let conda_data_dir = conda_prefix.join("share").join("jupyter");
-        data_dirs.push(conda_data_dir);
-    }
-
     // Search for kernels inside the base python environment
     let command = util::command::new_smol_command("python")
         .arg("-c")
@@ -453,12 +730,21 @@ pub async fn local_kernel_specifications(fs: Arc<dyn Fs>) -> Result<Vec<LocalKer
         .collect::<Vec<_>>();
 
     let kernel_dirs = futures::future::join_all(kernel_dirs).await;
-    let kernel_dirs = kernel_dirs
+    let mut kernel_dirs = kernel_dirs
         .into_iter()
         .filter_map(Result::ok)
         .flatten()
         .collect::<Vec<_>>();
 
+    // Pick up any kernels from the active conda environment
+    if let Ok(conda_prefix) = env::var("CONDA_PREFIX") {
+        match LocalKernelSpecification::from_conda_env(Path::new(&conda_prefix), fs.as_ref()).await
+        {
+            Ok(conda_kernels) => kernel_dirs.extend(conda_kernels),
+            Err(err) => log::warn!("{err:?}"),
+        }
+    }
+
     Ok(kernel_dirs)
 }
 
@@ -518,4 +804,177 @@ mod test {
             vec!["deno", "python"]
         );
     }
+
+    #[gpui::test]
+    async fn test_kernelspecs_from_conda_env(cx: &mut TestAppContext) {
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(
+            "/conda/envs/ml",
+            json!({
+                "share": {
+                    "jupyter": {
+                        "kernels": {
+                            "python3": {
+                                "kernel.json": r#"{
+                                    "display_name": "Python 3 (ml)",
+                                    "language": "python",
+                                    "argv": ["/conda/envs/ml/bin/python", "{resource_dir}/launch.py", "-f", "{connection_file}"],
+                                    "env": {}
+                                }"#,
+                                "launch.py": "",
+                            },
+                            "broken": {
+                                "kernel.json": "not json",
+                            },
+                        },
+                    },
+                },
+            }),
+        )
+        .await;
+
+        let kernels =
+            LocalKernelSpecification::from_conda_env(Path::new("/conda/envs/ml"), fs.as_ref())
+                .await
+                .unwrap();
+
+        assert_eq!(kernels.len(), 1);
+        assert_eq!(kernels[0].name, "python3");
+        assert_eq!(kernels[0].kernelspec.display_name, "Python 3 (ml)");
+        assert_eq!(
+            kernels[0].kernelspec.argv,
+            vec![
+                "/conda/envs/ml/bin/python",
+                "/conda/envs/ml/share/jupyter/kernels/python3/launch.py",
+                "-f",
+                "{connection_file}",
+            ]
+        );
+
+        assert!(LocalKernelSpecification::from_conda_env(
+            Path::new("/conda/envs/missing"),
+            fs.as_ref()
+        )
+        .await
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_kernelspec() {
+        let kernelspec = |program: &str| LocalKernelSpecification {
+            name: "python3".to_string(),
+            path: PathBuf::from(program),
+            kernelspec: JupyterKernelspec {
+                argv: vec![
+                    program.to_string(),
+                    "-m".to_string(),
+                    "ipykernel_launcher".to_string(),
+                    "-f".to_string(),
+                    "{connection_file}".to_string(),
+                ],
+                display_name: "Python 3".to_string(),
+                language: "python".to_string(),
+                interrupt_mode: None,
+                metadata: None,
+                env: None,
+            },
+        };
+
+        let missing = "/nonexistent/venv/bin/python3";
+        assert_eq!(
+            kernelspec(missing).validate().unwrap_err().to_string(),
+            format!("Kernel binary not found at {missing}")
+        );
+
+        let current_exe = env::current_exe().unwrap();
+        kernelspec(current_exe.to_str().unwrap())
+            .validate()
+            .unwrap();
+    }
+
+    #[gpui::test]
+    async fn test_read_running_kernels(cx: &mut TestAppContext) {
+        let connection_file = r#"{
+            "transport": "tcp",
+            "ip": "127.0.0.1",
+            "stdin_port": 50001,
+            "control_port": 50002,
+            "hb_port": 50003,
+            "shell_port": 50004,
+            "iopub_port": 50005,
+            "signature_scheme": "hmac-sha256",
+            "key": "a0436f6c-1916-498b-8eb9-e81ab9368e84",
+            "kernel_name": "python3"
+        }"#;
+
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(
+            "/runtime",
+            json!({
+                "kernel-1234.json": connection_file,
+                "kernel-zed-42.json": connection_file,
+                "nbserver-1234.json": "{}",
+                "kernel-invalid.json": "not json",
+            }),
+        )
+        .await;
+
+        let mut kernels = read_running_kernels(PathBuf::from("/runtime"), fs.as_ref())
+            .await
+            .unwrap();
+
+        kernels.sort_by(|a, b| a.connection_file.cmp(&b.connection_file));
+
+        assert_eq!(
+            kernels
+                .iter()
+                .map(|kernel| (kernel.connection_file.clone(), kernel.pid))
+                .collect::<Vec<_>>(),
+            vec![
+                (PathBuf::from("/runtime/kernel-1234.json"), Some(1234)),
+                (PathBuf::from("/runtime/kernel-zed-42.json"), None),
+            ]
+        );
+        assert_eq!(kernels[0].connection_info.shell_port, 50004);
+        assert_eq!(kernels[0].kernel_name(), Some("python3"));
+        assert_eq!(kernels[0].label(), "python3 (1234)");
+        assert_eq!(kernels[1].label(), "python3");
+    }
+
+    #[cfg(unix)]
+    #[gpui::test]
+    async fn test_force_shutdown_reconnected_kernel(cx: &mut TestAppContext) {
+        cx.executor().allow_parking();
+        let mut process = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let connection_path = env::temp_dir().join(format!("kernel-{}.json", process.id()));
+        std::fs::write(&connection_path, "{}").unwrap();
+
+        let (request_tx, _request_rx) = mpsc::channel(1);
+        let mut kernel = NativeRunningKernel {
+            process: None,
+            reconnected_pid: Some(process.id()),
+            _shell_task: Task::ready(Ok(())),
+            _control_task: Task::ready(Ok(())),
+            _routing_task: Task::ready(Ok(())),
+            connection_path: Some(connection_path.clone()),
+            _process_status_task: None,
+            working_directory: env::temp_dir(),
+            request_tx,
+            execution_state: ExecutionState::Idle,
+            kernel_info: None,
+        };
+
+        let cx = cx.add_empty_window();
+        cx.update(|cx| kernel.force_shutdown(cx)).await.unwrap();
+
+        assert!(!process.wait().unwrap().success());
+        assert!(!connection_path.exists());
+
+        // Without a process id, a reconnected kernel can't be killed.
+        kernel.reconnected_pid = None;
+        assert!(cx.update(|cx| kernel.force_shutdown(cx)).await.is_err());
+    }
 }
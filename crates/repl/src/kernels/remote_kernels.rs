@@ -19,7 +19,7 @@ use jupyter_websocket_client::{
     JupyterWebSocket, JupyterWebSocketReader, JupyterWebSocketWriter, KernelLaunchRequest,
     KernelSpecsResponse, RemoteServer,
 };
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, path::Path, sync::Arc};
 
 #[derive(Debug, Clone)]
 pub struct RemoteKernelSpecification {
@@ -273,6 +273,10 @@ impl RunningKernel for RemoteRunningKernel {
         self.kernel_info = Some(info);
     }
 
+    fn connection_file(&self) -> Option<&Path> {
+        None
+    }
+
     fn force_shutdown(&mut self, cx: &mut WindowContext) -> Task<anyhow::Result<()>> {
         let url = self
             .remote_server
@@ -1,4 +1,6 @@
 mod cell;
+mod cell_picker;
 mod notebook_ui;
 pub use cell::*;
+pub use cell_picker::*;
 pub use notebook_ui::*;
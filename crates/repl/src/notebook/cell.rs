@@ -1,9 +1,9 @@
 #![allow(unused, dead_code)]
 use std::sync::Arc;
 
-use editor::{Editor, EditorMode, MultiBuffer};
+use editor::{actions::SelectAll, Editor, EditorEvent, EditorMode, MultiBuffer};
 use futures::future::Shared;
-use gpui::{prelude::*, AppContext, Hsla, Task, TextStyleRefinement, View};
+use gpui::{prelude::*, AppContext, Hsla, Subscription, Task, TextStyleRefinement, View};
 use language::{Buffer, Language, LanguageRegistry};
 use markdown_preview::{markdown_parser::parse_markdown, markdown_renderer::render_markdown_block};
 use nbformat::v4::{CellId, CellMetadata, CellType};
@@ -80,6 +80,12 @@ pub enum Cell {
     Raw(View<RawCell>),
 }
 
+/// The editor for a cell's name, shown in the cell's header while the cell is being renamed.
+pub struct CellNameEditor {
+    editor: View<Editor>,
+    _subscription: Subscription,
+}
+
 fn convert_outputs(outputs: &Vec<nbformat::v4::Output>, cx: &mut WindowContext) -> Vec<Output> {
     outputs
         .into_iter()
@@ -93,16 +99,52 @@ fn convert_outputs(outputs: &Vec<nbformat::v4::Output>, cx: &mut WindowContext)
             nbformat::v4::Output::ExecuteResult(execute_result) => {
                 Output::new(&execute_result.data, None, cx)
             }
-            nbformat::v4::Output::Error(error) => Output::ErrorOutput(ErrorView {
-                ename: error.ename.clone(),
-                evalue: error.evalue.clone(),
-                traceback: cx.new_view(|cx| TerminalOutput::from(&error.traceback.join("\n"), cx)),
-            }),
+            nbformat::v4::Output::Error(error) => Output::ErrorOutput(ErrorView::new(
+                error.ename.clone(),
+                error.evalue.clone(),
+                cx.new_view(|cx| TerminalOutput::from(&error.traceback.join("\n"), cx)),
+            )),
         })
         .collect()
 }
 
 impl Cell {
+    /// The user-visible name of the cell, if it has been given one.
+    pub fn name(&self, cx: &AppContext) -> Option<SharedString> {
+        match self {
+            Cell::Code(cell) => cell.read(cx).name(),
+            Cell::Markdown(cell) => cell.read(cx).name(),
+            Cell::Raw(cell) => cell.read(cx).name(),
+        }
+    }
+
+    /// Names the cell, or removes its name if `name` is `None` or blank.
+    pub fn rename(&self, name: Option<SharedString>, cx: &mut WindowContext) {
+        match self {
+            Cell::Code(cell) => cell.update(cx, |cell, cx| {
+                cell.rename(name);
+                cx.notify();
+            }),
+            Cell::Markdown(cell) => cell.update(cx, |cell, cx| {
+                cell.rename(name);
+                cx.notify();
+            }),
+            Cell::Raw(cell) => cell.update(cx, |cell, cx| {
+                cell.rename(name);
+                cx.notify();
+            }),
+        }
+    }
+
+    /// Shows an editor for the cell's name in its header, and focuses it.
+    pub fn start_rename(&self, cx: &mut WindowContext) {
+        match self {
+            Cell::Code(cell) => cell.update(cx, |cell, cx| cell.start_rename(cx)),
+            Cell::Markdown(cell) => cell.update(cx, |cell, cx| cell.start_rename(cx)),
+            Cell::Raw(cell) => cell.update(cx, |cell, cx| cell.start_rename(cx)),
+        }
+    }
+
     pub fn load(
         cell: &nbformat::v4::Cell,
         languages: &Arc<LanguageRegistry>,
@@ -142,6 +184,7 @@ impl Cell {
                         markdown_parsing_task,
                         languages: languages.clone(),
                         id: id.clone(),
+                        name_editor: None,
                         metadata: metadata.clone(),
                         source: source.clone(),
                         parsed_markdown: None,
@@ -202,6 +245,7 @@ impl Cell {
 
                 CodeCell {
                     id: id.clone(),
+                    name_editor: None,
                     metadata: metadata.clone(),
                     execution_count: *execution_count,
                     source: source.join(""),
@@ -218,6 +262,7 @@ impl Cell {
                 source,
             } => Cell::Raw(cx.new_view(|_| RawCell {
                 id: id.clone(),
+                name_editor: None,
                 metadata: metadata.clone(),
                 source: source.join(""),
                 selected: false,
@@ -233,6 +278,9 @@ pub trait RenderableCell: Render {
     fn id(&self) -> &CellId;
     fn cell_type(&self) -> CellType;
     fn metadata(&self) -> &CellMetadata;
+    fn metadata_mut(&mut self) -> &mut CellMetadata;
+    fn name_editor(&self) -> Option<&CellNameEditor>;
+    fn name_editor_mut(&mut self) -> &mut Option<CellNameEditor>;
     fn source(&self) -> &String;
     fn selected(&self) -> bool;
     fn set_selected(&mut self, selected: bool) -> &mut Self;
@@ -250,6 +298,91 @@ pub trait RenderableCell: Render {
         None
     }
 
+    /// The user-visible name of the cell, stored in its metadata so it's saved with the notebook.
+    fn name(&self) -> Option<SharedString> {
+        self.metadata().name.clone().map(SharedString::from)
+    }
+
+    fn rename(&mut self, name: Option<SharedString>) -> &mut Self {
+        let name = name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+        self.metadata_mut().name = name;
+        self
+    }
+
+    fn start_rename(&mut self, cx: &mut ViewContext<Self>) {
+        let name = self.name().unwrap_or_default();
+        let editor = cx.new_view(|cx| {
+            let mut editor = Editor::single_line(cx);
+            editor.set_placeholder_text("Name this cell", cx);
+            editor.set_text(name.to_string(), cx);
+            editor.select_all(&SelectAll, cx);
+            editor
+        });
+        let subscription = cx.subscribe(&editor, |this: &mut Self, _, event: &EditorEvent, cx| {
+            if let EditorEvent::Blurred = event {
+                this.finish_rename(true, cx);
+            }
+        });
+        editor.focus_handle(cx).focus(cx);
+
+        *self.name_editor_mut() = Some(CellNameEditor {
+            editor,
+            _subscription: subscription,
+        });
+        cx.notify();
+    }
+
+    /// Hides the editor for the cell's name, renaming the cell to the entered name if `save` is true.
+    fn finish_rename(&mut self, save: bool, cx: &mut ViewContext<Self>) {
+        let Some(name_editor) = self.name_editor_mut().take() else {
+            return;
+        };
+
+        if save {
+            let name = name_editor.editor.read(cx).text(cx);
+            self.rename(Some(name.into()));
+        }
+        cx.notify();
+    }
+
+    /// Renders the cell's name above it, or the editor for it while the cell is being
+    /// renamed. Cells without a name have no header.
+    fn name_header(&self, cx: &ViewContext<Self>) -> Option<impl IntoElement> {
+        let content = if let Some(name_editor) = self.name_editor() {
+            div()
+                .w_64()
+                .child(name_editor.editor.clone())
+                .into_any_element()
+        } else {
+            div()
+                .id("cell-name")
+                .cursor_pointer()
+                .child(
+                    Label::new(self.name()?)
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                )
+                .on_click(cx.listener(|this: &mut Self, _, cx| this.start_rename(cx)))
+                .into_any_element()
+        };
+
+        Some(
+            h_flex()
+                .w_full()
+                .pl(px(GUTTER_WIDTH) + DynamicSpacing::Base08.px(cx))
+                .pr_6()
+                .on_action(cx.listener(|this: &mut Self, _: &menu::Confirm, cx| {
+                    this.finish_rename(true, cx)
+                }))
+                .on_action(cx.listener(|this: &mut Self, _: &menu::Cancel, cx| {
+                    this.finish_rename(false, cx)
+                }))
+                .child(content),
+        )
+    }
+
     fn cell_position_spacer(
         &self,
         is_first: bool,
@@ -320,6 +453,7 @@ pub trait RunnableCell: RenderableCell {
 pub struct MarkdownCell {
     id: CellId,
     metadata: CellMetadata,
+    name_editor: Option<CellNameEditor>,
     source: String,
     parsed_markdown: Option<markdown_preview::markdown_elements::ParsedMarkdown>,
     markdown_parsing_task: Task<()>,
@@ -343,6 +477,18 @@ impl RenderableCell for MarkdownCell {
         &self.metadata
     }
 
+    fn metadata_mut(&mut self) -> &mut CellMetadata {
+        &mut self.metadata
+    }
+
+    fn name_editor(&self) -> Option<&CellNameEditor> {
+        self.name_editor.as_ref()
+    }
+
+    fn name_editor_mut(&mut self) -> &mut Option<CellNameEditor> {
+        &mut self.name_editor
+    }
+
     fn source(&self) -> &String {
         &self.source
     }
@@ -383,6 +529,7 @@ impl Render for MarkdownCell {
             .size_full()
             // TODO: Move base cell render into trait impl so we don't have to repeat this
             .children(self.cell_position_spacer(true, cx))
+            .children(self.name_header(cx))
             .child(
                 h_flex()
                     .w_full()
@@ -415,6 +562,7 @@ impl Render for MarkdownCell {
 pub struct CodeCell {
     id: CellId,
     metadata: CellMetadata,
+    name_editor: Option<CellNameEditor>,
     execution_count: Option<i32>,
     source: String,
     editor: View<editor::Editor>,
@@ -501,6 +649,18 @@ impl RenderableCell for CodeCell {
         &self.metadata
     }
 
+    fn metadata_mut(&mut self) -> &mut CellMetadata {
+        &mut self.metadata
+    }
+
+    fn name_editor(&self) -> Option<&CellNameEditor> {
+        self.name_editor.as_ref()
+    }
+
+    fn name_editor_mut(&mut self) -> &mut Option<CellNameEditor> {
+        &mut self.name_editor
+    }
+
     fn source(&self) -> &String {
         &self.source
     }
@@ -557,6 +717,7 @@ impl Render for CodeCell {
             .size_full()
             // TODO: Move base cell render into trait impl so we don't have to repeat this
             .children(self.cell_position_spacer(true, cx))
+            .children(self.name_header(cx))
             // Editor portion
             .child(
                 h_flex()
@@ -614,6 +775,15 @@ impl Render for CodeCell {
                                             Output::Markdown { content, .. } => {
                                                 Some(content.clone().into_any_element())
                                             }
+                                            Output::Html { content, .. } => {
+                                                Some(content.clone().into_any_element())
+                                            }
+                                            Output::Json { content, .. } => {
+                                                Some(content.clone().into_any_element())
+                                            }
+                                            Output::Latex { content, .. } => {
+                                                Some(content.clone().into_any_element())
+                                            }
                                             Output::Stream { content, .. } => {
                                                 Some(content.clone().into_any_element())
                                             }
@@ -629,6 +799,9 @@ impl Render for CodeCell {
                                             Output::ErrorOutput(error_view) => {
                                                 error_view.render(cx)
                                             }
+                                            Output::Widget { content } => {
+                                                Some(content.clone().into_any_element())
+                                            }
                                             Output::ClearOutputWaitMarker => None,
                                         };
 
@@ -655,6 +828,7 @@ impl Render for CodeCell {
 pub struct RawCell {
     id: CellId,
     metadata: CellMetadata,
+    name_editor: Option<CellNameEditor>,
     source: String,
     selected: bool,
     cell_position: Option<CellPosition>,
@@ -675,6 +849,18 @@ impl RenderableCell for RawCell {
         &self.metadata
     }
 
+    fn metadata_mut(&mut self) -> &mut CellMetadata {
+        &mut self.metadata
+    }
+
+    fn name_editor(&self) -> Option<&CellNameEditor> {
+        self.name_editor.as_ref()
+    }
+
+    fn name_editor_mut(&mut self) -> &mut Option<CellNameEditor> {
+        &mut self.name_editor
+    }
+
     fn source(&self) -> &String {
         &self.source
     }
@@ -704,6 +890,7 @@ impl Render for RawCell {
             .size_full()
             // TODO: Move base cell render into trait impl so we don't have to repeat this
             .children(self.cell_position_spacer(true, cx))
+            .children(self.name_header(cx))
             .child(
                 h_flex()
                     .w_full()
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use fuzzy::{StringMatch, StringMatchCandidate};
+use gpui::{DismissEvent, Task, WeakView};
+use picker::{Picker, PickerDelegate};
+use ui::{prelude::*, HighlightedLabel, ListItem, ListItemSpacing};
+
+use super::NotebookEditor;
+
+/// A picker listing the named cells of a notebook, used to jump to one of them.
+pub struct CellPickerDelegate {
+    notebook: WeakView<NotebookEditor>,
+    /// The index and name of every named cell, in notebook order.
+    cells: Vec<(usize, SharedString)>,
+    /// The cells matching the query, best match first. Their candidate ids are
+    /// indices into `cells`.
+    matches: Vec<StringMatch>,
+    selected_index: usize,
+}
+
+impl CellPickerDelegate {
+    pub fn new(notebook: WeakView<NotebookEditor>, cells: Vec<(usize, SharedString)>) -> Self {
+        let matches = cells
+            .iter()
+            .enumerate()
+            .map(|(ix, (_, name))| StringMatch {
+                candidate_id: ix,
+                score: 0.,
+                positions: Vec::new(),
+                string: name.to_string(),
+            })
+            .collect();
+        Self {
+            notebook,
+            cells,
+            matches,
+            selected_index: 0,
+        }
+    }
+}
+
+impl PickerDelegate for CellPickerDelegate {
+    type ListItem = ListItem;
+
+    fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    fn set_selected_index(&mut self, ix: usize, cx: &mut ViewContext<Picker<Self>>) {
+        self.selected_index = ix;
+        cx.notify();
+    }
+
+    fn placeholder_text(&self, _cx: &mut WindowContext) -> Arc<str> {
+        "Go to a named cell...".into()
+    }
+
+    fn no_matches_text(&self, _cx: &mut WindowContext) -> SharedString {
+        "No named cells".into()
+    }
+
+    fn update_matches(&mut self, query: String, cx: &mut ViewContext<Picker<Self>>) -> Task<()> {
+        let candidates = self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(ix, (_, name))| StringMatchCandidate::new(ix, name))
+            .collect::<Vec<_>>();
+        let executor = cx.background_executor().clone();
+
+        cx.spawn(|picker, mut cx| async move {
+            let matches = fuzzy::match_strings(
+                &candidates,
+                &query,
+                false,
+                candidates.len(),
+                &Default::default(),
+                executor,
+            )
+            .await;
+
+            picker
+                .update(&mut cx, |picker, cx| {
+                    picker.delegate.matches = matches;
+                    picker.delegate.selected_index = 0;
+                    cx.notify();
+                })
+                .ok();
+        })
+    }
+
+    fn confirm(&mut self, _secondary: bool, cx: &mut ViewContext<Picker<Self>>) {
+        if let Some((cell_index, _)) = self
+            .matches
+            .get(self.selected_index)
+            .and_then(|string_match| self.cells.get(string_match.candidate_id))
+        {
+            let cell_index = *cell_index;
+            self.notebook
+                .update(cx, |notebook, cx| {
+                    notebook.set_selected_index(cell_index, true, cx);
+                    cx.notify();
+                })
+                .ok();
+        }
+        cx.emit(DismissEvent);
+    }
+
+    fn dismissed(&mut self, _cx: &mut ViewContext<Picker<Self>>) {}
+
+    fn render_match(
+        &self,
+        ix: usize,
+        selected: bool,
+        _cx: &mut ViewContext<Picker<Self>>,
+    ) -> Option<Self::ListItem> {
+        let string_match = self.matches.get(ix)?;
+        let (cell_index, _) = self.cells.get(string_match.candidate_id)?;
+
+        Some(
+            ListItem::new(ix)
+                .inset(true)
+                .spacing(ListItemSpacing::Sparse)
+                .toggle_state(selected)
+                .child(HighlightedLabel::new(
+                    string_match.string.clone(),
+                    string_match.positions.clone(),
+                ))
+                .end_slot(
+                    Label::new(format!("Cell {}", cell_index + 1))
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                ),
+        )
+    }
+}
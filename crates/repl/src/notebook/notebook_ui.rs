@@ -13,14 +13,15 @@ use gpui::{
     ListScrollEvent, ListState, Model, Point, Task, View,
 };
 use language::{Language, LanguageRegistry};
+use picker::Picker;
 use project::{Project, ProjectEntryId, ProjectPath};
 use ui::{prelude::*, Tooltip};
 use workspace::item::{ItemEvent, TabContentParams};
 use workspace::searchable::SearchableItemHandle;
-use workspace::{Item, ItemHandle, ProjectItem, ToolbarItemLocation};
+use workspace::{Item, ItemHandle, ProjectItem, ToolbarItemLocation, Workspace};
 use workspace::{ToolbarItemEvent, ToolbarItemView};
 
-use super::{Cell, CellPosition, RenderableCell};
+use super::{Cell, CellPickerDelegate, CellPosition, RenderableCell};
 
 use nbformat::v4::CellId;
 use nbformat::v4::Metadata as NotebookMetadata;
@@ -35,6 +36,8 @@ actions!(
         MoveCellDown,
         AddMarkdownBlock,
         AddCodeBlock,
+        GoToCell,
+        RenameCell,
     ]
 );
 
@@ -177,11 +180,32 @@ impl NotebookEditor {
     }
 
     fn move_cell_up(&mut self, cx: &mut ViewContext<Self>) {
-        println!("Move cell up triggered");
+        let index = self.selected_cell_index;
+        if index == 0 || index >= self.cell_order.len() {
+            return;
+        }
+
+        self.swap_with_next_cell(index - 1, cx);
+        self.set_selected_index(index - 1, true, cx);
     }
 
     fn move_cell_down(&mut self, cx: &mut ViewContext<Self>) {
-        println!("Move cell down triggered");
+        let index = self.selected_cell_index;
+        if index + 1 >= self.cell_order.len() {
+            return;
+        }
+
+        self.swap_with_next_cell(index, cx);
+        self.set_selected_index(index + 1, true, cx);
+    }
+
+    /// Swaps the cell at the given index with the one after it.
+    fn swap_with_next_cell(&mut self, index: usize, cx: &mut ViewContext<Self>) {
+        self.cell_order.swap(index, index + 1);
+        // The cells may have different heights, so they need to be measured again
+        self.cell_list.splice(index..index + 2, 2);
+        cx.emit(());
+        cx.notify();
     }
 
     fn add_markdown_block(&mut self, cx: &mut ViewContext<Self>) {
@@ -192,6 +216,43 @@ impl NotebookEditor {
         println!("Add code block triggered");
     }
 
+    /// Opens a picker listing the named cells, filtered by name, to jump to one of them.
+    fn go_to_cell(&mut self, _: &GoToCell, cx: &mut ViewContext<Self>) {
+        let Some(workspace) = Workspace::for_window(cx) else {
+            return;
+        };
+
+        let cells = self
+            .cell_order
+            .iter()
+            .enumerate()
+            .filter_map(|(index, cell_id)| {
+                let name = self.cell_map.get(cell_id)?.name(cx)?;
+                Some((index, name))
+            })
+            .collect();
+        let notebook = cx.view().downgrade();
+
+        workspace.update(cx, |workspace, cx| {
+            workspace.toggle_modal(cx, |cx| {
+                Picker::uniform_list(CellPickerDelegate::new(notebook, cells), cx)
+            });
+        });
+    }
+
+    /// Shows an editor for the selected cell's name.
+    fn rename_cell(&mut self, _: &RenameCell, cx: &mut ViewContext<Self>) {
+        let Some(cell) = self
+            .cell_order
+            .get(self.selected_cell_index)
+            .and_then(|cell_id| self.cell_map.get(cell_id))
+        else {
+            return;
+        };
+
+        cell.start_rename(cx);
+    }
+
     fn cell_count(&self) -> usize {
         self.cell_map.len()
     }
@@ -450,6 +511,8 @@ impl Render for NotebookEditor {
             .on_action(cx.listener(|this, &MoveCellDown, cx| this.move_cell_down(cx)))
             .on_action(cx.listener(|this, &AddMarkdownBlock, cx| this.add_markdown_block(cx)))
             .on_action(cx.listener(|this, &AddCodeBlock, cx| this.add_code_block(cx)))
+            .on_action(cx.listener(Self::go_to_cell))
+            .on_action(cx.listener(Self::rename_cell))
             .on_action(cx.listener(Self::select_next))
             .on_action(cx.listener(Self::select_previous))
             .on_action(cx.listener(Self::select_first))
@@ -636,6 +699,11 @@ impl EventEmitter<()> for NotebookEditor {}
 impl Item for NotebookEditor {
     type Event = ();
 
+    fn to_item_events(_: &Self::Event, mut f: impl FnMut(ItemEvent)) {
+        // The only event is emitted when the cells are reordered
+        f(ItemEvent::Edit)
+    }
+
     fn clone_on_split(
         &self,
         _workspace_id: Option<workspace::WorkspaceId>,
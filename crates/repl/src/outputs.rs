@@ -14,9 +14,13 @@
 //! The module supports several output types, including:
 //! - Plain text
 //! - Markdown
+//! - HTML (a small subset of tags)
+//! - JSON, as a collapsible tree
+//! - LaTeX, with math converted to Unicode text
 //! - Images (PNG and JPEG)
 //! - Tables
 //! - Error messages
+//! - Progress bar widgets from `ipywidgets`
 //!
 //! ## Clipboard Support
 //!
@@ -33,20 +37,30 @@
 //! This module is designed to work with Jupyter message protocols,
 //! interpreting and displaying various types of Jupyter output.
 
-use std::time::Duration;
+use std::{rc::Rc, time::Duration};
 
 use editor::{Editor, MultiBuffer};
 use gpui::{
-    percentage, Animation, AnimationExt, AnyElement, ClipboardItem, Model, Render, Transformation,
-    View, WeakView,
+    percentage, Animation, AnimationExt, AnyElement, ClipboardItem, EventEmitter, Model, Render,
+    Transformation, View, WeakView,
 };
 use language::Buffer;
 use runtimelib::{ExecutionState, JupyterMessageContent, MimeBundle, MimeType};
+use settings::Settings as _;
 use ui::{div, prelude::*, v_flex, IntoElement, Styled, Tooltip, ViewContext};
 
+mod html;
+use html::HtmlOutput;
+
 mod image;
 use image::ImageView;
 
+mod json;
+use json::JsonOutput;
+
+mod latex;
+use latex::LaTeXOutput;
+
 mod markdown;
 use markdown::MarkdownView;
 
@@ -57,15 +71,23 @@ pub mod plain;
 use plain::TerminalOutput;
 
 pub(crate) mod user_error;
-use user_error::ErrorView;
+use user_error::{ErrorLocals, ErrorView};
+
+mod widget;
+pub use widget::ProgressWidget;
 use workspace::Workspace;
 
+use crate::JupyterSettings;
+
 /// When deciding what to render from a collection of mediatypes, we need to rank them in order of importance
 fn rank_mime_type(mimetype: &MimeType) -> usize {
     match mimetype {
-        MimeType::DataTable(_) => 6,
-        MimeType::Png(_) => 4,
-        MimeType::Jpeg(_) => 3,
+        MimeType::DataTable(_) => 8,
+        MimeType::Png(_) => 7,
+        MimeType::Jpeg(_) => 6,
+        MimeType::Html(_) => 5,
+        MimeType::Json(_) => 4,
+        MimeType::Latex(_) => 3,
         MimeType::Markdown(_) => 2,
         MimeType::Plain(_) => 1,
         // All other media types are not supported in Zed at this time
@@ -126,6 +148,21 @@ pub enum Output {
         content: View<MarkdownView>,
         display_id: Option<String>,
     },
+    Html {
+        content: View<HtmlOutput>,
+        display_id: Option<String>,
+    },
+    Json {
+        content: View<JsonOutput>,
+        display_id: Option<String>,
+    },
+    Latex {
+        content: View<LaTeXOutput>,
+        display_id: Option<String>,
+    },
+    Widget {
+        content: View<ProgressWidget>,
+    },
     ClearOutputWaitMarker,
 }
 
@@ -210,11 +247,16 @@ impl Output {
         let content = match self {
             Self::Plain { content, .. } => Some(content.clone().into_any_element()),
             Self::Markdown { content, .. } => Some(content.clone().into_any_element()),
+            Self::Html { content, .. } => Some(content.clone().into_any_element()),
+            Self::Json { content, .. } => Some(content.clone().into_any_element()),
+            Self::Latex { content, .. } => Some(content.clone().into_any_element()),
             Self::Stream { content, .. } => Some(content.clone().into_any_element()),
             Self::Image { content, .. } => Some(content.clone().into_any_element()),
             Self::Message(message) => Some(div().child(message.clone()).into_any_element()),
             Self::Table { content, .. } => Some(content.clone().into_any_element()),
+            Self::ErrorOutput(error_view) if error_view.compact => error_view.render_compact(cx),
             Self::ErrorOutput(error_view) => error_view.render(cx),
+            Self::Widget { content } => Some(content.clone().into_any_element()),
             Self::ClearOutputWaitMarker => None,
         };
 
@@ -229,6 +271,15 @@ impl Output {
                 Self::Markdown { content, .. } => {
                     Self::render_output_controls(content.clone(), workspace.clone(), cx)
                 }
+                Self::Html { content, .. } => {
+                    Self::render_output_controls(content.clone(), workspace.clone(), cx)
+                }
+                Self::Json { content, .. } => {
+                    Self::render_output_controls(content.clone(), workspace.clone(), cx)
+                }
+                Self::Latex { content, .. } => {
+                    Self::render_output_controls(content.clone(), workspace.clone(), cx)
+                }
                 Self::Stream { content, .. } => {
                     Self::render_output_controls(content.clone(), workspace.clone(), cx)
                 }
@@ -242,6 +293,7 @@ impl Output {
                 Self::Table { content, .. } => {
                     Self::render_output_controls(content.clone(), workspace.clone(), cx)
                 }
+                Self::Widget { .. } => None,
                 Self::ClearOutputWaitMarker => None,
             })
     }
@@ -255,6 +307,10 @@ impl Output {
             Output::Message(_) => None,
             Output::Table { display_id, .. } => display_id.clone(),
             Output::Markdown { display_id, .. } => display_id.clone(),
+            Output::Html { display_id, .. } => display_id.clone(),
+            Output::Json { display_id, .. } => display_id.clone(),
+            Output::Latex { display_id, .. } => display_id.clone(),
+            Output::Widget { .. } => None,
             Output::ClearOutputWaitMarker => None,
         }
     }
@@ -265,6 +321,20 @@ impl Output {
                 content: cx.new_view(|cx| TerminalOutput::from(text, cx)),
                 display_id,
             },
+            Some(MimeType::Json(json)) => Output::Json {
+                content: cx.new_view(|_| JsonOutput::new(json.clone())),
+                display_id,
+            },
+            Some(MimeType::Latex(latex)) => {
+                let plain_text = data.content.iter().find_map(|media| match media {
+                    MimeType::Plain(text) => Some(text.as_str()),
+                    _ => None,
+                });
+                Output::Latex {
+                    content: cx.new_view(|cx| LaTeXOutput::new(latex, plain_text, cx)),
+                    display_id,
+                }
+            }
             Some(MimeType::Markdown(text)) => {
                 let view = cx.new_view(|cx| MarkdownView::from(text.clone(), cx));
                 Output::Markdown {
@@ -272,12 +342,13 @@ impl Output {
                     display_id,
                 }
             }
-            Some(MimeType::Png(data)) | Some(MimeType::Jpeg(data)) => match ImageView::from(data) {
-                Ok(view) => Output::Image {
-                    content: cx.new_view(|_| view),
-                    display_id,
-                },
-                Err(error) => Output::Message(format!("Failed to load image: {}", error)),
+            Some(MimeType::Html(html)) => Output::Html {
+                content: cx.new_view(|cx| HtmlOutput::from(html, cx)),
+                display_id,
+            },
+            Some(MimeType::Png(data)) | Some(MimeType::Jpeg(data)) => Output::Image {
+                content: cx.new_view(|cx| ImageView::new(data.clone(), cx)),
+                display_id,
             },
             Some(MimeType::DataTable(data)) => Output::Table {
                 content: cx.new_view(|cx| TableView::new(data, cx)),
@@ -311,8 +382,19 @@ pub struct ExecutionView {
     workspace: WeakView<Workspace>,
     pub outputs: Vec<Output>,
     pub status: ExecutionStatus,
+    /// How long the kernel took to execute the code, once it has replied.
+    pub duration: Option<Duration>,
+    can_show_locals: bool,
+    compact_errors: bool,
+}
+
+pub enum ExecutionViewEvent {
+    /// The user asked to see the local variables of the frame that raised an error.
+    RequestLocals,
 }
 
+impl EventEmitter<ExecutionViewEvent> for ExecutionView {}
+
 impl ExecutionView {
     pub fn new(
         status: ExecutionStatus,
@@ -323,6 +405,63 @@ impl ExecutionView {
             workspace,
             outputs: Default::default(),
             status,
+            duration: None,
+            can_show_locals: false,
+            compact_errors: false,
+        }
+    }
+
+    pub fn set_duration(&mut self, duration: Duration, cx: &mut ViewContext<Self>) {
+        self.duration = Some(duration);
+        cx.notify();
+    }
+
+    /// Enables the "Show locals" button on errors, for kernels that can report
+    /// the local variables of the frame that raised.
+    pub fn set_can_show_locals(&mut self, can_show_locals: bool) {
+        self.can_show_locals = can_show_locals;
+    }
+
+    /// Shows errors on a single line, which can be clicked to show the full error,
+    /// for outputs shown inline in an editor.
+    pub fn set_compact_errors(&mut self, compact_errors: bool) {
+        self.compact_errors = compact_errors;
+    }
+
+    fn error_view_mut(&mut self) -> Option<&mut ErrorView> {
+        self.outputs
+            .iter_mut()
+            .rev()
+            .find_map(|output| match output {
+                Output::ErrorOutput(error_view) => Some(error_view),
+                _ => None,
+            })
+    }
+
+    pub fn toggle_locals(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(error_view) = self.error_view_mut() else {
+            return;
+        };
+
+        error_view.show_locals = !error_view.show_locals;
+        if error_view.show_locals && error_view.locals == ErrorLocals::NotRequested {
+            error_view.locals = ErrorLocals::Loading;
+            cx.emit(ExecutionViewEvent::RequestLocals);
+        }
+        cx.notify();
+    }
+
+    pub fn expand_error(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(error_view) = self.error_view_mut() {
+            error_view.compact = false;
+            cx.notify();
+        }
+    }
+
+    pub fn set_locals(&mut self, locals: ErrorLocals, cx: &mut ViewContext<Self>) {
+        if let Some(error_view) = self.error_view_mut() {
+            error_view.locals = locals;
+            cx.notify();
         }
     }
 
@@ -351,11 +490,26 @@ impl ExecutionView {
                 let terminal =
                     cx.new_view(|cx| TerminalOutput::from(&result.traceback.join("\n"), cx));
 
-                Output::ErrorOutput(ErrorView {
-                    ename: result.ename.clone(),
-                    evalue: result.evalue.clone(),
-                    traceback: terminal,
-                })
+                let mut error_view =
+                    ErrorView::new(result.ename.clone(), result.evalue.clone(), terminal);
+                if self.can_show_locals {
+                    let execution_view = cx.view().downgrade();
+                    error_view.on_toggle_locals = Some(Rc::new(move |cx| {
+                        execution_view
+                            .update(cx, |execution_view, cx| execution_view.toggle_locals(cx))
+                            .ok();
+                    }));
+                }
+                if self.compact_errors {
+                    let execution_view = cx.view().downgrade();
+                    error_view.compact = true;
+                    error_view.on_expand = Some(Rc::new(move |cx| {
+                        execution_view
+                            .update(cx, |execution_view, cx| execution_view.expand_error(cx))
+                            .ok();
+                    }));
+                }
+                Output::ErrorOutput(error_view)
             }
             JupyterMessageContent::ExecuteReply(reply) => {
                 for payload in reply.payload.iter() {
@@ -404,6 +558,16 @@ impl ExecutionView {
         cx.notify();
     }
 
+    /// Displays an `ipywidgets` widget, whose model is kept up to date by the session's comms.
+    pub fn push_widget(&mut self, widget: View<ProgressWidget>, cx: &mut ViewContext<Self>) {
+        if let Some(Output::ClearOutputWaitMarker) = self.outputs.last() {
+            self.outputs.clear();
+        }
+
+        self.outputs.push(Output::Widget { content: widget });
+        cx.notify();
+    }
+
     pub fn update_display_data(
         &mut self,
         data: &MimeBundle,
@@ -436,14 +600,21 @@ impl ExecutionView {
                 // and can just update the most recent terminal output
                 last_stream.update(cx, |last_stream, cx| {
                     last_stream.append_text(text, cx);
+                    last_stream.scroll_to_bottom(cx);
                     cx.notify();
                 });
                 return None;
             }
         }
 
+        let max_lines = JupyterSettings::get_global(cx).stream_output_max_lines;
         Some(Output::Stream {
-            content: cx.new_view(|cx| TerminalOutput::from(text, cx)),
+            content: cx.new_view(|cx| {
+                let mut output = TerminalOutput::from(text, cx);
+                output.set_max_visible_lines((max_lines > 0).then_some(max_lines));
+                output.scroll_to_bottom(cx);
+                output
+            }),
         })
     }
 }
@@ -491,16 +662,24 @@ impl Render for ExecutionView {
                 .into_any_element(),
         };
 
+        let duration = self.duration.map(|duration| {
+            h_flex()
+                .w_full()
+                .justify_end()
+                .child(Label::new(format!("{:.2}s", duration.as_secs_f64())).color(Color::Muted))
+        });
+
         if self.outputs.is_empty() {
-            return v_flex()
+            return h_flex()
                 .min_h(cx.line_height())
-                .justify_center()
                 .child(status)
+                .children(duration)
                 .into_any_element();
         }
 
         div()
             .w_full()
+            .children(duration)
             .children(
                 self.outputs
                     .iter()
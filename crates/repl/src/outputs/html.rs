@@ -0,0 +1,410 @@
+//! # HTML Output for REPL
+//!
+//! Some kernels (IPython rich displays, Plotly, widget fallbacks) emit `text/html`.
+//! Rather than embedding a browser, this module renders a small subset of HTML
+//! natively:
+//!
+//! - `<b>`/`<strong>`, `<i>`/`<em>` and `<code>` as styled text runs
+//! - `<a href>` as clickable links
+//! - `<p>` and `<br>` as paragraph and line breaks
+//! - `<pre>` using [`TerminalOutput`]
+//! - `<table>` as a grid of cells
+//!
+//! Any other tag is rendered as its text content.
+
+use std::ops::Range;
+
+use gpui::{
+    AnyElement, ClipboardItem, FontStyle, FontWeight, HighlightStyle, InteractiveText, StyledText,
+    UnderlineStyle, View,
+};
+use html5ever::{
+    driver::ParseOpts, parse_document, tendril::TendrilSink, tree_builder::TreeBuilderOpts,
+};
+use markup5ever_rcdom::{Handle, NodeData, RcDom};
+use ui::{div, prelude::*, v_flex, IntoElement};
+
+use crate::outputs::{plain::TerminalOutput, OutputContent};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct InlineStyle {
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct HtmlSpan {
+    range: Range<usize>,
+    style: InlineStyle,
+    link: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct HtmlParagraph {
+    text: String,
+    spans: Vec<HtmlSpan>,
+}
+
+#[derive(Debug, PartialEq)]
+enum HtmlBlock {
+    Paragraph(HtmlParagraph),
+    Preformatted(String),
+    Table(Vec<Vec<String>>),
+}
+
+enum RenderedHtmlBlock {
+    Paragraph(HtmlParagraph),
+    Preformatted(View<TerminalOutput>),
+    Table(Vec<Vec<String>>),
+}
+
+/// Renders `text/html` output using a GPUI-native subset of HTML.
+pub struct HtmlOutput {
+    raw_html: String,
+    blocks: Vec<RenderedHtmlBlock>,
+}
+
+impl HtmlOutput {
+    pub fn from(html: &str, cx: &mut WindowContext) -> Self {
+        let blocks = parse_html(html)
+            .into_iter()
+            .map(|block| match block {
+                HtmlBlock::Paragraph(paragraph) => RenderedHtmlBlock::Paragraph(paragraph),
+                HtmlBlock::Preformatted(text) => RenderedHtmlBlock::Preformatted(
+                    cx.new_view(|cx| TerminalOutput::from(&text, cx)),
+                ),
+                HtmlBlock::Table(rows) => RenderedHtmlBlock::Table(rows),
+            })
+            .collect();
+
+        Self {
+            raw_html: html.to_string(),
+            blocks,
+        }
+    }
+
+    fn render_paragraph(
+        ix: usize,
+        paragraph: &HtmlParagraph,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let text_style = cx.text_style();
+        let link_color = cx.theme().colors().text_accent;
+        let code_background = cx.theme().colors().element_background;
+
+        let highlights = paragraph.spans.iter().map(|span| {
+            let mut highlight = HighlightStyle::default();
+            if span.style.bold {
+                highlight.font_weight = Some(FontWeight::BOLD);
+            }
+            if span.style.italic {
+                highlight.font_style = Some(FontStyle::Italic);
+            }
+            if span.style.code {
+                highlight.background_color = Some(code_background);
+            }
+            if span.link.is_some() {
+                highlight.color = Some(link_color);
+                highlight.underline = Some(UnderlineStyle {
+                    thickness: px(1.),
+                    ..Default::default()
+                });
+            }
+            (span.range.clone(), highlight)
+        });
+        let styled_text =
+            StyledText::new(paragraph.text.clone()).with_highlights(&text_style, highlights);
+
+        let (link_ranges, link_urls): (Vec<_>, Vec<_>) = paragraph
+            .spans
+            .iter()
+            .filter_map(|span| Some((span.range.clone(), span.link.clone()?)))
+            .unzip();
+        if link_ranges.is_empty() {
+            return div().child(styled_text).into_any_element();
+        }
+
+        InteractiveText::new(("html-paragraph", ix), styled_text)
+            .on_click(link_ranges, move |link_ix, cx| {
+                cx.open_url(&link_urls[link_ix]);
+            })
+            .into_any_element()
+    }
+
+    fn render_table(rows: &[Vec<String>], cx: &mut ViewContext<Self>) -> AnyElement {
+        let border_color = cx.theme().colors().border;
+        let header_background = cx.theme().colors().element_background;
+
+        v_flex()
+            .children(rows.iter().enumerate().map(|(row_ix, row)| {
+                h_flex().children(row.iter().map(|cell| {
+                    div()
+                        .flex_1()
+                        .min_w_16()
+                        .px_2()
+                        .py_0p5()
+                        .border_1()
+                        .border_color(border_color)
+                        .when(row_ix == 0, |this| this.bg(header_background))
+                        .child(cell.clone())
+                }))
+            }))
+            .into_any_element()
+    }
+}
+
+impl Render for HtmlOutput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex().gap_2().children(
+            self.blocks
+                .iter()
+                .enumerate()
+                .map(|(ix, block)| match block {
+                    RenderedHtmlBlock::Paragraph(paragraph) => {
+                        Self::render_paragraph(ix, paragraph, cx)
+                    }
+                    RenderedHtmlBlock::Preformatted(view) => view.clone().into_any_element(),
+                    RenderedHtmlBlock::Table(rows) => Self::render_table(rows, cx),
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl OutputContent for HtmlOutput {
+    fn clipboard_content(&self, _cx: &WindowContext) -> Option<ClipboardItem> {
+        Some(ClipboardItem::new_string(self.raw_html.clone()))
+    }
+
+    fn has_clipboard_content(&self, _cx: &WindowContext) -> bool {
+        true
+    }
+}
+
+fn parse_html(html: &str) -> Vec<HtmlBlock> {
+    let parse_options = ParseOpts {
+        tree_builder: TreeBuilderOpts {
+            drop_doctype: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let dom = parse_document(RcDom::default(), parse_options).one(html);
+
+    let mut builder = HtmlBlockBuilder::default();
+    builder.visit(&dom.document, InlineStyle::default(), None);
+    builder.finish_paragraph();
+    builder.blocks
+}
+
+#[derive(Default)]
+struct HtmlBlockBuilder {
+    blocks: Vec<HtmlBlock>,
+    paragraph: HtmlParagraph,
+}
+
+impl HtmlBlockBuilder {
+    fn visit(&mut self, node: &Handle, style: InlineStyle, link: Option<&str>) {
+        match &node.data {
+            NodeData::Text { contents } => self.push_text(&contents.borrow(), style, link),
+            NodeData::Element { name, attrs, .. } => {
+                let mut style = style;
+                let mut link = link.map(ToString::to_string);
+                match &*name.local {
+                    "head" | "script" | "style" => return,
+                    "b" | "strong" => style.bold = true,
+                    "i" | "em" => style.italic = true,
+                    "code" => style.code = true,
+                    "a" => {
+                        link = attrs
+                            .borrow()
+                            .iter()
+                            .find(|attr| &*attr.name.local == "href")
+                            .map(|attr| attr.value.to_string());
+                    }
+                    "br" => {
+                        self.paragraph.text.push('\n');
+                        return;
+                    }
+                    "p" => {
+                        self.finish_paragraph();
+                        self.visit_children(node, style, link.as_deref());
+                        self.finish_paragraph();
+                        return;
+                    }
+                    "pre" => {
+                        self.finish_paragraph();
+                        let mut text = String::new();
+                        collect_text(node, &mut text);
+                        self.blocks.push(HtmlBlock::Preformatted(text));
+                        return;
+                    }
+                    "table" => {
+                        self.finish_paragraph();
+                        let mut rows = Vec::new();
+                        collect_table_rows(node, &mut rows);
+                        self.blocks.push(HtmlBlock::Table(rows));
+                        return;
+                    }
+                    _ => {}
+                }
+                self.visit_children(node, style, link.as_deref());
+            }
+            _ => self.visit_children(node, style, link),
+        }
+    }
+
+    fn visit_children(&mut self, node: &Handle, style: InlineStyle, link: Option<&str>) {
+        for child in node.children.borrow().iter() {
+            self.visit(child, style, link);
+        }
+    }
+
+    fn push_text(&mut self, text: &str, style: InlineStyle, link: Option<&str>) {
+        let mut collapsed = String::with_capacity(text.len());
+        let mut last_was_whitespace =
+            self.paragraph.text.is_empty() || self.paragraph.text.ends_with(char::is_whitespace);
+        for ch in text.chars() {
+            if ch.is_whitespace() {
+                if !last_was_whitespace {
+                    collapsed.push(' ');
+                }
+                last_was_whitespace = true;
+            } else {
+                collapsed.push(ch);
+                last_was_whitespace = false;
+            }
+        }
+        if collapsed.is_empty() {
+            return;
+        }
+
+        let start = self.paragraph.text.len();
+        self.paragraph.text.push_str(&collapsed);
+        if style != InlineStyle::default() || link.is_some() {
+            self.paragraph.spans.push(HtmlSpan {
+                range: start..self.paragraph.text.len(),
+                style,
+                link: link.map(ToString::to_string),
+            });
+        }
+    }
+
+    fn finish_paragraph(&mut self) {
+        let mut paragraph = std::mem::take(&mut self.paragraph);
+        let trimmed_len = paragraph.text.trim_end().len();
+        paragraph.text.truncate(trimmed_len);
+        for span in &mut paragraph.spans {
+            span.range.end = span.range.end.min(trimmed_len);
+        }
+        paragraph.spans.retain(|span| !span.range.is_empty());
+
+        if !paragraph.text.is_empty() {
+            self.blocks.push(HtmlBlock::Paragraph(paragraph));
+        }
+    }
+}
+
+fn collect_text(node: &Handle, text: &mut String) {
+    match &node.data {
+        NodeData::Text { contents } => text.push_str(&contents.borrow()),
+        NodeData::Element { name, .. } if &*name.local == "br" => text.push('\n'),
+        _ => {
+            for child in node.children.borrow().iter() {
+                collect_text(child, text);
+            }
+        }
+    }
+}
+
+fn collect_table_rows(node: &Handle, rows: &mut Vec<Vec<String>>) {
+    for child in node.children.borrow().iter() {
+        let NodeData::Element { name, .. } = &child.data else {
+            continue;
+        };
+        if &*name.local == "tr" {
+            let cells = child
+                .children
+                .borrow()
+                .iter()
+                .filter(|cell| {
+                    matches!(
+                        &cell.data,
+                        NodeData::Element { name, .. }
+                            if matches!(&*name.local, "td" | "th")
+                    )
+                })
+                .map(|cell| {
+                    let mut text = String::new();
+                    collect_text(cell, &mut text);
+                    text.split_whitespace().collect::<Vec<_>>().join(" ")
+                })
+                .collect();
+            rows.push(cells);
+        } else {
+            collect_table_rows(child, rows);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_inline_styles_and_links() {
+        let blocks =
+            parse_html(r#"<p>Some <b>bold</b> and <a href="https://zed.dev">a link</a></p>"#);
+
+        assert_eq!(
+            blocks,
+            vec![HtmlBlock::Paragraph(HtmlParagraph {
+                text: "Some bold and a link".into(),
+                spans: vec![
+                    HtmlSpan {
+                        range: 5..9,
+                        style: InlineStyle {
+                            bold: true,
+                            ..Default::default()
+                        },
+                        link: None,
+                    },
+                    HtmlSpan {
+                        range: 14..20,
+                        style: InlineStyle::default(),
+                        link: Some("https://zed.dev".into()),
+                    },
+                ],
+            })]
+        );
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let blocks = parse_html(
+            "<div>intro<br>next</div><pre>a  b\nc</pre>\
+             <table><tr><th>x</th><th>y</th></tr><tr><td>1</td><td>2</td></tr></table>\
+             <span>unsupported</span>",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                HtmlBlock::Paragraph(HtmlParagraph {
+                    text: "intro\nnext".into(),
+                    spans: Vec::new(),
+                }),
+                HtmlBlock::Preformatted("a  b\nc".into()),
+                HtmlBlock::Table(vec![
+                    vec!["x".into(), "y".into()],
+                    vec!["1".into(), "2".into()],
+                ]),
+                HtmlBlock::Paragraph(HtmlParagraph {
+                    text: "unsupported".into(),
+                    spans: Vec::new(),
+                }),
+            ]
+        );
+    }
+}
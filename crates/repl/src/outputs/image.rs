@@ -4,14 +4,21 @@ use base64::{
     engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
     Engine as _,
 };
-use gpui::{img, ClipboardItem, Image, ImageFormat, Pixels, RenderImage, WindowContext};
+use gpui::{img, ClipboardItem, Image, ImageFormat, Pixels, RenderImage, Task, WindowContext};
 use std::sync::Arc;
 use ui::{div, prelude::*, IntoElement, Styled};
 
 use crate::outputs::OutputContent;
 
 /// ImageView renders an image inline in an editor, adapting to the line height to fit the image.
+///
+/// The image is decoded on a blocking thread pool, so large images don't stall the UI.
 pub struct ImageView {
+    image: Option<Result<DecodedImage, String>>,
+    _decode_task: Task<()>,
+}
+
+struct DecodedImage {
     clipboard_image: Arc<Image>,
     height: u32,
     width: u32,
@@ -26,7 +33,26 @@ pub const STANDARD_INDIFFERENT: GeneralPurpose = GeneralPurpose::new(
 );
 
 impl ImageView {
-    pub fn from(base64_encoded_data: &str) -> Result<Self> {
+    pub fn new(base64_encoded_data: String, cx: &mut ViewContext<Self>) -> Self {
+        let decode = cx.spawn_blocking(move || DecodedImage::from(&base64_encoded_data));
+        let decode_task = cx.spawn(|this, mut cx| async move {
+            let image = decode.await.map_err(|error| error.to_string());
+            this.update(&mut cx, |this, cx| {
+                this.image = Some(image);
+                cx.notify();
+            })
+            .ok();
+        });
+
+        Self {
+            image: None,
+            _decode_task: decode_task,
+        }
+    }
+}
+
+impl DecodedImage {
+    fn from(base64_encoded_data: &str) -> Result<Self> {
         let filtered =
             base64_encoded_data.replace(&[' ', '\n', '\t', '\r', '\x0b', '\x0c'][..], "");
         let bytes = STANDARD_INDIFFERENT.decode(filtered)?;
@@ -64,7 +90,7 @@ impl ImageView {
             id: gpui_image_data.id.0 as u64,
         });
 
-        Ok(ImageView {
+        Ok(DecodedImage {
             clipboard_image,
             height,
             width,
@@ -75,28 +101,49 @@ impl ImageView {
 
 impl Render for ImageView {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let decoded = match &self.image {
+            Some(Ok(decoded)) => decoded,
+            Some(Err(error)) => {
+                return div()
+                    .child(format!("Failed to load image: {}", error))
+                    .into_any_element()
+            }
+            None => {
+                return Label::new("Loading image…")
+                    .color(Color::Muted)
+                    .into_any_element()
+            }
+        };
+
         let line_height = cx.line_height();
 
-        let (height, width) = if self.height as f32 / line_height.0 == u8::MAX as f32 {
+        let (height, width) = if decoded.height as f32 / line_height.0 == u8::MAX as f32 {
             let height = u8::MAX as f32 * line_height.0;
-            let width = self.width as f32 * height / self.height as f32;
+            let width = decoded.width as f32 * height / decoded.height as f32;
             (height, width)
         } else {
-            (self.height as f32, self.width as f32)
+            (decoded.height as f32, decoded.width as f32)
         };
 
-        let image = self.image.clone();
+        let image = decoded.image.clone();
 
-        div().h(Pixels(height)).w(Pixels(width)).child(img(image))
+        div()
+            .h(Pixels(height))
+            .w(Pixels(width))
+            .child(img(image))
+            .into_any_element()
     }
 }
 
 impl OutputContent for ImageView {
     fn clipboard_content(&self, _cx: &WindowContext) -> Option<ClipboardItem> {
-        Some(ClipboardItem::new_image(self.clipboard_image.as_ref()))
+        match &self.image {
+            Some(Ok(decoded)) => Some(ClipboardItem::new_image(decoded.clipboard_image.as_ref())),
+            _ => None,
+        }
     }
 
     fn has_clipboard_content(&self, _cx: &WindowContext) -> bool {
-        true
+        matches!(self.image, Some(Ok(_)))
     }
 }
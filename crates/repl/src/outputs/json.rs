@@ -0,0 +1,159 @@
+//! # JSON Output for REPL
+//!
+//! Renders `application/json` output as a collapsible tree. Objects and arrays
+//! are shown as `{…}` and `[…]` rows that expand to show their entries, and
+//! primitive values are colored by type.
+
+use std::sync::Arc;
+
+use collections::HashMap;
+use gpui::{AnyElement, ClipboardItem};
+use serde_json::Value;
+use ui::{prelude::*, Disclosure};
+
+use crate::outputs::OutputContent;
+
+/// Containers nested less deeply than this are expanded until the user collapses them.
+const DEFAULT_EXPANDED_DEPTH: usize = 1;
+
+pub struct JsonOutput {
+    value: Value,
+    /// Expand/collapse state keyed by the path of keys (or array indices) leading to a container.
+    expanded: HashMap<Vec<String>, bool>,
+}
+
+impl JsonOutput {
+    pub fn new(value: Value) -> Self {
+        Self {
+            value,
+            expanded: HashMap::default(),
+        }
+    }
+
+    fn is_expanded(&self, path: &[String]) -> bool {
+        self.expanded
+            .get(path)
+            .copied()
+            .unwrap_or(path.len() < DEFAULT_EXPANDED_DEPTH)
+    }
+
+    fn toggle(&mut self, path: Vec<String>) {
+        let expanded = !self.is_expanded(&path);
+        self.expanded.insert(path, expanded);
+    }
+
+    fn render_value(
+        &self,
+        key: Option<&str>,
+        value: &Value,
+        path: Vec<String>,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let key_label = key.map(|key| Label::new(format!("{key}:")).color(Color::Muted));
+
+        let entries: Vec<(String, &Value)> = match value {
+            Value::Object(object) => object.iter().map(|(k, v)| (k.clone(), v)).collect(),
+            Value::Array(array) => array
+                .iter()
+                .enumerate()
+                .map(|(ix, v)| (ix.to_string(), v))
+                .collect(),
+            primitive => {
+                return h_flex()
+                    .gap_1()
+                    .pl_5()
+                    .children(key_label)
+                    .child(render_primitive(primitive))
+                    .into_any_element();
+            }
+        };
+
+        let is_expanded = self.is_expanded(&path);
+        let summary = match (value, is_expanded) {
+            (Value::Object(_), false) => format!("{{…}} {} keys", entries.len()),
+            (Value::Object(_), true) => "{".to_string(),
+            (_, false) => format!("[…] {} items", entries.len()),
+            (_, true) => "[".to_string(),
+        };
+
+        let header = h_flex()
+            .gap_1()
+            .child(
+                Disclosure::new(
+                    SharedString::from(format!("json-{}", path.join("/"))),
+                    is_expanded,
+                )
+                .on_toggle(Arc::new(cx.listener({
+                    let path = path.clone();
+                    move |this, _, cx| {
+                        this.toggle(path.clone());
+                        cx.notify();
+                    }
+                }))),
+            )
+            .children(key_label)
+            .child(Label::new(summary).color(Color::Muted));
+
+        v_flex()
+            .child(header)
+            .when(is_expanded, |this| {
+                this.child(v_flex().pl_4().children(entries.into_iter().map(
+                    |(entry_key, entry_value)| {
+                        let mut entry_path = path.clone();
+                        entry_path.push(entry_key.clone());
+                        self.render_value(Some(&entry_key), entry_value, entry_path, cx)
+                    },
+                )))
+            })
+            .into_any_element()
+    }
+}
+
+fn render_primitive(value: &Value) -> Label {
+    match value {
+        Value::String(string) => Label::new(format!("{string:?}")).color(Color::Success),
+        Value::Number(number) => Label::new(number.to_string()).color(Color::Info),
+        Value::Bool(bool) => Label::new(bool.to_string()).color(Color::Warning),
+        _ => Label::new(value.to_string()).color(Color::Muted),
+    }
+}
+
+impl Render for JsonOutput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .py_1()
+            .font_buffer(cx)
+            .child(self.render_value(None, &self.value, Vec::new(), cx))
+    }
+}
+
+impl OutputContent for JsonOutput {
+    fn clipboard_content(&self, _cx: &WindowContext) -> Option<ClipboardItem> {
+        let text = serde_json::to_string_pretty(&self.value).ok()?;
+        Some(ClipboardItem::new_string(text))
+    }
+
+    fn has_clipboard_content(&self, _cx: &WindowContext) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_output_expansion() {
+        let mut output = JsonOutput::new(json!({ "user": { "name": "Ada", "tags": ["a", "b"] } }));
+        let user = vec!["user".to_string()];
+        assert!(output.is_expanded(&[]));
+        assert!(!output.is_expanded(&user));
+
+        output.toggle(user.clone());
+        assert!(output.is_expanded(&user));
+        output.toggle(Vec::new());
+        assert!(!output.is_expanded(&[]));
+        assert!(output.is_expanded(&user));
+    }
+}
@@ -0,0 +1,584 @@
+//! # LaTeX Output for REPL
+//!
+//! SymPy, SciPy and other libraries display formulae as `text/latex`, where math is
+//! delimited by `$…$` or `\(…\)` for inline math and `$$…$$`, `\[…\]` or an `equation`
+//! style environment for display math.
+//!
+//! Formulae aren't typeset. Without a math typesetting engine, they're shown as a Unicode
+//! approximation instead, by converting common commands to their Unicode equivalents: Greek
+//! letters, operators and arrows, simple superscripts and subscripts, fractions as `a/b` and
+//! roots as `√x`. Inline math is shown in a smaller italic font within the surrounding text,
+//! and display math is centered on its own line at a larger size.
+//!
+//! Anything else, such as the `matrix` and `cases` environments SymPy uses for matrices and
+//! piecewise functions, can't be approximated faithfully. For output that uses it, the
+//! `text/plain` representation sent alongside the LaTeX is shown instead, or the LaTeX
+//! source if there is none.
+
+use gpui::{AnyElement, ClipboardItem, View};
+use ui::{div, prelude::*, v_flex, IntoElement};
+
+use crate::outputs::{plain::TerminalOutput, OutputContent};
+
+/// Environments whose content is rendered as display math.
+const DISPLAY_ENVIRONMENTS: &[&str] = &[
+    "equation",
+    "equation*",
+    "align",
+    "align*",
+    "gather",
+    "gather*",
+    "displaymath",
+];
+
+/// Commands that only affect spacing or sizing, and are dropped.
+const IGNORED_COMMANDS: &[&str] = &[
+    "left",
+    "right",
+    "displaystyle",
+    "textstyle",
+    "scriptstyle",
+    "limits",
+    "nolimits",
+    "big",
+    "Big",
+    "bigl",
+    "bigr",
+    "Bigl",
+    "Bigr",
+    "!",
+];
+
+#[derive(Debug, PartialEq)]
+enum LatexSegment {
+    Text(String),
+    InlineMath(String),
+    DisplayMath(String),
+}
+
+enum LatexContent {
+    Converted(Vec<LatexSegment>),
+    /// Plain text shown in place of LaTeX that can't be converted.
+    Plain(View<TerminalOutput>),
+}
+
+/// Renders `text/latex` output, with math converted to Unicode text.
+pub struct LaTeXOutput {
+    source: String,
+    content: LatexContent,
+}
+
+impl LaTeXOutput {
+    /// Converts the math in `source`, falling back to `plain_text`, or to `source` itself,
+    /// if it uses commands or environments that can't be converted to Unicode text.
+    pub fn new(source: &str, plain_text: Option<&str>, cx: &mut WindowContext) -> Self {
+        let content = match convert_latex(source) {
+            Some(segments) => LatexContent::Converted(segments),
+            None => {
+                let text = plain_text.unwrap_or(source);
+                LatexContent::Plain(cx.new_view(|cx| TerminalOutput::from(text, cx)))
+            }
+        };
+
+        Self {
+            source: source.to_string(),
+            content,
+        }
+    }
+}
+
+impl Render for LaTeXOutput {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let segments = match &self.content {
+            LatexContent::Converted(segments) => segments,
+            LatexContent::Plain(text) => return text.clone().into_any_element(),
+        };
+
+        let mut blocks: Vec<AnyElement> = Vec::new();
+        let mut line: Vec<AnyElement> = Vec::new();
+
+        fn flush_line(line: &mut Vec<AnyElement>, blocks: &mut Vec<AnyElement>) {
+            if !line.is_empty() {
+                blocks.push(
+                    h_flex()
+                        .flex_wrap()
+                        .gap_1()
+                        .children(line.drain(..))
+                        .into_any_element(),
+                );
+            }
+        }
+
+        for segment in segments {
+            match segment {
+                LatexSegment::Text(text) => {
+                    line.push(div().child(text.clone()).into_any_element());
+                }
+                LatexSegment::InlineMath(math) => {
+                    line.push(
+                        div()
+                            .text_sm()
+                            .italic()
+                            .child(math.clone())
+                            .into_any_element(),
+                    );
+                }
+                LatexSegment::DisplayMath(math) => {
+                    flush_line(&mut line, &mut blocks);
+                    blocks.push(
+                        h_flex()
+                            .w_full()
+                            .justify_center()
+                            .py_1()
+                            .text_xl()
+                            .italic()
+                            .child(math.clone())
+                            .into_any_element(),
+                    );
+                }
+            }
+        }
+        flush_line(&mut line, &mut blocks);
+
+        v_flex().gap_1().children(blocks).into_any_element()
+    }
+}
+
+impl OutputContent for LaTeXOutput {
+    fn clipboard_content(&self, _cx: &WindowContext) -> Option<ClipboardItem> {
+        Some(ClipboardItem::new_string(self.source.clone()))
+    }
+
+    fn has_clipboard_content(&self, _cx: &WindowContext) -> bool {
+        true
+    }
+}
+
+/// Converts the math in LaTeX output to Unicode text, or returns `None` if it uses commands
+/// or environments that can't be converted.
+fn convert_latex(source: &str) -> Option<Vec<LatexSegment>> {
+    parse_latex(source)
+        .into_iter()
+        .map(|segment| {
+            Some(match segment {
+                LatexSegment::Text(text) => LatexSegment::Text(text),
+                LatexSegment::InlineMath(math) => {
+                    LatexSegment::InlineMath(latex_to_unicode(&math)?)
+                }
+                LatexSegment::DisplayMath(math) => {
+                    LatexSegment::DisplayMath(latex_to_unicode(&math)?)
+                }
+            })
+        })
+        .collect()
+}
+
+/// Splits LaTeX output into text and the inline and display math within it.
+fn parse_latex(source: &str) -> Vec<LatexSegment> {
+    const DELIMITERS: &[(&str, &str, bool)] = &[
+        ("$$", "$$", true),
+        ("\\[", "\\]", true),
+        ("$", "$", false),
+        ("\\(", "\\)", false),
+    ];
+
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = source;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("\\$") {
+            text.push('$');
+            rest = after;
+            continue;
+        }
+
+        let environment = DISPLAY_ENVIRONMENTS.iter().find_map(|environment| {
+            let inner = rest.strip_prefix(&format!("\\begin{{{environment}}}"))?;
+            let end = format!("\\end{{{environment}}}");
+            let end_ix = inner.find(&end)?;
+            Some((&inner[..end_ix], &inner[end_ix + end.len()..], true))
+        });
+        let math = environment.or_else(|| {
+            DELIMITERS.iter().find_map(|(open, close, display)| {
+                let inner = rest.strip_prefix(open)?;
+                let end_ix = inner.find(close)?;
+                Some((&inner[..end_ix], &inner[end_ix + close.len()..], *display))
+            })
+        });
+
+        if let Some((math, after, display)) = math {
+            let text = std::mem::take(&mut text);
+            if !text.trim().is_empty() {
+                segments.push(LatexSegment::Text(text.trim().to_string()));
+            }
+            let math = math.trim().to_string();
+            segments.push(if display {
+                LatexSegment::DisplayMath(math)
+            } else {
+                LatexSegment::InlineMath(math)
+            });
+            rest = after;
+        } else {
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    if !text.trim().is_empty() {
+        segments.push(LatexSegment::Text(text.trim().to_string()));
+    }
+    segments
+}
+
+/// Converts LaTeX math to Unicode text, or returns `None` if it contains an unknown command.
+fn latex_to_unicode(math: &str) -> Option<String> {
+    let mut output = String::new();
+    let mut rest = math.trim();
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\\' => {
+                let (command, after) = read_command(rest);
+                rest = after;
+                match command {
+                    "frac" | "dfrac" | "tfrac" => {
+                        let (numerator, after) = read_argument(rest);
+                        let (denominator, after) = read_argument(after);
+                        rest = after;
+                        output.push_str(&parenthesize(latex_to_unicode(numerator)?));
+                        output.push('/');
+                        output.push_str(&parenthesize(latex_to_unicode(denominator)?));
+                    }
+                    "sqrt" => {
+                        let (radicand, after) = read_argument(rest);
+                        rest = after;
+                        output.push('√');
+                        output.push_str(&parenthesize(latex_to_unicode(radicand)?));
+                    }
+                    "text" | "textrm" | "mathrm" | "mathbf" | "mathit" | "mathsf" | "mathtt"
+                    | "operatorname" => {
+                        let (content, after) = read_argument(rest);
+                        rest = after;
+                        output.push_str(&latex_to_unicode(content)?);
+                    }
+                    command if IGNORED_COMMANDS.contains(&command) => {
+                        rest = rest.trim_start();
+                    }
+                    command => output.push_str(command_symbol(command)?),
+                }
+            }
+            '^' | '_' => {
+                let (script, after) = read_argument(&rest[1..]);
+                rest = after;
+                let script = latex_to_unicode(script)?;
+                let to_script = if c == '^' { superscript } else { subscript };
+                match script.chars().map(to_script).collect::<Option<String>>() {
+                    Some(mapped) if !mapped.is_empty() => output.push_str(&mapped),
+                    _ => {
+                        output.push(c);
+                        output.push_str(&parenthesize(script));
+                    }
+                }
+            }
+            '{' | '}' => rest = &rest[1..],
+            '&' => rest = &rest[1..],
+            '~' => {
+                output.push(' ');
+                rest = &rest[1..];
+            }
+            c => {
+                output.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    Some(output)
+}
+
+/// Reads the command at the start of `source`, which begins with a backslash: either a run of
+/// letters, such as `\alpha`, or a single other character, such as `\,`.
+fn read_command(source: &str) -> (&str, &str) {
+    let name = &source[1..];
+    let len = match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => name
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(name.len()),
+        Some(c) => c.len_utf8(),
+        None => 0,
+    };
+    (&name[..len], &name[len..])
+}
+
+/// Reads the argument at the start of `source`: a braced group, a command, or a single character.
+fn read_argument(source: &str) -> (&str, &str) {
+    let source = source.trim_start();
+    match source.chars().next() {
+        Some('{') => {
+            let mut depth = 0;
+            for (ix, c) in source.char_indices() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return (&source[1..ix], &source[ix + 1..]);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            (&source[1..], "")
+        }
+        Some('\\') => {
+            let (command, rest) = read_command(source);
+            (&source[..command.len() + 1], rest)
+        }
+        Some(c) => source.split_at(c.len_utf8()),
+        None => ("", ""),
+    }
+}
+
+/// Wraps the text in parentheses, unless it's a single number or identifier.
+fn parenthesize(text: String) -> String {
+    if text.chars().count() <= 1 || text.chars().all(|c| c.is_alphanumeric() || c == '.') {
+        text
+    } else {
+        format!("({text})")
+    }
+}
+
+fn superscript(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '+' => '⁺',
+        '-' | '−' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'i' => 'ⁱ',
+        'n' => 'ⁿ',
+        'T' => 'ᵀ',
+        '′' => '′',
+        _ => return None,
+    })
+}
+
+fn subscript(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '₀',
+        '1' => '₁',
+        '2' => '₂',
+        '3' => '₃',
+        '4' => '₄',
+        '5' => '₅',
+        '6' => '₆',
+        '7' => '₇',
+        '8' => '₈',
+        '9' => '₉',
+        '+' => '₊',
+        '-' | '−' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'i' => 'ᵢ',
+        'j' => 'ⱼ',
+        'k' => 'ₖ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'x' => 'ₓ',
+        _ => return None,
+    })
+}
+
+/// The Unicode text for a command that stands for a symbol or a function name.
+fn command_symbol(command: &str) -> Option<&'static str> {
+    Some(match command {
+        "alpha" => "α",
+        "beta" => "β",
+        "gamma" => "γ",
+        "delta" => "δ",
+        "epsilon" => "ϵ",
+        "varepsilon" => "ε",
+        "zeta" => "ζ",
+        "eta" => "η",
+        "theta" => "θ",
+        "vartheta" => "ϑ",
+        "iota" => "ι",
+        "kappa" => "κ",
+        "lambda" => "λ",
+        "mu" => "μ",
+        "nu" => "ν",
+        "xi" => "ξ",
+        "pi" => "π",
+        "rho" => "ρ",
+        "sigma" => "σ",
+        "tau" => "τ",
+        "upsilon" => "υ",
+        "phi" => "ϕ",
+        "varphi" => "φ",
+        "chi" => "χ",
+        "psi" => "ψ",
+        "omega" => "ω",
+        "Gamma" => "Γ",
+        "Delta" => "Δ",
+        "Theta" => "Θ",
+        "Lambda" => "Λ",
+        "Xi" => "Ξ",
+        "Pi" => "Π",
+        "Sigma" => "Σ",
+        "Upsilon" => "Υ",
+        "Phi" => "Φ",
+        "Psi" => "Ψ",
+        "Omega" => "Ω",
+        "cdot" => "⋅",
+        "times" => "×",
+        "div" => "÷",
+        "pm" => "±",
+        "mp" => "∓",
+        "leq" | "le" => "≤",
+        "geq" | "ge" => "≥",
+        "neq" | "ne" => "≠",
+        "approx" => "≈",
+        "equiv" => "≡",
+        "sim" => "∼",
+        "propto" => "∝",
+        "infty" => "∞",
+        "partial" => "∂",
+        "nabla" => "∇",
+        "sum" => "∑",
+        "prod" => "∏",
+        "int" => "∫",
+        "iint" => "∬",
+        "oint" => "∮",
+        "to" | "rightarrow" => "→",
+        "leftarrow" => "←",
+        "Rightarrow" | "implies" => "⇒",
+        "Leftarrow" => "⇐",
+        "leftrightarrow" => "↔",
+        "Leftrightarrow" | "iff" => "⇔",
+        "mapsto" => "↦",
+        "in" => "∈",
+        "notin" => "∉",
+        "subset" => "⊂",
+        "subseteq" => "⊆",
+        "supset" => "⊃",
+        "supseteq" => "⊇",
+        "cup" => "∪",
+        "cap" => "∩",
+        "emptyset" | "varnothing" => "∅",
+        "forall" => "∀",
+        "exists" => "∃",
+        "neg" => "¬",
+        "land" | "wedge" => "∧",
+        "lor" | "vee" => "∨",
+        "ldots" | "dots" => "…",
+        "cdots" => "⋯",
+        "circ" => "∘",
+        "hbar" => "ℏ",
+        "ell" => "ℓ",
+        "Re" => "ℜ",
+        "Im" => "ℑ",
+        "langle" => "⟨",
+        "rangle" => "⟩",
+        "lfloor" => "⌊",
+        "rfloor" => "⌋",
+        "lceil" => "⌈",
+        "rceil" => "⌉",
+        "prime" => "′",
+        "sin" => "sin",
+        "cos" => "cos",
+        "tan" => "tan",
+        "log" => "log",
+        "ln" => "ln",
+        "exp" => "exp",
+        "lim" => "lim",
+        "max" => "max",
+        "min" => "min",
+        "det" => "det",
+        "quad" => "  ",
+        "qquad" => "    ",
+        "," | ";" | ":" | " " => " ",
+        "\\" => "\n",
+        "{" => "{",
+        "}" => "}",
+        "$" => "$",
+        "%" => "%",
+        "&" => "&",
+        "_" => "_",
+        "#" => "#",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_latex() {
+        assert_eq!(
+            parse_latex(r"The area is $\pi r^2$, so \$5 per $$A = \pi r^{2}$$"),
+            vec![
+                LatexSegment::Text("The area is".into()),
+                LatexSegment::InlineMath(r"\pi r^2".into()),
+                LatexSegment::Text(", so $5 per".into()),
+                LatexSegment::DisplayMath(r"A = \pi r^{2}".into()),
+            ]
+        );
+        assert_eq!(
+            parse_latex(r"\begin{equation*}x + 1\end{equation*} and \(y\)"),
+            vec![
+                LatexSegment::DisplayMath("x + 1".into()),
+                LatexSegment::Text("and".into()),
+                LatexSegment::InlineMath("y".into()),
+            ]
+        );
+        assert_eq!(
+            parse_latex("no math here"),
+            vec![LatexSegment::Text("no math here".into())]
+        );
+    }
+
+    #[test]
+    fn test_latex_to_unicode() {
+        let convert = |math: &str| latex_to_unicode(math).unwrap();
+        assert_eq!(convert(r"\displaystyle x^{2} + 1"), "x² + 1");
+        assert_eq!(convert(r"\alpha_0 \leq \beta_{i}"), "α₀ ≤ βᵢ");
+        assert_eq!(convert(r"\frac{x + 1}{2}"), "(x + 1)/2");
+        assert_eq!(convert(r"\sqrt{2} \cdot \pi"), "√2 ⋅ π");
+        assert_eq!(convert(r"e^{x y}"), "e^(x y)");
+        assert_eq!(convert(r"\left(\sum_{k=1}^{n} k\right)"), "(∑ₖ₌₁ⁿ k)");
+        assert_eq!(latex_to_unicode(r"\mathrm{d}x \unknown"), None);
+        assert_eq!(latex_to_unicode(r"\frac{1}{\unknown}"), None);
+    }
+
+    #[test]
+    fn test_unsupported_environments_are_not_converted() {
+        // `sympy.Matrix([[1, 2], [3, 4]])._repr_latex_()`
+        let matrix = r"$\displaystyle \left[\begin{matrix}1 & 2\\3 & 4\end{matrix}\right]$";
+        assert_eq!(convert_latex(matrix), None);
+
+        // `sympy.Piecewise((x, x > 0), (0, True))._repr_latex_()`
+        let piecewise = r"$\displaystyle \begin{cases} x & \text{for}\: x > 0 \\0 & \text{otherwise} \end{cases}$";
+        assert_eq!(convert_latex(piecewise), None);
+
+        // `(sympy.Symbol("x") ** 2 + 1)._repr_latex_()`
+        assert_eq!(
+            convert_latex(r"$\displaystyle x^{2} + 1$"),
+            Some(vec![LatexSegment::InlineMath("x² + 1".into())])
+        );
+    }
+}
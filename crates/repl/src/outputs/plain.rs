@@ -14,20 +14,26 @@
 //! - Plain text content
 //! - Error tracebacks
 //!
+//! OSC 8 hyperlinks emitted by kernels are underlined and can be clicked to open their URI.
+//!
 
 use alacritty_terminal::{
     grid::Dimensions as _,
     index::{Column, Line, Point},
     term::Config,
-    vte::ansi::Processor,
+    vte::ansi::{Attr, Color as AnsiColor, Handler, NamedColor, Processor},
+};
+use gpui::{
+    canvas, point, size, ClipboardItem, CursorStyle, DispatchPhase, FontStyle, FontWeight,
+    HighlightStyle, Hsla, Model, MouseButton, MouseDownEvent, ScrollHandle, StrikethroughStyle,
+    TextStyle, UnderlineStyle, WhiteSpace,
 };
-use gpui::{canvas, size, ClipboardItem, FontStyle, Model, TextStyle, WhiteSpace};
 use language::Buffer;
 use settings::Settings as _;
-use std::mem;
+use std::{cell::OnceCell, mem, ops::Range};
 use terminal::ZedListener;
-use terminal_view::terminal_element::TerminalElement;
-use theme::ThemeSettings;
+use terminal_view::terminal_element::{convert_color, TerminalElement};
+use theme::{Theme, ThemeSettings};
 use ui::{prelude::*, IntoElement};
 
 use crate::outputs::OutputContent;
@@ -51,6 +57,65 @@ pub struct TerminalOutput {
     parser: Processor,
     /// Alacritty terminal instance that manages the terminal state and content.
     handler: alacritty_terminal::Term<ZedListener>,
+    /// The text content of the terminal, split into lines. Computed on first access
+    /// and invalidated whenever text is appended.
+    text_lines: OnceCell<TextLines>,
+    /// The number of lines shown before the output becomes scrollable. When `None`, the
+    /// output grows to fit all of its lines.
+    max_visible_lines: Option<usize>,
+    /// Scrolls the rendered lines once there are more than `max_visible_lines`.
+    scroll_handle: ScrollHandle,
+}
+
+/// The text of a [`TerminalOutput`], along with the byte offset at which each line starts.
+struct TextLines {
+    text: String,
+    line_starts: Vec<usize>,
+}
+
+impl TextLines {
+    fn new(text: String) -> Self {
+        let line_starts = if text.is_empty() {
+            Vec::new()
+        } else {
+            std::iter::once(0)
+                .chain(text.match_indices('\n').map(|(ix, _)| ix + 1))
+                .collect()
+        };
+        Self { text, line_starts }
+    }
+}
+
+// Line-oriented queries over the cached line starts, for searching the output. Nothing in the
+// UI searches outputs yet.
+#[allow(unused)]
+impl TextLines {
+    fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    fn line_at(&self, index: usize) -> Option<&str> {
+        let start = *self.line_starts.get(index)?;
+        let end = self
+            .line_starts
+            .get(index + 1)
+            .map_or(self.text.len(), |next_start| next_start - 1);
+        Some(&self.text[start..end])
+    }
+
+    fn search_matches(&self, query: &str) -> Vec<(usize, Range<usize>)> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        (0..self.line_count())
+            .filter_map(|index| Some((index, self.line_at(index)?)))
+            .flat_map(|(index, line)| {
+                line.match_indices(query)
+                    .map(move |(start, _)| (index, start..start + query.len()))
+            })
+            .collect()
+    }
 }
 
 const DEFAULT_NUM_LINES: usize = 32;
@@ -82,11 +147,116 @@ pub fn text_style(cx: &mut WindowContext) -> TextStyle {
         underline: None,
         strikethrough: None,
         color: theme.colors().terminal_foreground,
+        letter_spacing: None,
+        word_spacing: None,
+        shadows: Default::default(),
     };
 
     text_style
 }
 
+/// Strips the ANSI escape sequences from a short piece of text, such as an error message,
+/// returning the plain text along with highlights for the colors and styles they set.
+///
+/// Unlike [`TerminalOutput`], this doesn't lay the text out in a terminal grid, so it can
+/// be rendered inline as a [`gpui::StyledText`].
+pub fn ansi_highlights(text: &str, theme: &Theme) -> (String, Vec<(Range<usize>, HighlightStyle)>) {
+    let mut highlighter = AnsiHighlighter {
+        theme,
+        text: String::new(),
+        highlights: Vec::new(),
+        style: HighlightStyle::default(),
+    };
+    let mut parser: Processor = Processor::new();
+    for byte in text.as_bytes() {
+        parser.advance(&mut highlighter, *byte);
+    }
+    (highlighter.text, highlighter.highlights)
+}
+
+struct AnsiHighlighter<'a> {
+    theme: &'a Theme,
+    text: String,
+    highlights: Vec<(Range<usize>, HighlightStyle)>,
+    style: HighlightStyle,
+}
+
+impl AnsiHighlighter<'_> {
+    fn push(&mut self, c: char) {
+        let start = self.text.len();
+        self.text.push(c);
+        let range = start..self.text.len();
+
+        if self.style == HighlightStyle::default() {
+            return;
+        }
+        if let Some((last_range, last_style)) = self.highlights.last_mut() {
+            if last_range.end == range.start && *last_style == self.style {
+                last_range.end = range.end;
+                return;
+            }
+        }
+        self.highlights.push((range, self.style));
+    }
+
+    fn color(&self, color: AnsiColor, default: NamedColor) -> Option<Hsla> {
+        match color {
+            AnsiColor::Named(named) if named == default => None,
+            color => Some(convert_color(&color, self.theme)),
+        }
+    }
+}
+
+impl Handler for AnsiHighlighter<'_> {
+    fn input(&mut self, c: char) {
+        self.push(c);
+    }
+
+    fn linefeed(&mut self) {
+        self.push('\n');
+    }
+
+    fn put_tab(&mut self, count: u16) {
+        for _ in 0..count {
+            self.push('\t');
+        }
+    }
+
+    fn terminal_attribute(&mut self, attr: Attr) {
+        match attr {
+            Attr::Reset => self.style = HighlightStyle::default(),
+            Attr::Bold => self.style.font_weight = Some(FontWeight::BOLD),
+            Attr::Dim => self.style.fade_out = Some(0.3),
+            Attr::Italic => self.style.font_style = Some(FontStyle::Italic),
+            Attr::Underline => {
+                self.style.underline = Some(UnderlineStyle {
+                    thickness: px(1.),
+                    ..Default::default()
+                })
+            }
+            Attr::Strike => {
+                self.style.strikethrough = Some(StrikethroughStyle {
+                    thickness: px(1.),
+                    ..Default::default()
+                })
+            }
+            Attr::CancelBold => self.style.font_weight = None,
+            Attr::CancelBoldDim => {
+                self.style.font_weight = None;
+                self.style.fade_out = None;
+            }
+            Attr::CancelItalic => self.style.font_style = None,
+            Attr::CancelUnderline => self.style.underline = None,
+            Attr::CancelStrike => self.style.strikethrough = None,
+            Attr::Foreground(color) => self.style.color = self.color(color, NamedColor::Foreground),
+            Attr::Background(color) => {
+                self.style.background_color = self.color(color, NamedColor::Background)
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Returns the default terminal size for the terminal output.
 pub fn terminal_size(cx: &mut WindowContext) -> terminal::TerminalSize {
     let text_style = text_style(cx);
@@ -135,6 +305,9 @@ impl TerminalOutput {
             parser: Processor::new(),
             handler: term,
             full_buffer: None,
+            text_lines: OnceCell::new(),
+            max_visible_lines: None,
+            scroll_handle: ScrollHandle::new(),
         }
     }
 
@@ -192,6 +365,7 @@ impl TerminalOutput {
                 self.parser.advance(&mut self.handler, *byte);
             }
         }
+        self.text_lines.take();
 
         // This will keep the buffer up to date, though with some terminal codes it won't be perfect
         if let Some(buffer) = self.full_buffer.as_ref() {
@@ -201,6 +375,67 @@ impl TerminalOutput {
         }
     }
 
+    /// Limits the height of the output to the given number of lines, scrolling the rest.
+    pub fn set_max_visible_lines(&mut self, max_visible_lines: Option<usize>) {
+        self.max_visible_lines = max_visible_lines;
+    }
+
+    /// Whether the output is limited to a number of lines and can be scrolled.
+    pub fn is_scrollable(&self) -> bool {
+        self.max_visible_lines.is_some()
+    }
+
+    /// Scrolls the output so that its last line is visible.
+    ///
+    /// Does nothing unless the output is [scrollable](Self::is_scrollable).
+    pub fn scroll_to_bottom(&self, cx: &mut WindowContext) {
+        if !self.is_scrollable() {
+            return;
+        }
+        // The offset is clamped to the scrollable height when the output is laid out.
+        self.scroll_handle.set_offset(point(px(0.), -Pixels::MAX));
+        cx.refresh();
+    }
+
+    /// Scrolls the output so that the given line of the rendered output is at the top.
+    ///
+    /// Does nothing unless the output is [scrollable](Self::is_scrollable).
+    pub fn scroll_to_line(&self, line: usize, cx: &mut WindowContext) {
+        if !self.is_scrollable() {
+            return;
+        }
+        let line_height = text_style(cx).line_height_in_pixels(cx.rem_size());
+        self.scroll_handle
+            .set_offset(point(px(0.), -(line_height * line as f32)));
+        cx.refresh();
+    }
+
+    /// Returns the output as plain text, as it appears on screen.
+    ///
+    /// The text is read back from the terminal grid, so ANSI escape sequences (SGR, CSI, OSC,
+    /// and so on) have already been interpreted by the terminal parser and never appear in it.
+    pub fn export_as_text(&self) -> String {
+        self.text_lines().text.clone()
+    }
+
+    /// Returns the on-screen cells that are part of an OSC 8 hyperlink, along with the
+    /// link's URI. The escape sequences themselves are consumed by the terminal parser.
+    fn hyperlinks(&self) -> Vec<(Point, SharedString)> {
+        self.handler
+            .renderable_content()
+            .display_iter
+            .filter_map(|indexed| {
+                let uri = indexed.cell.hyperlink()?.uri().to_string();
+                Some((indexed.point, uri.into()))
+            })
+            .collect()
+    }
+
+    fn text_lines(&self) -> &TextLines {
+        self.text_lines
+            .get_or_init(|| TextLines::new(self.full_text()))
+    }
+
     fn full_text(&self) -> String {
         let mut full_text = String::new();
 
@@ -273,11 +508,13 @@ impl Render for TerminalOutput {
             .map(|advance| advance.width)
             .unwrap_or(Pixels(0.0));
 
-        canvas(
+        let hyperlinks = self.hyperlinks();
+
+        let terminal = canvas(
             // prepaint
-            move |_bounds, _| {},
+            move |bounds, cx| cx.insert_hitbox(bounds, false),
             // paint
-            move |bounds, _, cx| {
+            move |bounds, hitbox, cx| {
                 for rect in rects {
                     rect.paint(
                         bounds.origin,
@@ -302,16 +539,86 @@ impl Render for TerminalOutput {
                         cx,
                     );
                 }
+
+                if hyperlinks.is_empty() {
+                    return;
+                }
+
+                let origin = bounds.origin;
+                let is_hovering_link = hitbox.is_hovered(cx)
+                    && hyperlink_at(
+                        &hyperlinks,
+                        cx.mouse_position(),
+                        origin,
+                        cell_width,
+                        text_line_height,
+                    )
+                    .is_some();
+                if is_hovering_link {
+                    cx.set_cursor_style(CursorStyle::PointingHand, &hitbox);
+                }
+
+                cx.on_mouse_event(move |event: &MouseDownEvent, phase, cx| {
+                    if phase != DispatchPhase::Bubble
+                        || event.button != MouseButton::Left
+                        || !hitbox.is_hovered(cx)
+                    {
+                        return;
+                    }
+                    if let Some(uri) = hyperlink_at(
+                        &hyperlinks,
+                        event.position,
+                        origin,
+                        cell_width,
+                        text_line_height,
+                    ) {
+                        cx.open_url(&uri);
+                        cx.stop_propagation();
+                    }
+                });
             },
         )
         // We must set the height explicitly for the editor block to size itself correctly
-        .h(height)
+        .h(height);
+
+        let Some(max_visible_lines) = self.max_visible_lines else {
+            return terminal.into_any_element();
+        };
+
+        div()
+            .id("terminal-output")
+            .max_h(max_visible_lines as f32 * text_line_height)
+            .overflow_y_scroll()
+            .track_scroll(&self.scroll_handle)
+            .child(terminal)
+            .into_any_element()
+    }
+}
+
+/// Finds the hyperlink in the cell under `position`, given the origin of the rendered grid
+/// and the size of its cells.
+fn hyperlink_at(
+    hyperlinks: &[(Point, SharedString)],
+    position: gpui::Point<Pixels>,
+    origin: gpui::Point<Pixels>,
+    cell_width: Pixels,
+    line_height: Pixels,
+) -> Option<SharedString> {
+    if position.x < origin.x || position.y < origin.y || cell_width <= px(0.) {
+        return None;
     }
+
+    let column = Column(((position.x - origin.x) / cell_width) as usize);
+    let line = Line(((position.y - origin.y) / line_height) as i32);
+    hyperlinks
+        .iter()
+        .find(|(point, _)| point.line == line && point.column == column)
+        .map(|(_, uri)| uri.clone())
 }
 
 impl OutputContent for TerminalOutput {
     fn clipboard_content(&self, _cx: &WindowContext) -> Option<ClipboardItem> {
-        Some(ClipboardItem::new_string(self.full_text()))
+        Some(ClipboardItem::new_string(self.export_as_text()))
     }
 
     fn has_clipboard_content(&self, _cx: &WindowContext) -> bool {
@@ -328,8 +635,8 @@ impl OutputContent for TerminalOutput {
         }
 
         let buffer = cx.new_model(|cx| {
-            let mut buffer =
-                Buffer::local(self.full_text(), cx).with_language(language::PLAIN_TEXT.clone(), cx);
+            let mut buffer = Buffer::local(self.export_as_text(), cx)
+                .with_language(language::PLAIN_TEXT.clone(), cx);
             buffer.set_capability(language::Capability::ReadOnly, cx);
             buffer
         });
@@ -338,3 +645,184 @@ impl OutputContent for TerminalOutput {
         Some(buffer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+    use settings::SettingsStore;
+
+    fn init_test(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+        });
+    }
+
+    #[test]
+    fn test_text_lines() {
+        let lines = TextLines::new("foo bar\n\nbaz foo".to_string());
+        assert_eq!(lines.line_count(), 3);
+        assert_eq!(lines.line_at(0), Some("foo bar"));
+        assert_eq!(lines.line_at(1), Some(""));
+        assert_eq!(lines.line_at(2), Some("baz foo"));
+        assert_eq!(lines.line_at(3), None);
+
+        assert_eq!(lines.search_matches("foo"), vec![(0, 0..3), (2, 4..7)]);
+        assert_eq!(lines.search_matches(""), vec![]);
+
+        let empty = TextLines::new(String::new());
+        assert_eq!(empty.line_count(), 0);
+        assert_eq!(empty.line_at(0), None);
+    }
+
+    #[gpui::test]
+    fn test_export_as_text(cx: &mut TestAppContext) {
+        init_test(cx);
+        let cx = cx.add_empty_window();
+
+        let output = cx.update(|cx| {
+            TerminalOutput::from(
+                "\x1b[1;31merror\x1b[0m: see \x1b]8;;file:///a.py\x1b\\a.py\x1b]8;;\x1b\\\nok",
+                cx,
+            )
+        });
+        assert_eq!(output.export_as_text(), "error: see a.py\nok");
+    }
+
+    #[gpui::test]
+    fn test_scrolling_is_opt_in(cx: &mut TestAppContext) {
+        init_test(cx);
+        let cx = cx.add_empty_window();
+
+        let text = (0..40)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut output = cx.update(|cx| TerminalOutput::from(&text, cx));
+        assert!(!output.is_scrollable());
+
+        cx.update(|cx| {
+            output.scroll_to_bottom(cx);
+            output.scroll_to_line(10, cx);
+        });
+        assert_eq!(output.scroll_handle.offset(), point(px(0.), px(0.)));
+
+        output.set_max_visible_lines(Some(16));
+        assert!(output.is_scrollable());
+        cx.update(|cx| output.scroll_to_bottom(cx));
+        assert_eq!(output.scroll_handle.offset(), point(px(0.), -Pixels::MAX));
+
+        cx.update(|cx| {
+            let line_height = text_style(cx).line_height_in_pixels(cx.rem_size());
+            output.scroll_to_line(10, cx);
+            assert_eq!(
+                output.scroll_handle.offset(),
+                point(px(0.), -(line_height * 10.))
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_scrolls_to_appended_lines(cx: &mut TestAppContext) {
+        init_test(cx);
+        let cx = cx.add_empty_window();
+
+        let output = cx.new_view(|cx| {
+            let mut output = TerminalOutput::from("0\n1", cx);
+            output.set_max_visible_lines(Some(4));
+            output
+        });
+        let draw = |cx: &mut gpui::VisualTestContext| {
+            let output = output.clone();
+            cx.draw(point(px(0.), px(0.)), size(px(1000.), px(1000.)), |_| {
+                output
+            });
+        };
+        let offset = |cx: &mut gpui::VisualTestContext| {
+            cx.update(|cx| output.read(cx).scroll_handle.offset())
+        };
+
+        draw(cx);
+        assert_eq!(offset(cx), point(px(0.), px(0.)));
+
+        // Appending stream output scrolls to its last line, which is clamped to the
+        // height of the lines beyond the limit once laid out.
+        output.update(cx, |output, cx| {
+            output.append_text("\n2\n3\n4\n5\n6\n7\n8\n9", cx);
+            output.scroll_to_bottom(cx);
+        });
+        draw(cx);
+        let line_height = cx.update(|cx| text_style(cx).line_height_in_pixels(cx.rem_size()));
+        assert_eq!(offset(cx), point(px(0.), -(line_height * 6.)));
+    }
+
+    #[gpui::test]
+    fn test_ansi_highlights(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        cx.update(|cx| {
+            let theme = cx.theme();
+            let (text, highlights) = ansi_highlights(
+                "\x1b[1;31mKeyError\x1b[0m: 'a' \x1b[33mwarn\x1b[39m done",
+                theme,
+            );
+            assert_eq!(text, "KeyError: 'a' warn done");
+            assert_eq!(
+                highlights,
+                vec![
+                    (
+                        0..8,
+                        HighlightStyle {
+                            color: Some(theme.colors().terminal_ansi_red),
+                            font_weight: Some(FontWeight::BOLD),
+                            ..Default::default()
+                        }
+                    ),
+                    (
+                        14..18,
+                        HighlightStyle {
+                            color: Some(theme.colors().terminal_ansi_yellow),
+                            ..Default::default()
+                        }
+                    ),
+                ]
+            );
+
+            let (text, highlights) = ansi_highlights("plain", theme);
+            assert_eq!(text, "plain");
+            assert!(highlights.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_hyperlink_at() {
+        let hyperlinks = vec![
+            (
+                Point::new(Line(1), Column(2)),
+                SharedString::from("file:///a.py"),
+            ),
+            (
+                Point::new(Line(1), Column(3)),
+                SharedString::from("file:///a.py"),
+            ),
+        ];
+        let origin = point(px(10.), px(20.));
+        let link_at = |x: f32, y: f32| {
+            hyperlink_at(&hyperlinks, point(px(x), px(y)), origin, px(8.), px(16.))
+        };
+
+        assert_eq!(
+            link_at(10. + 8. * 2.5, 20. + 16. * 1.5).as_deref(),
+            Some("file:///a.py")
+        );
+        assert_eq!(
+            link_at(10. + 8. * 3.9, 20. + 16. * 1.1).as_deref(),
+            Some("file:///a.py")
+        );
+        assert_eq!(link_at(10. + 8. * 4.1, 20. + 16. * 1.5), None);
+        assert_eq!(link_at(10. + 8. * 2.5, 20. + 16. * 0.5), None);
+        assert_eq!(link_at(5., 30.), None);
+    }
+}
@@ -101,6 +101,8 @@ impl TableView {
             background_color: None,
             underline: None,
             strikethrough: None,
+            letter_spacing: None,
+            word_spacing: None,
         }];
 
         for field in table.schema.fields.iter() {
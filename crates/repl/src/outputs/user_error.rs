@@ -1,16 +1,53 @@
-use gpui::{AnyElement, FontWeight, View, WindowContext};
-use ui::{h_flex, prelude::*, v_flex, Label};
+use std::rc::Rc;
 
-use crate::outputs::plain::TerminalOutput;
+use gpui::{AnyElement, FontWeight, StyledText, TextStyle, View, VisualContext, WindowContext};
+use ui::{h_flex, prelude::*, tooltip_container, v_flex, Label};
+
+use crate::outputs::plain::{self, ansi_highlights, TerminalOutput};
+
+/// The local variables of the innermost frame of an error, as fetched from the kernel.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ErrorLocals {
+    #[default]
+    NotRequested,
+    Loading,
+    /// Variable names paired with the `repr` of their values.
+    Loaded(Vec<(String, String)>),
+    Failed(String),
+}
 
 /// Userspace error from the kernel
+#[derive(Clone)]
 pub struct ErrorView {
     pub ename: String,
     pub evalue: String,
     pub traceback: View<TerminalOutput>,
+    pub show_locals: bool,
+    pub locals: ErrorLocals,
+    /// Called when the "Show locals" button is clicked. The button is only
+    /// shown for kernels that can report the locals of a failing frame.
+    pub on_toggle_locals: Option<Rc<dyn Fn(&mut WindowContext)>>,
+    /// Whether the error is shown on a single line by [`ErrorView::render_compact`],
+    /// as it is in the inline outputs of an editor.
+    pub compact: bool,
+    /// Called when the compact error is clicked, to show the full error in its place.
+    pub on_expand: Option<Rc<dyn Fn(&mut WindowContext)>>,
 }
 
 impl ErrorView {
+    pub fn new(ename: String, evalue: String, traceback: View<TerminalOutput>) -> Self {
+        Self {
+            ename,
+            evalue,
+            traceback,
+            show_locals: false,
+            locals: ErrorLocals::NotRequested,
+            on_toggle_locals: None,
+            compact: false,
+            on_expand: None,
+        }
+    }
+
     pub fn render(&self, cx: &mut WindowContext) -> Option<AnyElement> {
         let theme = cx.theme();
 
@@ -28,11 +65,7 @@ impl ErrorView {
                                 .color(Color::Error)
                                 .weight(FontWeight::BOLD),
                         )
-                        .child(
-                            Label::new(self.evalue.clone())
-                                // .size(LabelSize::Large)
-                                .weight(FontWeight::BOLD),
-                        ),
+                        .child(self.render_evalue(cx)),
                 )
                 .child(
                     div()
@@ -43,7 +76,115 @@ impl ErrorView {
                         .border_color(theme.status().error_border)
                         .child(self.traceback.clone()),
                 )
+                .when_some(self.on_toggle_locals.clone(), |this, on_toggle_locals| {
+                    this.child(
+                        h_flex().child(
+                            Button::new(
+                                "toggle-locals",
+                                if self.show_locals {
+                                    "Hide locals"
+                                } else {
+                                    "Show locals"
+                                },
+                            )
+                            .label_size(LabelSize::Small)
+                            .on_click(move |_, cx| on_toggle_locals(cx)),
+                        ),
+                    )
+                })
+                .when(self.show_locals, |this| {
+                    this.child(self.render_locals(padding, cx))
+                })
+                .into_any_element(),
+        )
+    }
+
+    /// Renders only `ename: evalue` on a single line, for places where the full
+    /// traceback doesn't fit. Hovering it shows the full error in a tooltip.
+    pub fn render_compact(&self, cx: &mut WindowContext) -> Option<AnyElement> {
+        let (evalue, _) = ansi_highlights(&self.evalue, cx.theme());
+        let error = self.clone();
+
+        Some(
+            h_flex()
+                .id("compact-error")
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .font_buffer(cx)
+                .child(
+                    Label::new(format!("{}: ", self.ename))
+                        .color(Color::Error)
+                        .weight(FontWeight::BOLD),
+                )
+                .child(Label::new(
+                    evalue.lines().next().unwrap_or_default().to_string(),
+                ))
+                .tooltip(move |cx| {
+                    let error = error.clone();
+                    cx.new_view(|_| ErrorTooltip { error }).into()
+                })
+                .when_some(self.on_expand.clone(), |this, on_expand| {
+                    this.cursor_pointer().on_click(move |_, cx| on_expand(cx))
+                })
                 .into_any_element(),
         )
     }
+
+    /// Renders the error value in bold, in the colors set by any ANSI escape sequences in it,
+    /// such as those of tracebacks formatted by `rich`.
+    fn render_evalue(&self, cx: &mut WindowContext) -> StyledText {
+        let (evalue, highlights) = ansi_highlights(&self.evalue, cx.theme());
+        let text_style = TextStyle {
+            color: cx.theme().colors().text,
+            background_color: None,
+            font_weight: FontWeight::BOLD,
+            ..plain::text_style(cx)
+        };
+        StyledText::new(evalue).with_highlights(&text_style, highlights)
+    }
+
+    fn render_locals(&self, padding: Pixels, cx: &mut WindowContext) -> AnyElement {
+        match &self.locals {
+            ErrorLocals::NotRequested => div().into_any_element(),
+            ErrorLocals::Loading => Label::new("Fetching locals...")
+                .color(Color::Muted)
+                .into_any_element(),
+            ErrorLocals::Failed(error) => Label::new(format!("Unable to fetch locals: {error}"))
+                .color(Color::Error)
+                .into_any_element(),
+            ErrorLocals::Loaded(locals) if locals.is_empty() => Label::new("No local variables")
+                .color(Color::Muted)
+                .into_any_element(),
+            ErrorLocals::Loaded(locals) => v_flex()
+                .w_full()
+                .px(padding)
+                .border_l_1()
+                .border_color(cx.theme().colors().border)
+                .font_buffer(cx)
+                .children(locals.iter().map(|(name, value)| {
+                    h_flex()
+                        .gap_2()
+                        .items_start()
+                        .child(
+                            div()
+                                .flex_none()
+                                .min_w_32()
+                                .child(Label::new(name.clone()).color(Color::Muted)),
+                        )
+                        .child(div().flex_1().child(Label::new(value.clone())))
+                }))
+                .into_any_element(),
+        }
+    }
+}
+
+/// Shows the full error when hovering over [`ErrorView::render_compact`].
+struct ErrorTooltip {
+    error: ErrorView,
+}
+
+impl Render for ErrorTooltip {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        tooltip_container(cx, |this, cx| this.children(self.error.render(cx)))
+    }
 }
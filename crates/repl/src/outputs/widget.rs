@@ -0,0 +1,137 @@
+use gpui::{relative, Hsla};
+use serde_json::{Map, Value};
+use ui::{h_flex, prelude::*};
+
+/// A progress bar backed by an `ipywidgets` `IntProgress` or `FloatProgress`
+/// model, kept in sync with the kernel through its comm.
+pub struct ProgressWidget {
+    value: f64,
+    min: f64,
+    max: f64,
+    description: String,
+    bar_style: String,
+}
+
+impl ProgressWidget {
+    pub fn new(state: &Map<String, Value>) -> Self {
+        let mut widget = Self {
+            value: 0.,
+            min: 0.,
+            max: 100.,
+            description: String::new(),
+            bar_style: String::new(),
+        };
+        widget.apply_state(state);
+        widget
+    }
+
+    /// Whether the given widget model state can be rendered as a [`ProgressWidget`].
+    pub fn supports_model(state: &Map<String, Value>) -> bool {
+        matches!(
+            state.get("_model_name").and_then(Value::as_str),
+            Some("IntProgressModel" | "FloatProgressModel")
+        )
+    }
+
+    /// Applies a full or partial widget model state, as sent by `comm_open` and `comm_msg`.
+    pub fn apply_state(&mut self, state: &Map<String, Value>) {
+        let number = |key: &str| state.get(key).and_then(Value::as_f64);
+        let string = |key: &str| state.get(key).and_then(Value::as_str).map(str::to_string);
+
+        if let Some(value) = number("value") {
+            self.value = value;
+        }
+        if let Some(min) = number("min") {
+            self.min = min;
+        }
+        if let Some(max) = number("max") {
+            self.max = max;
+        }
+        if let Some(description) = string("description") {
+            self.description = description;
+        }
+        if let Some(bar_style) = string("bar_style") {
+            self.bar_style = bar_style;
+        }
+    }
+
+    fn fraction(&self) -> f32 {
+        if self.max <= self.min {
+            return 0.;
+        }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0., 1.) as f32
+    }
+
+    fn bar_color(&self, cx: &WindowContext) -> Hsla {
+        let status = cx.theme().status();
+        match self.bar_style.as_str() {
+            "success" => status.success,
+            "info" => status.info,
+            "warning" => status.warning,
+            "danger" => status.error,
+            _ => cx.theme().colors().text_accent,
+        }
+    }
+}
+
+impl Render for ProgressWidget {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .gap_2()
+            .when(!self.description.is_empty(), |this| {
+                this.child(Label::new(self.description.clone()))
+            })
+            .child(
+                div()
+                    .w_64()
+                    .h_2()
+                    .rounded_sm()
+                    .bg(cx.theme().colors().element_background)
+                    .child(
+                        div()
+                            .h_full()
+                            .w(relative(self.fraction()))
+                            .rounded_sm()
+                            .bg(self.bar_color(cx)),
+                    ),
+            )
+            .child(
+                Label::new(format!("{}/{}", self.value, self.max))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_progress_widget_state() {
+        let state = json!({
+            "_model_name": "IntProgressModel",
+            "value": 3,
+            "max": 12,
+            "description": "Loading:",
+        });
+        let state = state.as_object().unwrap();
+        assert!(ProgressWidget::supports_model(state));
+
+        let mut widget = ProgressWidget::new(state);
+        assert_eq!(widget.fraction(), 0.25);
+        assert_eq!(widget.description, "Loading:");
+
+        widget.apply_state(json!({ "value": 20 }).as_object().unwrap());
+        assert_eq!(widget.fraction(), 1.);
+        assert_eq!(widget.max, 12.);
+
+        assert!(!ProgressWidget::supports_model(
+            json!({ "_model_name": "IntSliderModel" })
+                .as_object()
+                .unwrap()
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+mod comm;
 pub mod components;
 mod jupyter_settings;
 pub mod kernels;
@@ -20,7 +21,8 @@ pub use crate::jupyter_settings::JupyterSettings;
 pub use crate::kernels::{Kernel, KernelSpecification, KernelStatus};
 pub use crate::repl_editor::*;
 pub use crate::repl_sessions_ui::{
-    ClearOutputs, Interrupt, ReplSessionsPage, Restart, Run, Sessions, Shutdown,
+    ClearOutputs, Interrupt, ReplSessionsPage, Restart, Run, RunAllCells, RunCellsAbove, Sessions,
+    Shutdown,
 };
 use crate::repl_store::ReplStore;
 pub use crate::session::Session;
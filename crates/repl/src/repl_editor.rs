@@ -5,10 +5,11 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use editor::Editor;
-use gpui::{prelude::*, Entity, View, WeakView, WindowContext};
+use gpui::{prelude::*, AppContext, Entity, View, WeakView, WindowContext};
 use language::{BufferSnapshot, Language, LanguageName, Point};
 use project::{ProjectItem as _, WorktreeId};
 
+use crate::kernels::RunningKernelInfo;
 use crate::repl_store::ReplStore;
 use crate::session::SessionEvent;
 use crate::{
@@ -70,6 +71,84 @@ pub fn assign_kernelspec(
     Ok(())
 }
 
+/// Lists the running kernels that an editor can be reconnected to with
+/// [`reconnect_kernel`].
+pub fn reconnectable_kernels(cx: &AppContext) -> Vec<RunningKernelInfo> {
+    let store = ReplStore::global(cx);
+    let store = store.read(cx);
+    store.reconnectable_kernels(cx).cloned().collect()
+}
+
+/// Starts a session for the editor that is connected to a kernel which is
+/// already running, rather than launching a new one.
+pub fn reconnect_kernel(
+    running_kernel: RunningKernelInfo,
+    weak_editor: WeakView<Editor>,
+    cx: &mut WindowContext,
+) -> Result<View<Session>> {
+    let store = ReplStore::global(cx);
+    anyhow::ensure!(store.read(cx).is_enabled(), "the REPL is disabled");
+
+    let worktree_id = crate::repl_editor::worktree_id_for_editor(weak_editor.clone(), cx)
+        .context("editor is not in a worktree")?;
+
+    let kernel_specification = store
+        .read(cx)
+        .kernelspec_for_running_kernel(&running_kernel)
+        .or_else(|| store.read(cx).active_kernelspec(worktree_id, None, cx))
+        .with_context(|| {
+            format!(
+                "no kernelspec found for the kernel at {:?}",
+                running_kernel.connection_file
+            )
+        })?;
+
+    let fs = store.read(cx).fs().clone();
+
+    if let Some(session) = store.read(cx).get_session(weak_editor.entity_id()).cloned() {
+        // Drop previous session, start new one
+        session.update(cx, |session, cx| {
+            session.clear_outputs(cx);
+            session.shutdown(cx);
+            cx.notify();
+        });
+    }
+
+    let session = cx.new_view(|cx| {
+        Session::reconnect(
+            weak_editor.clone(),
+            fs,
+            kernel_specification,
+            running_kernel,
+            cx,
+        )
+    });
+
+    weak_editor
+        .update(cx, |_editor, cx| {
+            cx.notify();
+
+            cx.subscribe(&session, {
+                let store = store.clone();
+                move |_this, _session, event, cx| match event {
+                    SessionEvent::Shutdown(shutdown_event) => {
+                        store.update(cx, |store, _cx| {
+                            store.remove_session(shutdown_event.entity_id());
+                        });
+                    }
+                }
+            })
+            .detach();
+        })
+        .ok();
+
+    store.update(cx, |store, _cx| {
+        store.insert_session(weak_editor.entity_id(), session.clone());
+    });
+
+    Ok(session)
+}
+
 pub fn run(editor: WeakView<Editor>, move_down: bool, cx: &mut WindowContext) -> Result<()> {
     let store = ReplStore::global(cx);
     if !store.read(cx).is_enabled() {
@@ -102,37 +181,7 @@ pub fn run(editor: WeakView<Editor>, move_down: bool, cx: &mut WindowContext) ->
             .active_kernelspec(project_path.worktree_id, Some(language.clone()), cx)
             .ok_or_else(|| anyhow::anyhow!("No kernel found for language: {}", language.name()))?;
 
-        let fs = store.read(cx).fs().clone();
-
-        let session = if let Some(session) = store.read(cx).get_session(editor.entity_id()).cloned()
-        {
-            session
-        } else {
-            let weak_editor = editor.downgrade();
-            let session = cx.new_view(|cx| Session::new(weak_editor, fs, kernel_specification, cx));
-
-            editor.update(cx, |_editor, cx| {
-                cx.notify();
-
-                cx.subscribe(&session, {
-                    let store = store.clone();
-                    move |_this, _session, event, cx| match event {
-                        SessionEvent::Shutdown(shutdown_event) => {
-                            store.update(cx, |store, _cx| {
-                                store.remove_session(shutdown_event.entity_id());
-                            });
-                        }
-                    }
-                })
-                .detach();
-            });
-
-            store.update(cx, |store, _cx| {
-                store.insert_session(editor.entity_id(), session.clone());
-            });
-
-            session
-        };
+        let session = get_or_start_session(&editor, kernel_specification, cx);
 
         let selected_text;
         let anchor_range;
@@ -155,6 +204,110 @@ pub fn run(editor: WeakView<Editor>, move_down: bool, cx: &mut WindowContext) ->
     anyhow::Ok(())
 }
 
+/// Runs every cell of the editor from top to bottom, one after another.
+pub fn run_all_cells(editor: WeakView<Editor>, cx: &mut WindowContext) -> Result<()> {
+    let Some((session, _)) = session_for_cells(editor, cx)? else {
+        return Ok(());
+    };
+    session.update(cx, |session, cx| session.run_all_cells(cx));
+    Ok(())
+}
+
+/// Runs the cells of the editor above the cell containing the cursor, one after another.
+pub fn run_cells_above(editor: WeakView<Editor>, cx: &mut WindowContext) -> Result<()> {
+    let Some((session, editor)) = session_for_cells(editor, cx)? else {
+        return Ok(());
+    };
+    let cursor = editor
+        .update(cx, |editor, cx| editor.selections.newest_adjusted(cx))
+        .head();
+    let Some(buffer) = editor.read(cx).buffer().read(cx).as_singleton() else {
+        return Ok(());
+    };
+    let cell_index = cell_ranges(&buffer.read(cx).snapshot())
+        .iter()
+        .rposition(|range| range.start <= cursor)
+        .unwrap_or(0);
+
+    session.update(cx, |session, cx| session.run_cells_above(cell_index, cx));
+    Ok(())
+}
+
+/// Returns the editor's session, starting one for the language of its first cell if needed.
+fn session_for_cells(
+    editor: WeakView<Editor>,
+    cx: &mut WindowContext,
+) -> Result<Option<(View<Session>, View<Editor>)>> {
+    let store = ReplStore::global(cx);
+    if !store.read(cx).is_enabled() {
+        return Ok(None);
+    }
+
+    let editor = editor.upgrade().context("editor was dropped")?;
+    if let Some(session) = store.read(cx).get_session(editor.entity_id()).cloned() {
+        return Ok(Some((session, editor)));
+    }
+
+    let multibuffer = editor.read(cx).buffer().clone();
+    let Some(buffer) = multibuffer.read(cx).as_singleton() else {
+        return Ok(None);
+    };
+    let Some(project_path) = buffer.read(cx).project_path(cx) else {
+        return Ok(None);
+    };
+    let Some(first_cell) = cell_ranges(&buffer.read(cx).snapshot()).into_iter().next() else {
+        return Ok(None);
+    };
+    let Some(language) = multibuffer.read(cx).language_at(first_cell.start, cx) else {
+        return Ok(None);
+    };
+
+    let kernel_specification = store
+        .read(cx)
+        .active_kernelspec(project_path.worktree_id, Some(language.clone()), cx)
+        .ok_or_else(|| anyhow::anyhow!("No kernel found for language: {}", language.name()))?;
+
+    let session = get_or_start_session(&editor, kernel_specification, cx);
+    Ok(Some((session, editor)))
+}
+
+fn get_or_start_session(
+    editor: &View<Editor>,
+    kernel_specification: KernelSpecification,
+    cx: &mut WindowContext,
+) -> View<Session> {
+    let store = ReplStore::global(cx);
+    if let Some(session) = store.read(cx).get_session(editor.entity_id()).cloned() {
+        return session;
+    }
+
+    let fs = store.read(cx).fs().clone();
+    let weak_editor = editor.downgrade();
+    let session = cx.new_view(|cx| Session::new(weak_editor, fs, kernel_specification, cx));
+
+    editor.update(cx, |_editor, cx| {
+        cx.notify();
+
+        cx.subscribe(&session, {
+            let store = store.clone();
+            move |_this, _session, event, cx| match event {
+                SessionEvent::Shutdown(shutdown_event) => {
+                    store.update(cx, |store, _cx| {
+                        store.remove_session(shutdown_event.entity_id());
+                    });
+                }
+            }
+        })
+        .detach();
+    });
+
+    store.update(cx, |store, _cx| {
+        store.insert_session(editor.entity_id(), session.clone());
+    });
+
+    session
+}
+
 #[allow(clippy::large_enum_variant)]
 pub enum SessionSupport {
     ActiveSession(View<Session>),
@@ -341,21 +494,11 @@ fn jupytext_cells(
 ) -> (Vec<Range<Point>>, Option<Point>) {
     let mut current_row = range.start.row;
 
-    let Some(language) = buffer.language() else {
-        return (Vec::new(), None);
-    };
-
-    let default_scope = language.default_scope();
-    let comment_prefixes = default_scope.line_comment_prefixes();
-    if comment_prefixes.is_empty() {
+    let jupytext_prefixes = jupytext_prefixes(buffer);
+    if jupytext_prefixes.is_empty() {
         return (Vec::new(), None);
     }
 
-    let jupytext_prefixes = comment_prefixes
-        .iter()
-        .map(|comment_prefix| format!("{comment_prefix}%%"))
-        .collect::<Vec<_>>();
-
     let mut snippet_start_row = None;
     loop {
         if jupytext_prefixes
@@ -400,6 +543,57 @@ fn jupytext_cells(
     (snippets, None)
 }
 
+/// Returns the markers that start a Jupytext cell, such as `# %%`, in the buffer's language.
+fn jupytext_prefixes(buffer: &BufferSnapshot) -> Vec<String> {
+    let Some(language) = buffer.language() else {
+        return Vec::new();
+    };
+
+    language
+        .default_scope()
+        .line_comment_prefixes()
+        .iter()
+        .map(|comment_prefix| format!("{comment_prefix}%%"))
+        .collect()
+}
+
+/// Returns the ranges of all the cells in the buffer, in order: the code blocks of a
+/// Markdown buffer, the Jupytext cells of a buffer with `%%` markers, or otherwise the
+/// whole buffer as a single cell.
+pub(crate) fn cell_ranges(buffer: &BufferSnapshot) -> Vec<Range<Point>> {
+    let max_point = buffer.max_point();
+    if let Some(language) = buffer.language() {
+        if language.name() == "Markdown".into() {
+            return markdown_code_blocks(buffer, Point::zero()..max_point);
+        }
+    }
+
+    let jupytext_prefixes = jupytext_prefixes(buffer);
+    let mut start_rows = (0..=max_point.row)
+        .filter(|row| {
+            jupytext_prefixes
+                .iter()
+                .any(|prefix| buffer.contains_str_at(Point::new(*row, 0), prefix))
+        })
+        .collect::<Vec<_>>();
+    // Code before the first marker (or in a buffer without markers) is a cell of its own.
+    let first_marker_row = start_rows.first().copied().unwrap_or(max_point.row + 1);
+    if (0..first_marker_row).any(|row| !buffer.is_line_blank(row)) {
+        start_rows.insert(0, 0);
+    }
+
+    start_rows
+        .iter()
+        .enumerate()
+        .map(|(ix, start_row)| {
+            let end_row = start_rows
+                .get(ix + 1)
+                .map_or(max_point.row, |next_start_row| next_start_row - 1);
+            cell_range(buffer, *start_row, end_row)
+        })
+        .collect()
+}
+
 fn runnable_ranges(
     buffer: &BufferSnapshot,
     range: Range<Point>,
@@ -610,6 +804,54 @@ mod tests {
         );
     }
 
+    #[gpui::test]
+    fn test_cell_ranges(cx: &mut AppContext) {
+        let test_language = Arc::new(Language::new(
+            LanguageConfig {
+                name: "TestLang".into(),
+                line_comments: vec!["# ".into()],
+                ..Default::default()
+            },
+            None,
+        ));
+
+        let buffer = cx.new_model(|cx| {
+            Buffer::local(
+                indoc! { r#"
+                    import math
+
+                    # %%
+                    print(math.pi)
+
+                    # %%
+                    print(math.e)
+                "# },
+                cx,
+            )
+            .with_language(test_language.clone(), cx)
+        });
+        let snapshot = buffer.read(cx).snapshot();
+        let cells = cell_ranges(&snapshot)
+            .into_iter()
+            .map(|range| snapshot.text_for_range(range).collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            vec!["import math", "# %%\nprint(math.pi)", "# %%\nprint(math.e)"]
+        );
+
+        // Without any markers, the whole buffer is a single cell
+        let buffer = cx.new_model(|cx| {
+            Buffer::local("x = 1\n\nprint(x)\n", cx).with_language(test_language, cx)
+        });
+        let snapshot = buffer.read(cx).snapshot();
+        let cells = cell_ranges(&snapshot)
+            .into_iter()
+            .map(|range| snapshot.text_for_range(range).collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(cells, vec!["x = 1\n\nprint(x)"]);
+    }
+
     #[gpui::test]
     fn test_markdown_code_blocks(cx: &mut AppContext) {
         let markdown = languages::language("markdown", tree_sitter_md::LANGUAGE.into());
@@ -18,6 +18,8 @@ actions!(
     [
         Run,
         RunInPlace,
+        RunAllCells,
+        RunCellsAbove,
         ClearOutputs,
         Sessions,
         Interrupt,
@@ -121,6 +123,32 @@ pub fn init(cx: &mut AppContext) {
                     }
                 })
                 .detach();
+
+            editor
+                .register_action({
+                    let editor_handle = editor_handle.clone();
+                    move |_: &RunAllCells, cx| {
+                        if !JupyterSettings::enabled(cx) {
+                            return;
+                        }
+
+                        crate::run_all_cells(editor_handle.clone(), cx).log_err();
+                    }
+                })
+                .detach();
+
+            editor
+                .register_action({
+                    let editor_handle = editor_handle.clone();
+                    move |_: &RunCellsAbove, cx| {
+                        if !JupyterSettings::enabled(cx) {
+                            return;
+                        }
+
+                        crate::run_cells_above(editor_handle.clone(), cx).log_err();
+                    }
+                })
+                .detach();
         });
     })
     .detach();
@@ -12,7 +12,8 @@ use project::{Fs, Project, WorktreeId};
 use settings::{Settings, SettingsStore};
 
 use crate::kernels::{
-    list_remote_kernelspecs, local_kernel_specifications, python_env_kernel_specifications,
+    list_remote_kernelspecs, list_running_kernels, local_kernel_specifications,
+    python_env_kernel_specifications, Kernel, RunningKernelInfo,
 };
 use crate::{JupyterSettings, KernelSpecification, Session};
 
@@ -27,6 +28,7 @@ pub struct ReplStore {
     kernel_specifications: Vec<KernelSpecification>,
     selected_kernel_for_worktree: HashMap<WorktreeId, KernelSpecification>,
     kernel_specifications_for_worktree: HashMap<WorktreeId, Vec<KernelSpecification>>,
+    running_kernels: Vec<RunningKernelInfo>,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -60,6 +62,7 @@ impl ReplStore {
             _subscriptions: subscriptions,
             kernel_specifications_for_worktree: HashMap::default(),
             selected_kernel_for_worktree: HashMap::default(),
+            running_kernels: Vec::new(),
         };
         this.on_enabled_changed(cx);
         this
@@ -165,6 +168,7 @@ impl ReplStore {
         let local_kernel_specifications = local_kernel_specifications(self.fs.clone());
 
         let remote_kernel_specifications = self.get_remote_kernel_specifications(cx);
+        let running_kernels = list_running_kernels(self.fs.clone());
 
         let all_specs = cx.background_executor().spawn(async move {
             let mut all_specs = local_kernel_specifications
@@ -193,6 +197,17 @@ impl ReplStore {
                 .ok();
             }
 
+            match running_kernels.await {
+                Ok(running_kernels) => {
+                    this.update(&mut cx, |this, cx| {
+                        this.running_kernels = running_kernels;
+                        cx.notify();
+                    })
+                    .ok();
+                }
+                Err(err) => log::debug!("Error listing running kernels: {err:?}"),
+            }
+
             anyhow::Ok(())
         })
     }
@@ -270,6 +285,46 @@ impl ReplStore {
             .cloned()
     }
 
+    /// The kernels left running outside of a session (for example, after Zed
+    /// quit or crashed) that can be reconnected to, as of the last refresh.
+    pub fn reconnectable_kernels<'a>(
+        &'a self,
+        cx: &'a AppContext,
+    ) -> impl Iterator<Item = &'a RunningKernelInfo> {
+        let connected_files = self
+            .sessions
+            .values()
+            .filter_map(|session| match &session.read(cx).kernel {
+                Kernel::RunningKernel(kernel) => kernel.connection_file(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        self.running_kernels.iter().filter(move |running_kernel| {
+            !connected_files.contains(&running_kernel.connection_file.as_path())
+        })
+    }
+
+    /// Finds the kernelspec a running kernel was started from, using the kernel
+    /// name recorded in its connection file.
+    pub fn kernelspec_for_running_kernel(
+        &self,
+        running_kernel: &RunningKernelInfo,
+    ) -> Option<KernelSpecification> {
+        let kernel_name = running_kernel.kernel_name()?;
+
+        self.kernel_specifications
+            .iter()
+            .chain(self.kernel_specifications_for_worktree.values().flatten())
+            .find(|kernel_specification| match kernel_specification {
+                KernelSpecification::Jupyter(spec) | KernelSpecification::PythonEnv(spec) => {
+                    spec.name == kernel_name
+                }
+                KernelSpecification::Remote(_) => false,
+            })
+            .cloned()
+    }
+
     pub fn get_session(&self, entity_id: EntityId) -> Option<&View<Session>> {
         self.sessions.get(&entity_id)
     }
@@ -1,11 +1,14 @@
+use crate::comm::CommManager;
 use crate::components::KernelListItem;
-use crate::kernels::RemoteRunningKernel;
+use crate::kernels::{RemoteRunningKernel, RunningKernel, RunningKernelInfo};
+use crate::repl_editor::cell_ranges;
 use crate::setup_editor_session_actions;
 use crate::{
     kernels::{Kernel, KernelSpecification, NativeRunningKernel},
-    outputs::{ExecutionStatus, ExecutionView},
-    KernelStatus,
+    outputs::{user_error::ErrorLocals, ExecutionStatus, ExecutionView, ExecutionViewEvent},
+    JupyterSettings, KernelStatus,
 };
+use chrono::Utc;
 use collections::{HashMap, HashSet};
 use editor::{
     display_map::{
@@ -17,15 +20,17 @@ use editor::{
 };
 use futures::FutureExt as _;
 use gpui::{
-    div, prelude::*, EventEmitter, Model, Render, Subscription, Task, View, ViewContext, WeakView,
+    div, prelude::*, AppContext, EventEmitter, Model, Notification, NotificationLevel, Render,
+    Subscription, Task, View, ViewContext, WeakView,
 };
 use language::Point;
 use project::Fs;
 use runtimelib::{
     ExecuteRequest, ExecutionState, InterruptRequest, JupyterMessage, JupyterMessageContent,
-    ShutdownRequest,
+    ReplyStatus, ShutdownRequest, Stdio,
 };
-use std::{env::temp_dir, ops::Range, sync::Arc, time::Duration};
+use settings::Settings as _;
+use std::{collections::VecDeque, env::temp_dir, ops::Range, sync::Arc, time::Duration};
 use theme::ActiveTheme;
 use ui::{prelude::*, IconButtonShape, Tooltip};
 use util::ResultExt as _;
@@ -35,17 +40,45 @@ pub struct Session {
     editor: WeakView<Editor>,
     pub kernel: Kernel,
     blocks: HashMap<String, EditorBlock>,
+    locals_requests: HashMap<String, LocalsRequest>,
+    comms: CommManager,
+    /// Cells waiting to be run by [`Session::run_all_cells`] or [`Session::run_cells_above`].
+    queued_cells: VecDeque<QueuedCell>,
+    /// The message id of the queued cell the kernel is running, if any.
+    running_queued_cell: Option<String>,
     pub kernel_specification: KernelSpecification,
     _buffer_subscription: Subscription,
 }
 
+struct QueuedCell {
+    code: String,
+    anchor_range: Range<Anchor>,
+}
+
 struct EditorBlock {
     code_range: Range<Anchor>,
     invalidation_anchor: Anchor,
     block_id: CustomBlockId,
     execution_view: View<ExecutionView>,
+    _execution_view_subscription: Subscription,
+    /// Interrupts the execution if it runs for too long. Started once the kernel begins
+    /// running the request, so that time spent queued behind other cells doesn't count.
+    execution_timeout: Option<Task<()>>,
+}
+
+/// A pending request for the local variables of the frame that raised an error.
+struct LocalsRequest {
+    execution_view: WeakView<ExecutionView>,
+    stdout: String,
 }
 
+/// Prints the locals of the innermost frame of the last traceback as a JSON list
+/// of `[name, repr]` pairs. Written as a single expression so that running it
+/// doesn't leave any names behind in the user's namespace.
+///
+/// This relies on `sys.last_traceback`, which is only set by IPython-based kernels.
+const PYTHON_LOCALS_CODE: &str = r#"print(__import__("json").dumps([[name, repr(value)[:200]] for name, value in list(__import__("traceback").walk_tb(__import__("sys").last_traceback))[-1][0].f_locals.items() if not name.startswith("_") and not callable(value) and type(value).__name__ != "module"]))"#;
+
 type CloseBlockFn =
     Arc<dyn for<'a> Fn(CustomBlockId, &'a mut WindowContext) + Send + Sync + 'static>;
 
@@ -67,6 +100,8 @@ impl EditorBlock {
 
         let execution_view =
             cx.new_view(|cx| ExecutionView::new(status, workspace.downgrade(), cx));
+        let execution_view_subscription =
+            cx.subscribe(&execution_view, Session::on_execution_view_event);
 
         let (block_id, invalidation_anchor) = editor.update(cx, |editor, cx| {
             let buffer = editor.buffer().clone();
@@ -105,11 +140,21 @@ impl EditorBlock {
             invalidation_anchor,
             block_id,
             execution_view,
+            _execution_view_subscription: execution_view_subscription,
+            execution_timeout: None,
         })
     }
 
     fn handle_message(&mut self, message: &JupyterMessage, cx: &mut ViewContext<Session>) {
         self.execution_view.update(cx, |execution_view, cx| {
+            if let (JupyterMessageContent::ExecuteReply(_), Some(request_header)) =
+                (&message.content, message.parent_header.as_ref())
+            {
+                let duration = (Utc::now() - request_header.date)
+                    .to_std()
+                    .unwrap_or_default();
+                execution_view.set_duration(duration, cx);
+            }
             execution_view.push_message(&message.content, cx);
         });
     }
@@ -194,6 +239,31 @@ impl Session {
         fs: Arc<dyn Fs>,
         kernel_specification: KernelSpecification,
         cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let mut session = Self::new_without_kernel(editor, fs, kernel_specification, cx);
+        session.start_kernel(cx);
+        session
+    }
+
+    /// Creates a session for a kernel that is already running, connecting to
+    /// it instead of launching a new kernel process.
+    pub fn reconnect(
+        editor: WeakView<Editor>,
+        fs: Arc<dyn Fs>,
+        kernel_specification: KernelSpecification,
+        running_kernel: RunningKernelInfo,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let mut session = Self::new_without_kernel(editor, fs, kernel_specification, cx);
+        session.reconnect_kernel(running_kernel, cx);
+        session
+    }
+
+    fn new_without_kernel(
+        editor: WeakView<Editor>,
+        fs: Arc<dyn Fs>,
+        kernel_specification: KernelSpecification,
+        cx: &mut ViewContext<Self>,
     ) -> Self {
         let subscription = match editor.upgrade() {
             Some(editor) => {
@@ -211,17 +281,18 @@ impl Session {
             })
             .ok();
 
-        let mut session = Self {
+        Self {
             fs,
             editor,
             kernel: Kernel::StartingKernel(Task::ready(()).shared()),
             blocks: HashMap::default(),
+            locals_requests: HashMap::default(),
+            comms: CommManager::default(),
+            queued_cells: VecDeque::new(),
+            running_queued_cell: None,
             kernel_specification,
             _buffer_subscription: subscription,
-        };
-
-        session.start_kernel(cx);
-        session
+        }
     }
 
     fn start_kernel(&mut self, cx: &mut ViewContext<Self>) {
@@ -260,6 +331,38 @@ impl Session {
             ),
         };
 
+        self.await_kernel(kernel, cx);
+    }
+
+    fn reconnect_kernel(&mut self, running_kernel: RunningKernelInfo, cx: &mut ViewContext<Self>) {
+        let working_directory = self
+            .editor
+            .upgrade()
+            .and_then(|editor| editor.read(cx).working_directory(cx))
+            .unwrap_or_else(temp_dir);
+
+        telemetry::event!(
+            "Kernel Status Changed",
+            kernel_language = self.kernel_specification.language(),
+            kernel_status = KernelStatus::Starting.to_string(),
+            repl_session_id = cx.entity_id().to_string(),
+        );
+
+        let kernel = NativeRunningKernel::reconnect(
+            running_kernel,
+            working_directory,
+            cx.view().clone(),
+            cx,
+        );
+
+        self.await_kernel(kernel, cx);
+    }
+
+    fn await_kernel(
+        &mut self,
+        kernel: Task<anyhow::Result<Box<dyn RunningKernel>>>,
+        cx: &mut ViewContext<Self>,
+    ) {
         let pending_kernel = cx
             .spawn(|this, mut cx| async move {
                 let kernel = kernel.await;
@@ -357,6 +460,55 @@ impl Session {
         self.blocks.clear();
     }
 
+    /// Returns the source of the executed cells, in the order they appear in the editor, as a
+    /// script in the percent format, where each cell is preceded by a `# %%` line. Outputs are discarded.
+    pub fn export_as_script(&self, cx: &AppContext) -> String {
+        let Some(editor) = self.editor.upgrade() else {
+            return String::new();
+        };
+        let buffer = editor.read(cx).buffer().read(cx).snapshot(cx);
+
+        let mut code_ranges = self
+            .blocks
+            .values()
+            .map(|block| block.code_range.clone())
+            .collect::<Vec<_>>();
+        code_ranges.sort_by(|a, b| a.start.cmp(&b.start, &buffer));
+
+        let mut script = String::new();
+        for code_range in code_ranges {
+            if !script.is_empty() {
+                script.push('\n');
+            }
+            script.push_str("# %%\n");
+            script.extend(buffer.text_for_range(code_range));
+            let trimmed_len = script.trim_end().len();
+            script.truncate(trimmed_len);
+            script.push('\n');
+        }
+        script
+    }
+
+    fn save_as_script(&mut self, cx: &mut ViewContext<Self>) {
+        let script = self.export_as_script(cx);
+        let directory = self
+            .editor
+            .upgrade()
+            .and_then(|editor| editor.read(cx).working_directory(cx))
+            .unwrap_or_else(temp_dir);
+        let path = cx.prompt_for_new_path(&directory);
+        let fs = self.fs.clone();
+
+        cx.background_executor()
+            .spawn(async move {
+                if let Some(path) = path.await?? {
+                    fs.atomic_write(path, script).await?;
+                }
+                anyhow::Ok(())
+            })
+            .detach_and_log_err(cx);
+    }
+
     pub fn execute(
         &mut self,
         code: String,
@@ -365,12 +517,75 @@ impl Session {
         move_down: bool,
         cx: &mut ViewContext<Self>,
     ) {
+        self.send_execute_request(code, anchor_range, next_cell, move_down, cx);
+    }
+
+    /// Runs every cell of the editor from top to bottom, sending each cell to the
+    /// kernel once it's idle after running the previous one.
+    pub fn run_all_cells(&mut self, cx: &mut ViewContext<Self>) {
+        self.queue_cells(usize::MAX, cx);
+    }
+
+    /// Runs the cells of the editor above the cell at the given index, in order, such
+    /// as to restore the kernel's state after a restart.
+    pub fn run_cells_above(&mut self, cell_index: usize, cx: &mut ViewContext<Self>) {
+        self.queue_cells(cell_index, cx);
+    }
+
+    fn queue_cells(&mut self, cell_count: usize, cx: &mut ViewContext<Self>) {
         let Some(editor) = self.editor.upgrade() else {
             return;
         };
+        let multibuffer = editor.read(cx).buffer().clone();
+        let Some(buffer) = multibuffer.read(cx).as_singleton() else {
+            return;
+        };
+
+        let ranges = cell_ranges(&buffer.read(cx).snapshot());
+        {
+            let snapshot = multibuffer.read(cx).read(cx);
+            self.queued_cells = ranges
+                .into_iter()
+                .take(cell_count)
+                .map(|range| QueuedCell {
+                    code: snapshot.text_for_range(range.clone()).collect(),
+                    anchor_range: snapshot.anchor_before(range.start)
+                        ..snapshot.anchor_after(range.end),
+                })
+                .collect();
+        }
+
+        if self.running_queued_cell.is_none() {
+            self.run_next_queued_cell(cx);
+        }
+    }
+
+    fn run_next_queued_cell(&mut self, cx: &mut ViewContext<Self>) {
+        self.running_queued_cell = None;
+        while let Some(cell) = self.queued_cells.pop_front() {
+            if let Some(message_id) =
+                self.send_execute_request(cell.code, cell.anchor_range, None, false, cx)
+            {
+                self.running_queued_cell = Some(message_id);
+                return;
+            }
+        }
+    }
+
+    /// Sends the code to the kernel and shows its output below the given range,
+    /// returning the id of the execute request.
+    fn send_execute_request(
+        &mut self,
+        code: String,
+        anchor_range: Range<Anchor>,
+        next_cell: Option<Anchor>,
+        move_down: bool,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<String> {
+        let editor = self.editor.upgrade()?;
 
         if code.is_empty() {
-            return;
+            return None;
         }
 
         let execute_request = ExecuteRequest {
@@ -430,11 +645,14 @@ impl Session {
                 }
             });
 
-        let Ok(editor_block) =
-            EditorBlock::new(self.editor.clone(), anchor_range, status, on_close, cx)
-        else {
-            return;
-        };
+        let editor_block =
+            EditorBlock::new(self.editor.clone(), anchor_range, status, on_close, cx).ok()?;
+
+        let can_show_locals = locals_code(&self.kernel_specification.language()).is_some();
+        editor_block.execution_view.update(cx, |execution_view, _| {
+            execution_view.set_can_show_locals(can_show_locals);
+            execution_view.set_compact_errors(true);
+        });
 
         let new_cursor_pos = if let Some(next_cursor) = next_cell {
             next_cursor
@@ -442,8 +660,8 @@ impl Session {
             editor_block.invalidation_anchor
         };
 
-        self.blocks
-            .insert(message.header.msg_id.clone(), editor_block);
+        let message_id = message.header.msg_id.clone();
+        self.blocks.insert(message_id.clone(), editor_block);
 
         match &self.kernel {
             Kernel::RunningKernel(_) => {
@@ -473,18 +691,44 @@ impl Session {
                 });
             });
         }
+
+        Some(message_id)
+    }
+
+    /// Returns how long the kernel took to run the execution request with the given
+    /// message id, once it has replied.
+    pub fn cell_timing(&self, message_id: &str, cx: &AppContext) -> Option<Duration> {
+        self.blocks
+            .get(message_id)?
+            .execution_view
+            .read(cx)
+            .duration
     }
 
     pub fn route(&mut self, message: &JupyterMessage, cx: &mut ViewContext<Self>) {
+        if self.comms.handle_message(&message.content, cx) {
+            return;
+        }
+
         let parent_message_id = match message.parent_header.as_ref() {
             Some(header) => &header.msg_id,
             None => return,
         };
 
+        if starts_execution(&message.content) {
+            self.start_execution_timeout(parent_message_id, cx);
+        }
+
         match &message.content {
             JupyterMessageContent::Status(status) => {
                 self.kernel.set_execution_state(&status.execution_state);
 
+                if matches!(status.execution_state, ExecutionState::Idle)
+                    && self.running_queued_cell.as_ref() == Some(parent_message_id)
+                {
+                    self.run_next_queued_cell(cx);
+                }
+
                 telemetry::event!(
                     "Kernel Status Changed",
                     kernel_language = self.kernel_specification.language(),
@@ -494,10 +738,28 @@ impl Session {
 
                 cx.notify();
             }
+            JupyterMessageContent::ExecuteReply(reply)
+                if self.running_queued_cell.as_ref() == Some(parent_message_id) =>
+            {
+                // Like Jupyter, stop running the queued cells once one of them fails.
+                if matches!(reply.status, ReplyStatus::Error | ReplyStatus::Aborted) {
+                    self.queued_cells.clear();
+                }
+            }
             JupyterMessageContent::KernelInfoReply(reply) => {
                 self.kernel.set_kernel_info(reply);
                 cx.notify();
             }
+            JupyterMessageContent::DisplayData(display) => {
+                if let Some(widget) = self.comms.widget_for_display_data(&display.data) {
+                    if let Some(block) = self.blocks.get(parent_message_id) {
+                        block.execution_view.update(cx, |execution_view, cx| {
+                            execution_view.push_widget(widget, cx);
+                        });
+                    }
+                    return;
+                }
+            }
             JupyterMessageContent::UpdateDisplayData(update) => {
                 let display_id = if let Some(display_id) = update.transient.display_id.clone() {
                     display_id
@@ -515,12 +777,112 @@ impl Session {
             _ => {}
         }
 
+        if self.locals_requests.contains_key(parent_message_id) {
+            self.route_locals_reply(parent_message_id.clone(), &message.content, cx);
+            return;
+        }
+
         if let Some(block) = self.blocks.get_mut(parent_message_id) {
             block.handle_message(message, cx);
         }
     }
 
+    fn on_execution_view_event(
+        &mut self,
+        execution_view: View<ExecutionView>,
+        event: &ExecutionViewEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            ExecutionViewEvent::RequestLocals => self.request_locals(execution_view, cx),
+        }
+    }
+
+    /// Asks the kernel for the local variables of the frame that raised the most
+    /// recent error, and shows them below the error in the given execution view.
+    fn request_locals(&mut self, execution_view: View<ExecutionView>, cx: &mut ViewContext<Self>) {
+        let code = match locals_code(&self.kernel_specification.language()) {
+            Some(code) if matches!(self.kernel, Kernel::RunningKernel(_)) => code,
+            Some(_) => {
+                execution_view.update(cx, |execution_view, cx| {
+                    execution_view.set_locals(
+                        ErrorLocals::Failed("the kernel is not running".to_string()),
+                        cx,
+                    );
+                });
+                return;
+            }
+            None => {
+                execution_view.update(cx, |execution_view, cx| {
+                    execution_view.set_locals(
+                        ErrorLocals::Failed("this kernel can't report locals".to_string()),
+                        cx,
+                    );
+                });
+                return;
+            }
+        };
+
+        let execute_request = ExecuteRequest {
+            code: code.to_string(),
+            silent: false,
+            store_history: false,
+            ..ExecuteRequest::default()
+        };
+        let message: JupyterMessage = execute_request.into();
+
+        self.locals_requests.insert(
+            message.header.msg_id.clone(),
+            LocalsRequest {
+                execution_view: execution_view.downgrade(),
+                stdout: String::new(),
+            },
+        );
+        self.send(message, cx).ok();
+    }
+
+    fn route_locals_reply(
+        &mut self,
+        parent_message_id: String,
+        content: &JupyterMessageContent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let locals = match content {
+            JupyterMessageContent::StreamContent(stream) => {
+                if let (Stdio::Stdout, Some(request)) = (
+                    &stream.name,
+                    self.locals_requests.get_mut(&parent_message_id),
+                ) {
+                    request.stdout.push_str(&stream.text);
+                }
+                return;
+            }
+            JupyterMessageContent::ErrorOutput(error) => {
+                ErrorLocals::Failed(format!("{}: {}", error.ename, error.evalue))
+            }
+            JupyterMessageContent::Status(status)
+                if matches!(status.execution_state, ExecutionState::Idle) =>
+            {
+                match self.locals_requests.get(&parent_message_id) {
+                    Some(request) => parse_locals(&request.stdout),
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        if let Some(request) = self.locals_requests.remove(&parent_message_id) {
+            request
+                .execution_view
+                .update(cx, |execution_view, cx| {
+                    execution_view.set_locals(locals, cx)
+                })
+                .ok();
+        }
+    }
+
     pub fn interrupt(&mut self, cx: &mut ViewContext<Self>) {
+        self.queued_cells.clear();
         match &mut self.kernel {
             Kernel::RunningKernel(_kernel) => {
                 self.send(InterruptRequest {}.into(), cx).ok();
@@ -532,6 +894,62 @@ impl Session {
         }
     }
 
+    /// Starts the execution timeout of the block for the given request, unless there is no
+    /// timeout configured or it has already been started.
+    fn start_execution_timeout(&mut self, message_id: &str, cx: &mut ViewContext<Self>) {
+        let Some(timeout) = JupyterSettings::get_global(cx).execution_timeout_seconds else {
+            return;
+        };
+        if !self
+            .blocks
+            .get(message_id)
+            .is_some_and(|block| block.execution_timeout.is_none())
+        {
+            return;
+        }
+
+        let task =
+            self.interrupt_after_timeout(message_id.to_string(), Duration::from_secs(timeout), cx);
+        if let Some(block) = self.blocks.get_mut(message_id) {
+            block.execution_timeout = Some(task);
+        }
+    }
+
+    /// Interrupts the execution started by the given message if the kernel is still
+    /// running it after `timeout`, and lets the user know why it was interrupted.
+    pub fn interrupt_after_timeout(
+        &self,
+        message_id: String,
+        timeout: Duration,
+        cx: &mut ViewContext<Self>,
+    ) -> Task<()> {
+        cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(timeout).await;
+            this.update(&mut cx, |session, cx| {
+                let Some(block) = session.blocks.get(&message_id) else {
+                    return;
+                };
+                if !matches!(
+                    block.execution_view.read(cx).status,
+                    ExecutionStatus::Executing
+                ) {
+                    return;
+                }
+
+                session.interrupt(cx);
+                cx.push_notification(
+                    Notification::new("Execution interrupted")
+                        .body(format!(
+                            "The cell was still running after {} seconds.",
+                            timeout.as_secs()
+                        ))
+                        .level(NotificationLevel::Warning),
+                );
+            })
+            .ok();
+        })
+    }
+
     pub fn kernel(&mut self, kernel: Kernel, cx: &mut ViewContext<Self>) {
         if let Kernel::Shutdown = kernel {
             cx.emit(SessionEvent::Shutdown(self.editor.clone()));
@@ -551,6 +969,8 @@ impl Session {
     }
 
     pub fn shutdown(&mut self, cx: &mut ViewContext<Self>) {
+        self.queued_cells.clear();
+        self.running_queued_cell = None;
         let kernel = std::mem::replace(&mut self.kernel, Kernel::ShuttingDown);
 
         match kernel {
@@ -585,6 +1005,8 @@ impl Session {
     }
 
     pub fn restart(&mut self, cx: &mut ViewContext<Self>) {
+        self.queued_cells.clear();
+        self.running_queued_cell = None;
         let kernel = std::mem::replace(&mut self.kernel, Kernel::Restarting);
 
         match kernel {
@@ -612,6 +1034,7 @@ impl Session {
                     this.update(&mut cx, |session, cx| {
                         // TODO: Differentiate between restart and restart+clear-outputs
                         session.clear_outputs(cx);
+                        session.comms.clear();
                         session.start_kernel(cx);
                     })
                     .ok();
@@ -679,5 +1102,93 @@ impl Render for Session {
                     })),
             )
             .buttons(interrupt_button)
+            .button(
+                Button::new("export-as-script", "Export as Script")
+                    .style(ButtonStyle::Subtle)
+                    .disabled(self.blocks.is_empty())
+                    .on_click(cx.listener(move |session, _, cx| {
+                        session.save_as_script(cx);
+                    })),
+            )
+    }
+}
+
+/// Returns the code that prints the locals of the last error for kernels of the
+/// given language, or `None` if the language isn't supported.
+fn locals_code(language: &str) -> Option<&'static str> {
+    if language.eq_ignore_ascii_case("python") {
+        Some(PYTHON_LOCALS_CODE)
+    } else {
+        None
+    }
+}
+
+fn parse_locals(stdout: &str) -> ErrorLocals {
+    match serde_json::from_str::<Vec<(String, String)>>(stdout.trim()) {
+        Ok(locals) => ErrorLocals::Loaded(locals),
+        Err(_) => ErrorLocals::Failed("unexpected response from the kernel".to_string()),
+    }
+}
+
+/// Whether the message tells us that the kernel has started running the request it is a
+/// reply to, rather than it still waiting behind other requests.
+fn starts_execution(content: &JupyterMessageContent) -> bool {
+    match content {
+        JupyterMessageContent::Status(status) => {
+            matches!(status.execution_state, ExecutionState::Busy)
+        }
+        JupyterMessageContent::ExecuteInput(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runtimelib::{ExecuteInput, ExecutionCount, Status};
+
+    #[test]
+    fn test_locals_code_is_gated_on_kernel_language() {
+        assert_eq!(locals_code("python"), Some(PYTHON_LOCALS_CODE));
+        assert_eq!(locals_code("Python"), Some(PYTHON_LOCALS_CODE));
+        assert_eq!(locals_code("julia"), None);
+        assert_eq!(locals_code("R"), None);
+        assert_eq!(locals_code("typescript"), None);
+        assert_eq!(locals_code(""), None);
+    }
+
+    #[test]
+    fn test_execution_starts_when_the_kernel_is_busy() {
+        assert!(!starts_execution(&JupyterMessageContent::ExecuteRequest(
+            ExecuteRequest::default()
+        )));
+        assert!(!starts_execution(&JupyterMessageContent::Status(Status {
+            execution_state: ExecutionState::Idle,
+        })));
+        assert!(starts_execution(&JupyterMessageContent::Status(Status {
+            execution_state: ExecutionState::Busy,
+        })));
+        assert!(starts_execution(&JupyterMessageContent::ExecuteInput(
+            ExecuteInput {
+                code: "1 + 1".to_string(),
+                execution_count: ExecutionCount::new(1),
+            }
+        )));
+    }
+
+    #[test]
+    fn test_parse_locals() {
+        assert_eq!(
+            parse_locals("[[\"x\", \"1\"], [\"name\", \"'zed'\"]]\n"),
+            ErrorLocals::Loaded(vec![
+                ("x".to_string(), "1".to_string()),
+                ("name".to_string(), "'zed'".to_string()),
+            ])
+        );
+        assert_eq!(parse_locals("[]"), ErrorLocals::Loaded(Vec::new()));
+        assert!(matches!(
+            parse_locals("NameError: name 'sys' is not defined"),
+            ErrorLocals::Failed(_)
+        ));
     }
 }
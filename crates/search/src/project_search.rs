@@ -10,11 +10,10 @@ use editor::{
 };
 use futures::StreamExt;
 use gpui::{
-    actions, div, Action, AnyElement, AnyView, AppContext, Axis, Context as _, EntityId,
-    EventEmitter, FocusHandle, FocusableView, Global, Hsla, InteractiveElement, IntoElement,
-    KeyContext, Model, ModelContext, ParentElement, Point, Render, SharedString, Styled,
-    Subscription, Task, TextStyle, UpdateGlobal, View, ViewContext, VisualContext, WeakModel,
-    WeakView, WindowContext,
+    actions, div, Action, AnyView, AppContext, Axis, Context as _, EntityId, EventEmitter,
+    FocusHandle, FocusableView, Global, Hsla, InteractiveElement, IntoElement, KeyContext, Model,
+    ModelContext, ParentElement, Point, Render, SharedString, Styled, Subscription, Task,
+    TextStyle, UpdateGlobal, View, ViewContext, VisualContext, WeakModel, WeakView, WindowContext,
 };
 use language::Buffer;
 use menu::Confirm;
@@ -32,8 +31,8 @@ use std::{
 };
 use theme::ThemeSettings;
 use ui::{
-    h_flex, prelude::*, utils::SearchInputWidth, v_flex, Icon, IconButton, IconButtonShape,
-    IconName, KeyBinding, Label, LabelCommon, LabelSize, Toggleable, Tooltip,
+    h_flex, prelude::*, utils::SearchInputWidth, v_flex, EmptyState, Icon, IconButton,
+    IconButtonShape, IconName, KeyBinding, Label, LabelCommon, LabelSize, Toggleable, Tooltip,
 };
 use util::paths::PathMatcher;
 use workspace::{
@@ -332,10 +331,23 @@ impl Render for ProjectSearchView {
             let has_no_results = model.no_results.unwrap_or(false);
             let is_search_underway = model.pending_search.is_some();
 
+            let container = v_flex()
+                .size_full()
+                .items_center()
+                .justify_center()
+                .overflow_hidden()
+                .bg(cx.theme().colors().editor_background)
+                .track_focus(&self.focus_handle(cx));
+
+            if has_no_results && !is_search_underway {
+                return container.child(
+                    EmptyState::new(IconName::MagnifyingGlass, "No Results")
+                        .description("No results found in this project for the provided query"),
+                );
+            }
+
             let heading_text = if is_search_underway {
                 "Searching…"
-            } else if has_no_results {
-                "No Results"
             } else {
                 "Search All Files"
             };
@@ -344,38 +356,20 @@ impl Render for ProjectSearchView {
                 .justify_center()
                 .child(Label::new(heading_text).size(LabelSize::Large));
 
-            let page_content: Option<AnyElement> = if let Some(no_results) = model.no_results {
-                if model.pending_search.is_none() && no_results {
-                    Some(
-                        Label::new("No results found in this project for the provided query")
-                            .size(LabelSize::Small)
-                            .into_any_element(),
-                    )
-                } else {
-                    None
-                }
-            } else {
-                Some(self.landing_text_minor(cx).into_any_element())
-            };
-
-            let page_content = page_content.map(|text| div().child(text));
+            let page_content = model
+                .no_results
+                .is_none()
+                .then(|| div().child(self.landing_text_minor(cx)));
 
-            v_flex()
-                .size_full()
-                .items_center()
-                .justify_center()
-                .overflow_hidden()
-                .bg(cx.theme().colors().editor_background)
-                .track_focus(&self.focus_handle(cx))
-                .child(
-                    v_flex()
-                        .id("project-search-landing-page")
-                        .overflow_y_scroll()
-                        .max_w_80()
-                        .gap_1()
-                        .child(heading_text)
-                        .children(page_content),
-                )
+            container.child(
+                v_flex()
+                    .id("project-search-landing-page")
+                    .overflow_y_scroll()
+                    .max_w_80()
+                    .gap_1()
+                    .child(heading_text)
+                    .children(page_content),
+            )
         }
     }
 }
@@ -190,7 +190,9 @@ fn no_action() -> Box<dyn gpui::Action> {
 
 #[cfg(test)]
 mod tests {
-    use crate::KeymapFile;
+    use crate::{KeymapFile, SettingsAssets};
+    use gpui::{KeyBindingContextPredicate, KeyContext, Keystroke};
+    use util::asset_str;
 
     #[test]
     fn can_deserialize_keymap_with_trailing_comma() {
@@ -207,4 +209,52 @@ mod tests {
         };
         KeymapFile::parse(json).unwrap();
     }
+
+    #[test]
+    fn notebook_bindings_are_not_shadowed_by_cell_editors() {
+        fn keystrokes(input: &str) -> Vec<Keystroke> {
+            input
+                .split_whitespace()
+                .map(|keystroke| Keystroke::parse(keystroke).unwrap())
+                .collect()
+        }
+
+        // Cell editors are nested inside the notebook, so their bindings take precedence.
+        let context_stack = [
+            KeyContext::parse("Workspace").unwrap(),
+            KeyContext::parse("notebook").unwrap(),
+            KeyContext::parse("Editor mode = auto_height").unwrap(),
+        ];
+
+        for path in ["keymaps/default-linux.json", "keymaps/default-macos.json"] {
+            let keymap = KeymapFile::parse(&asset_str::<SettingsAssets>(path)).unwrap();
+            let notebook_keystrokes = keymap
+                .blocks()
+                .iter()
+                .filter(|block| block.context() == Some("notebook"))
+                .flat_map(|block| block.bindings().keys())
+                .map(|input| keystrokes(input))
+                .collect::<Vec<_>>();
+            assert!(!notebook_keystrokes.is_empty(), "{path}");
+
+            for block in keymap.blocks() {
+                let Some(context) = block.context() else {
+                    continue;
+                };
+                if context == "notebook" {
+                    continue;
+                }
+                let predicate = KeyBindingContextPredicate::parse(context).unwrap();
+                if !predicate.eval(&context_stack) {
+                    continue;
+                }
+                for input in block.bindings().keys() {
+                    assert!(
+                        !notebook_keystrokes.contains(&keystrokes(input)),
+                        "{path}: {input:?} in {context:?} shadows a notebook binding"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -5,7 +5,8 @@ use gpui::{
     HighlightStyle, Hitbox, Hsla, InputHandler, InteractiveElement, Interactivity, IntoElement,
     LayoutId, Model, ModelContext, ModifiersChangedEvent, MouseButton, MouseMoveEvent, Pixels,
     Point, ShapedLine, StatefulInteractiveElement, StrikethroughStyle, Styled, TextRun, TextStyle,
-    UTF16Selection, UnderlineStyle, View, WeakView, WhiteSpace, WindowContext, WindowTextSystem,
+    UTF16Selection, UnderlinePattern, UnderlineStyle, View, WeakView, WhiteSpace, WindowContext,
+    WindowTextSystem,
 };
 use itertools::Itertools;
 use language::CursorShape;
@@ -357,7 +358,11 @@ impl TerminalElement {
         .then(|| UnderlineStyle {
             color: Some(fg),
             thickness: Pixels::from(1.0),
-            wavy: flags.contains(Flags::UNDERCURL),
+            pattern: if flags.contains(Flags::UNDERCURL) {
+                UnderlinePattern::wavy(px(1.))
+            } else {
+                UnderlinePattern::Solid
+            },
         });
 
         let strikethrough = flags
@@ -390,6 +395,8 @@ impl TerminalElement {
             },
             underline,
             strikethrough,
+            letter_spacing: None,
+            word_spacing: None,
         };
 
         if let Some((style, range)) = hyperlink {
@@ -648,7 +655,7 @@ impl Element for TerminalElement {
                     underline: Some(UnderlineStyle {
                         thickness: px(1.0),
                         color: Some(theme.colors().link_text_hover),
-                        wavy: false,
+                        ..Default::default()
                     }),
                     strikethrough: None,
                     fade_out: None,
@@ -669,6 +676,9 @@ impl Element for TerminalElement {
                     underline: None,
                     strikethrough: None,
                     color: theme.colors().terminal_foreground,
+                    letter_spacing: None,
+                    word_spacing: None,
+                    shadows: Default::default(),
                 };
 
                 let text_system = cx.text_system();
@@ -782,6 +792,8 @@ impl Element for TerminalElement {
                                     background_color: None,
                                     underline: Default::default(),
                                     strikethrough: None,
+                                    letter_spacing: None,
+                                    word_spacing: None,
                                 }],
                             )
                             .unwrap()
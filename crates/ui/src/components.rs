@@ -1,10 +1,12 @@
 mod avatar;
 mod button;
+mod code_block;
 mod content_group;
 mod context_menu;
 mod disclosure;
 mod divider;
 mod dropdown_menu;
+mod empty_state;
 mod facepile;
 mod icon;
 mod image;
@@ -23,10 +25,12 @@ mod right_click_menu;
 mod scrollbar;
 mod settings_container;
 mod settings_group;
+mod slider;
 mod stack;
 mod tab;
 mod tab_bar;
 mod table;
+mod toast;
 mod toggle;
 mod tool_strip;
 mod tooltip;
@@ -36,11 +40,13 @@ mod stories;
 
 pub use avatar::*;
 pub use button::*;
+pub use code_block::*;
 pub use content_group::*;
 pub use context_menu::*;
 pub use disclosure::*;
 pub use divider::*;
 pub use dropdown_menu::*;
+pub use empty_state::*;
 pub use facepile::*;
 pub use icon::*;
 pub use image::*;
@@ -59,10 +65,12 @@ pub use right_click_menu::*;
 pub use scrollbar::*;
 pub use settings_container::*;
 pub use settings_group::*;
+pub use slider::*;
 pub use stack::*;
 pub use tab::*;
 pub use tab_bar::*;
 pub use table::*;
+pub use toast::*;
 pub use toggle::*;
 pub use tool_strip::*;
 pub use tooltip::*;
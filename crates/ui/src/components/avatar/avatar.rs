@@ -1,7 +1,27 @@
-use crate::prelude::*;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{prelude::*, Availability, AvatarAvailabilityIndicator};
 
 use gpui::{img, AnyElement, Hsla, ImageSource, Img, IntoElement, Styled};
 
+/// Whether the user an [`Avatar`] belongs to is currently online.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UserStatus {
+    /// The user is online, shown as a green dot.
+    Online,
+    /// The user is offline, shown as a grey dot.
+    Offline,
+}
+
+impl From<UserStatus> for Availability {
+    fn from(status: UserStatus) -> Self {
+        match status {
+            UserStatus::Online => Availability::Free,
+            UserStatus::Offline => Availability::Offline,
+        }
+    }
+}
+
 /// An element that renders a user avatar with customizable appearance options.
 ///
 /// # Examples
@@ -20,6 +40,8 @@ pub struct Avatar {
     size: Option<AbsoluteLength>,
     border_color: Option<Hsla>,
     indicator: Option<AnyElement>,
+    name: Option<SharedString>,
+    status: Option<UserStatus>,
 }
 
 impl Avatar {
@@ -30,9 +52,25 @@ impl Avatar {
             size: None,
             border_color: None,
             indicator: None,
+            name: None,
+            status: None,
         }
     }
 
+    /// Sets the name of the user, whose initials are shown in place of the
+    /// image while it is loading or if it fails to load.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Shows a dot in the bottom-right corner of the avatar indicating whether
+    /// the user is online.
+    pub fn with_status(mut self, status: UserStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
     /// Applies a grayscale filter to the avatar image.
     ///
     /// # Examples
@@ -79,7 +117,42 @@ impl RenderOnce for Avatar {
         };
 
         let image_size = self.size.unwrap_or_else(|| rems(1.).into());
-        let container_size = image_size.to_pixels(cx.rem_size()) + border_width * 2.;
+        let image_size_in_pixels = image_size.to_pixels(cx.rem_size());
+        let container_size = image_size_in_pixels + border_width * 2.;
+
+        let mut image = self.image;
+        if let Some(name) = self.name {
+            let mut hasher = DefaultHasher::new();
+            name.hash(&mut hasher);
+            let background = cx
+                .theme()
+                .players()
+                .color_for_participant(hasher.finish() as u32)
+                .cursor;
+            let text_color = cx.theme().colors().editor_background;
+            let initials = SharedString::from(initials(&name));
+
+            let render_initials = move || {
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .size(image_size)
+                    .rounded_full()
+                    .bg(background)
+                    .text_color(text_color)
+                    .text_size(image_size_in_pixels * 0.4)
+                    .child(initials.clone())
+                    .into_any_element()
+            };
+            image = image
+                .with_fallback(render_initials.clone())
+                .with_loading(render_initials);
+        }
+
+        let status = self.status.map(|status| {
+            AvatarAvailabilityIndicator::new(status.into()).avatar_size(image_size_in_pixels)
+        });
 
         div()
             .size(container_size)
@@ -88,11 +161,74 @@ impl RenderOnce for Avatar {
                 this.border(border_width).border_color(color)
             })
             .child(
-                self.image
+                image
                     .size(image_size)
                     .rounded_full()
                     .bg(cx.theme().colors().ghost_element_background),
             )
             .children(self.indicator.map(|indicator| div().child(indicator)))
+            .children(status)
+    }
+}
+
+/// Returns the uppercased initials of the first two words of the name.
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+impl ComponentPreview for Avatar {
+    fn description() -> impl Into<Option<&'static str>> {
+        "A user's profile picture, showing their initials while the picture is loading or unavailable."
+    }
+
+    fn examples(_: &mut WindowContext) -> Vec<ComponentExampleGroup<Self>> {
+        let url = "https://avatars.githubusercontent.com/u/1714999?s=60&v=4";
+
+        vec![example_group(vec![
+            single_example("Default", Avatar::new(url).name("Nate Butler")),
+            single_example(
+                "Fallback Initials",
+                Avatar::new("").name("Nate Butler").size(px(32.)),
+            ),
+            single_example(
+                "Online",
+                Avatar::new(url)
+                    .name("Nate Butler")
+                    .with_status(UserStatus::Online),
+            ),
+            single_example(
+                "Offline",
+                Avatar::new(url)
+                    .name("Nate Butler")
+                    .with_status(UserStatus::Offline),
+            ),
+        ])]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{initials, UserStatus};
+    use crate::Availability;
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials("Nate Butler"), "NB");
+        assert_eq!(initials("  ada lovelace byron "), "AL");
+        assert_eq!(initials("Mikayla"), "M");
+        assert_eq!(initials(""), "");
+    }
+
+    #[test]
+    fn test_user_status_availability() {
+        assert_eq!(Availability::from(UserStatus::Online), Availability::Free);
+        assert_eq!(
+            Availability::from(UserStatus::Offline),
+            Availability::Offline
+        );
     }
 }
@@ -5,6 +5,7 @@ use crate::prelude::*;
 pub enum Availability {
     Free,
     Busy,
+    Offline,
 }
 
 #[derive(IntoElement)]
@@ -44,6 +45,7 @@ impl RenderOnce for AvatarAvailabilityIndicator {
             .bg(match self.availability {
                 Availability::Free => cx.theme().status().created,
                 Availability::Busy => cx.theme().status().deleted,
+                Availability::Offline => cx.theme().status().ignored,
             })
     }
 }
@@ -0,0 +1,111 @@
+#![allow(missing_docs)]
+
+use std::ops::Range;
+
+use gpui::{HighlightStyle, StyledText};
+use settings::Settings;
+use theme::ThemeSettings;
+
+use crate::prelude::*;
+
+/// # CodeBlock
+///
+/// A snippet of code shown in the buffer font, such as in help text, documentation tooltips,
+/// or previews of a README.
+///
+/// `ui` doesn't depend on the language registry, so CodeBlock does no highlighting of its own.
+/// Callers that have a loaded language pass its highlights in with [`CodeBlock::highlights`].
+/// Without highlights, the code is shown in the editor's foreground color.
+#[derive(IntoElement)]
+pub struct CodeBlock {
+    code: SharedString,
+    language: Option<SharedString>,
+    highlights: Vec<(Range<usize>, HighlightStyle)>,
+}
+
+impl CodeBlock {
+    pub fn new(code: impl Into<SharedString>) -> Self {
+        Self {
+            code: code.into(),
+            language: None,
+            highlights: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the language of the code, shown above it.
+    pub fn language(mut self, language: impl Into<SharedString>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets the syntax highlighting of the code, as sorted, non-overlapping byte ranges.
+    pub fn highlights(
+        mut self,
+        highlights: impl IntoIterator<Item = (Range<usize>, HighlightStyle)>,
+    ) -> Self {
+        self.highlights = highlights.into_iter().collect();
+        self
+    }
+}
+
+impl RenderOnce for CodeBlock {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        let buffer_font = ThemeSettings::get_global(cx).buffer_font.clone();
+
+        let mut text_style = cx.text_style();
+        text_style.color = colors.editor_foreground;
+        text_style.font_family = buffer_font.family;
+        text_style.font_features = buffer_font.features;
+        text_style.font_fallbacks = buffer_font.fallbacks;
+        text_style.font_weight = buffer_font.weight;
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .p_2()
+            .rounded_md()
+            .border_1()
+            .border_color(colors.border_variant)
+            .bg(colors.editor_background)
+            .when_some(self.language, |this, language| {
+                this.child(
+                    Label::new(language)
+                        .size(LabelSize::XSmall)
+                        .color(Color::Muted),
+                )
+            })
+            .child(
+                div().font_buffer(cx).text_buffer(cx).child(
+                    StyledText::new(self.code).with_highlights(&text_style, self.highlights),
+                ),
+            )
+    }
+}
+
+impl ComponentPreview for CodeBlock {
+    fn description() -> impl Into<Option<&'static str>> {
+        "A snippet of code in the buffer font, optionally syntax highlighted."
+    }
+
+    fn examples(cx: &mut WindowContext) -> Vec<ComponentExampleGroup<Self>> {
+        let code = "fn main() {\n    println!(\"Hello, world!\");\n}";
+        let syntax = cx.theme().syntax();
+        let keyword = syntax.get("keyword");
+        let function = syntax.get("function");
+        let string = syntax.get("string");
+
+        vec![example_group(vec![
+            single_example("Plain", CodeBlock::new(code)),
+            single_example(
+                "Highlighted",
+                CodeBlock::new(code).language("Rust").highlights([
+                    (0..2, keyword),
+                    (3..7, function),
+                    (16..24, function),
+                    (25..40, string),
+                ]),
+            ),
+        ])]
+    }
+}
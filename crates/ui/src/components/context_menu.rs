@@ -5,7 +5,7 @@ use crate::{
 };
 use gpui::{
     px, Action, AnyElement, AppContext, DismissEvent, EventEmitter, FocusHandle, FocusableView,
-    IntoElement, Render, Subscription, View, VisualContext,
+    IntoElement, Pixels, Point, Render, Subscription, View, VisualContext,
 };
 use menu::{SelectFirst, SelectLast, SelectNext, SelectPrev};
 use settings::Settings;
@@ -78,6 +78,20 @@ impl ContextMenu {
         })
     }
 
+    /// Builds a context menu and shows it at the given position in the window, such as
+    /// where the user right-clicked, without the caller having to render it.
+    ///
+    /// See [`WindowContext::open_popup_at`] for how the menu is positioned and dismissed.
+    pub fn open_at(
+        position: Point<Pixels>,
+        cx: &mut WindowContext,
+        f: impl FnOnce(Self, &mut ViewContext<Self>) -> Self,
+    ) -> View<Self> {
+        let menu = Self::build(cx, f);
+        cx.open_popup_at(position, menu.clone());
+        menu
+    }
+
     pub fn context(mut self, focus: FocusHandle) -> Self {
         self.action_context = Some(focus);
         self
@@ -0,0 +1,101 @@
+#![allow(missing_docs)]
+
+use gpui::{Action, NoAction};
+
+use crate::{prelude::*, KeyBinding};
+
+/// # EmptyState
+///
+/// A centered placeholder shown in place of a panel's content when there is
+/// nothing to show, such as a search without results or a panel without an
+/// open project.
+///
+/// Consists of a large muted icon, a headline, an optional description and an
+/// optional button dispatching an action.
+#[derive(IntoElement)]
+pub struct EmptyState {
+    icon: IconName,
+    headline: SharedString,
+    description: Option<SharedString>,
+    action: Option<(SharedString, Box<dyn Action>)>,
+}
+
+impl EmptyState {
+    pub fn new(icon: IconName, headline: impl Into<SharedString>) -> Self {
+        Self {
+            icon,
+            headline: headline.into(),
+            description: None,
+            action: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds a button with the given label that dispatches the action when clicked.
+    pub fn action(mut self, label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
+        self.action = Some((label.into(), action));
+        self
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let action_button = self.action.map(|(label, action)| {
+            Button::new("empty-state-action", label)
+                .style(ButtonStyle::Filled)
+                .key_binding(KeyBinding::for_action(action.as_ref(), cx))
+                .on_click(move |_, cx| cx.dispatch_action(action.boxed_clone()))
+        });
+
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_2()
+            .items_center()
+            .justify_center()
+            .child(
+                Icon::new(self.icon)
+                    .size(IconSize::XLarge)
+                    .color(Color::Muted),
+            )
+            .child(Headline::new(self.headline).size(HeadlineSize::Small))
+            .children(self.description.map(|description| {
+                div().max_w_80().text_center().child(
+                    Label::new(description)
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                )
+            }))
+            .children(action_button.map(|button| div().pt_2().child(button)))
+    }
+}
+
+impl ComponentPreview for EmptyState {
+    fn description() -> impl Into<Option<&'static str>> {
+        "A placeholder for panels with nothing to show, such as a search without results."
+    }
+
+    fn examples(_: &mut WindowContext) -> Vec<ComponentExampleGroup<Self>> {
+        vec![example_group(vec![
+            single_example(
+                "Headline Only",
+                EmptyState::new(IconName::MagnifyingGlass, "No Results"),
+            ),
+            single_example(
+                "With Description",
+                EmptyState::new(IconName::MagnifyingGlass, "No Results")
+                    .description("No results found in this project for the provided query"),
+            ),
+            single_example(
+                "With Action",
+                EmptyState::new(IconName::Folder, "No Project Open")
+                    .description("Open a folder to browse its files.")
+                    .action("Open a project", Box::new(NoAction)),
+            ),
+        ])]
+    }
+}
@@ -59,6 +59,8 @@ pub enum IconSize {
     #[default]
     /// 16px
     Medium,
+    /// 24px
+    XLarge,
 }
 
 impl IconSize {
@@ -68,6 +70,7 @@ impl IconSize {
             IconSize::XSmall => rems_from_px(12.),
             IconSize::Small => rems_from_px(14.),
             IconSize::Medium => rems_from_px(16.),
+            IconSize::XLarge => rems_from_px(24.),
         }
     }
 
@@ -83,6 +86,7 @@ impl IconSize {
             IconSize::XSmall => DynamicSpacing::Base02.px(cx),
             IconSize::Small => DynamicSpacing::Base02.px(cx),
             IconSize::Medium => DynamicSpacing::Base02.px(cx),
+            IconSize::XLarge => DynamicSpacing::Base04.px(cx),
         };
 
         (icon_size, padding)
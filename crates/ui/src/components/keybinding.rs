@@ -32,6 +32,17 @@ impl KeyBinding {
         Some(Self::new(key_binding))
     }
 
+    /// Creates a key binding for a shortcut that isn't bound to an action, such as
+    /// `cmd-k cmd-s`.
+    pub fn from_keystrokes(keystrokes: &str) -> Option<Self> {
+        let key_binding =
+            gpui::KeyBinding::load(keystrokes, Box::new(gpui::NoAction), None, None).ok()?;
+        if key_binding.keystrokes().is_empty() {
+            return None;
+        }
+        Some(Self::new(key_binding))
+    }
+
     fn icon_for_key(&self, keystroke: &Keystroke) -> Option<IconName> {
         match keystroke.key.as_str() {
             "left" => Some(IconName::ArrowLeft),
@@ -304,6 +315,17 @@ pub fn text_for_keystroke(keystroke: &Keystroke, platform_style: PlatformStyle)
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_key_binding_from_keystrokes() {
+        let key_binding = KeyBinding::from_keystrokes("cmd-k cmd-s").unwrap();
+        assert_eq!(
+            text_for_key_binding(key_binding.key_binding, PlatformStyle::Mac),
+            "Command-K Command-S"
+        );
+
+        assert!(KeyBinding::from_keystrokes("").is_none());
+    }
+
     #[test]
     fn test_text_for_keystroke() {
         assert_eq!(
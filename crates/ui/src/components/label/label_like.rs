@@ -193,7 +193,7 @@ impl RenderOnce for LabelLike {
                     .underline = Some(UnderlineStyle {
                     thickness: px(1.),
                     color: None,
-                    wavy: false,
+                    ..Default::default()
                 });
                 this
             })
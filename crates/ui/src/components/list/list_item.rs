@@ -22,6 +22,8 @@ pub struct ListItem {
     spacing: ListItemSpacing,
     indent_level: usize,
     indent_step_size: Pixels,
+    /// Whether to draw a vertical guide line for each indent level.
+    tree_guide: bool,
     /// A slot for content that appears before the children, like an icon or avatar.
     start_slot: Option<AnyElement>,
     /// A slot for content that appears after the children, usually on the other side of the header.
@@ -52,6 +54,7 @@ impl ListItem {
             spacing: ListItemSpacing::Dense,
             indent_level: 0,
             indent_step_size: px(12.),
+            tree_guide: false,
             start_slot: None,
             end_slot: None,
             end_hover_slot: None,
@@ -74,6 +77,8 @@ impl ListItem {
         self
     }
 
+    /// Sets whether the item is highlighted when hovered, pressed, or selected with
+    /// [`Toggleable::toggle_state`].
     pub fn selectable(mut self, has_hover: bool) -> Self {
         self.selectable = has_hover;
         self
@@ -112,6 +117,13 @@ impl ListItem {
         self
     }
 
+    /// Draws a guide line for each indent level, connecting this item to its siblings
+    /// and ancestors in a tree.
+    pub fn with_tree_guide(mut self, tree_guide: bool) -> Self {
+        self.tree_guide = tree_guide;
+        self
+    }
+
     pub fn toggle(mut self, toggle: impl Into<Option<bool>>) -> Self {
         self.toggle = toggle.into();
         self
@@ -182,10 +194,33 @@ impl RenderOnce for ListItem {
             .id(self.id)
             .w_full()
             .relative()
-            // When an item is inset draw the indent spacing outside of the item
+            .when(self.selected, |this| {
+                this.text_color(Color::Default.color(cx))
+            })
+            // When an item is inset draw the indent spacing outside of the item, or as padding
+            // when it has tree guides so that they are drawn inside of it
             .when(self.inset, |this| {
-                this.ml(self.indent_level as f32 * self.indent_step_size)
-                    .px(DynamicSpacing::Base04.rems(cx))
+                let indent_width = self.indent_level as f32 * self.indent_step_size;
+                if self.tree_guide {
+                    this.pl(indent_width + DynamicSpacing::Base04.px(cx))
+                        .pr(DynamicSpacing::Base04.rems(cx))
+                } else {
+                    this.ml(indent_width).px(DynamicSpacing::Base04.rems(cx))
+                }
+            })
+            .when(self.tree_guide, |this| {
+                // The guides sit in the indent space to the left of the item's content,
+                // centered within each indent step.
+                let indent_step_size = self.indent_step_size;
+                this.children((0..self.indent_level).map(|level| {
+                    div()
+                        .absolute()
+                        .top_0()
+                        .bottom_0()
+                        .left(level as f32 * indent_step_size + indent_step_size / 2.)
+                        .border_l_1()
+                        .border_color(cx.theme().colors().panel_indent_guide)
+                }))
             })
             .when(!self.inset && !self.disabled, |this| {
                 this
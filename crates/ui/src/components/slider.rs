@@ -0,0 +1,300 @@
+#![allow(missing_docs)]
+
+use std::rc::Rc;
+
+use gpui::{DragMoveEvent, FocusHandle, KeyDownEvent, Render};
+
+use crate::prelude::*;
+
+const THUMB_SIZE: Pixels = px(12.);
+
+/// # Slider
+///
+/// A horizontal track with a thumb that can be dragged to pick a value within a
+/// range, such as an opacity or a zoom level. The current value is shown to the
+/// right of the track.
+///
+/// When the slider is focused, the left and right arrow keys move the value down
+/// and up by one step.
+#[derive(IntoElement)]
+pub struct Slider {
+    id: ElementId,
+    value: f32,
+    min: f32,
+    max: f32,
+    step: f32,
+    focus_handle: Option<FocusHandle>,
+    on_change: Rc<dyn Fn(f32, &mut WindowContext) + 'static>,
+}
+
+impl Slider {
+    /// Creates a slider for a value between 0 and 1, changed in steps of 0.01.
+    pub fn new(
+        id: impl Into<ElementId>,
+        value: f32,
+        on_change: impl Fn(f32, &mut WindowContext) + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            value,
+            min: 0.,
+            max: 1.,
+            step: 0.01,
+            focus_handle: None,
+            on_change: Rc::new(on_change),
+        }
+    }
+
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Tracks the given focus handle, allowing the slider to be adjusted with the arrow keys.
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+}
+
+/// Clamps the value to the range and rounds it to the nearest step from `min`.
+fn snap(value: f32, min: f32, max: f32, step: f32) -> f32 {
+    let value = value.clamp(min, max);
+    if step <= 0. {
+        return value;
+    }
+    (min + ((value - min) / step).round() * step).clamp(min, max)
+}
+
+/// Formats the value with as many decimal places as the step has.
+fn format_value(value: f32, step: f32) -> String {
+    let decimals = step
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len());
+    format!("{value:.decimals$}")
+}
+
+/// The payload of a slider's thumb while it's being dragged.
+#[derive(Clone)]
+struct DraggedSliderThumb {
+    id: ElementId,
+}
+
+impl Render for DraggedSliderThumb {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        gpui::Empty
+    }
+}
+
+impl RenderOnce for Slider {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let Self {
+            id,
+            value,
+            min,
+            max,
+            step,
+            focus_handle,
+            on_change,
+        } = self;
+
+        let value = value.clamp(min, max);
+        let ratio = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.
+        };
+        let is_focused = focus_handle
+            .as_ref()
+            .map_or(false, |focus_handle| focus_handle.is_focused(cx));
+        let colors = cx.theme().colors();
+
+        let set_value = Rc::new(move |new_value: f32, cx: &mut WindowContext| {
+            let new_value = snap(new_value, min, max, step);
+            if new_value != value {
+                on_change(new_value, cx);
+                cx.refresh();
+            }
+        });
+
+        h_flex()
+            .id(id.clone())
+            .gap_2()
+            .when_some(focus_handle.as_ref(), |this, focus_handle| {
+                this.track_focus(focus_handle)
+            })
+            .on_key_down({
+                let set_value = set_value.clone();
+                move |event: &KeyDownEvent, cx| {
+                    match event.keystroke.key.as_str() {
+                        "left" => set_value(value - step, cx),
+                        "right" => set_value(value + step, cx),
+                        _ => return,
+                    }
+                    cx.stop_propagation();
+                }
+            })
+            .child(
+                div()
+                    .id("slider-track")
+                    .relative()
+                    .w_40()
+                    .h(THUMB_SIZE)
+                    .flex()
+                    .items_center()
+                    .on_drag_move({
+                        let id = id.clone();
+                        move |event: &DragMoveEvent<DraggedSliderThumb>, cx| {
+                            if event.drag(cx).id != id {
+                                return;
+                            }
+
+                            let bounds = event.bounds;
+                            let ratio =
+                                (event.event.position.x - bounds.left()) / bounds.size.width;
+                            set_value(min + ratio * (max - min), cx);
+                        }
+                    })
+                    .child(
+                        div()
+                            .w_full()
+                            .h_1()
+                            .rounded_full()
+                            .bg(colors.element_background)
+                            .child(
+                                div()
+                                    .h_full()
+                                    .w(relative(ratio))
+                                    .rounded_full()
+                                    .bg(colors.text_accent),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .id("slider-thumb")
+                            .absolute()
+                            .top_0()
+                            .left(relative(ratio))
+                            .ml(-THUMB_SIZE / 2.)
+                            .size(THUMB_SIZE)
+                            .rounded_full()
+                            .border_1()
+                            .border_color(if is_focused {
+                                colors.border_focused
+                            } else {
+                                colors.border
+                            })
+                            .bg(colors.text_accent)
+                            .cursor_col_resize()
+                            .on_drag(DraggedSliderThumb { id }, |thumb, _, cx| {
+                                cx.stop_propagation();
+                                cx.new_view(|_| thumb.clone())
+                            }),
+                    ),
+            )
+            .child(
+                Label::new(format_value(value, step))
+                    .size(LabelSize::Small)
+                    .color(Color::Muted),
+            )
+    }
+}
+
+impl ComponentPreview for Slider {
+    fn description() -> impl Into<Option<&'static str>> {
+        "A track with a draggable thumb for picking a value within a range."
+    }
+
+    fn examples(_: &mut WindowContext) -> Vec<ComponentExampleGroup<Self>> {
+        vec![example_group(vec![
+            single_example("Default", Slider::new("slider_default", 0.4, |_, _| {})),
+            single_example(
+                "Zoom Level",
+                Slider::new("slider_zoom", 150., |_, _| {})
+                    .min(50.)
+                    .max(200.)
+                    .step(10.),
+            ),
+        ])]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use gpui::{FocusHandle, TestAppContext};
+    use settings::SettingsStore;
+
+    use super::{format_value, snap, Slider};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_snap() {
+        assert_eq!(snap(0.42, 0., 1., 0.1), 0.4);
+        assert_eq!(snap(1.5, 0., 1., 0.1), 1.);
+        assert_eq!(snap(-3., 0., 1., 0.1), 0.);
+        assert_eq!(snap(57., 50., 200., 10.), 60.);
+        assert_eq!(snap(0.42, 0., 1., 0.), 0.42);
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(0.4, 0.01), "0.40");
+        assert_eq!(format_value(0.5, 0.1), "0.5");
+        assert_eq!(format_value(150., 10.), "150");
+    }
+
+    struct SliderView {
+        value: Rc<Cell<f32>>,
+        focus_handle: FocusHandle,
+    }
+
+    impl Render for SliderView {
+        fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+            let value = self.value.clone();
+            Slider::new("slider", self.value.get(), move |new_value, _| {
+                value.set(new_value)
+            })
+            .min(0.)
+            .max(10.)
+            .step(2.)
+            .track_focus(&self.focus_handle)
+        }
+    }
+
+    #[gpui::test]
+    fn test_slider_keyboard(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let store = SettingsStore::test(cx);
+            cx.set_global(store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+        });
+
+        let value = Rc::new(Cell::new(4.));
+        let (view, cx) = cx.add_window_view(|cx| SliderView {
+            value: value.clone(),
+            focus_handle: cx.focus_handle(),
+        });
+        cx.run_until_parked();
+        cx.update(|cx| view.read(cx).focus_handle.focus(cx));
+
+        cx.simulate_keystrokes("right");
+        assert_eq!(value.get(), 6.);
+        cx.simulate_keystrokes("left left left left");
+        assert_eq!(value.get(), 0.);
+        cx.simulate_keystrokes("left");
+        assert_eq!(value.get(), 0.);
+    }
+}
@@ -0,0 +1,110 @@
+#![allow(missing_docs)]
+
+use gpui::{Action, Hsla, NoAction, NotificationLevel};
+
+use crate::prelude::*;
+
+/// Returns the theme's status color for notifications of the given level.
+pub fn notification_level_color(level: NotificationLevel, cx: &WindowContext) -> Hsla {
+    let status = cx.theme().status();
+    match level {
+        NotificationLevel::Info => status.info,
+        NotificationLevel::Success => status.success,
+        NotificationLevel::Warning => status.warning,
+        NotificationLevel::Error => status.error,
+    }
+}
+
+/// # Toast
+///
+/// A short, dismissable notification with a border colored by its [`NotificationLevel`].
+///
+/// The close button dispatches `dismiss_action`, so the owner of the toast decides
+/// how it is removed.
+#[derive(IntoElement)]
+pub struct Toast {
+    title: SharedString,
+    level: NotificationLevel,
+    dismiss_action: Box<dyn Action>,
+}
+
+impl Toast {
+    pub fn new(
+        title: impl Into<SharedString>,
+        level: NotificationLevel,
+        dismiss_action: Box<dyn Action>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            level,
+            dismiss_action,
+        }
+    }
+}
+
+impl RenderOnce for Toast {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let dismiss_action = self.dismiss_action;
+
+        h_flex()
+            .min_w_64()
+            .max_w_96()
+            .gap_2()
+            .py_1()
+            .pl_3()
+            .pr_1()
+            .justify_between()
+            .elevation_2(cx)
+            .border_l_4()
+            .border_color(notification_level_color(self.level, cx))
+            .child(Label::new(self.title))
+            .child(
+                IconButton::new("dismiss-toast", IconName::Close)
+                    .icon_size(IconSize::Small)
+                    .on_click(move |_, cx| cx.dispatch_action(dismiss_action.boxed_clone())),
+            )
+    }
+}
+
+impl ComponentPreview for Toast {
+    fn description() -> impl Into<Option<&'static str>> {
+        "A short, dismissable notification shown on top of the window."
+    }
+
+    fn examples(_: &mut WindowContext) -> Vec<ComponentExampleGroup<Self>> {
+        vec![example_group(vec![
+            single_example(
+                "Info",
+                Toast::new(
+                    "Indexing project",
+                    NotificationLevel::Info,
+                    Box::new(NoAction),
+                ),
+            ),
+            single_example(
+                "Success",
+                Toast::new(
+                    "Extension installed",
+                    NotificationLevel::Success,
+                    Box::new(NoAction),
+                ),
+            ),
+            single_example(
+                "Warning",
+                Toast::new(
+                    "Language server is slow to respond",
+                    NotificationLevel::Warning,
+                    Box::new(NoAction),
+                ),
+            ),
+            single_example(
+                "Error",
+                Toast::new(
+                    "Failed to save file",
+                    NotificationLevel::Error,
+                    Box::new(NoAction),
+                ),
+            ),
+        ])]
+    }
+}
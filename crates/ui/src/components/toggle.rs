@@ -1,7 +1,13 @@
 #![allow(missing_docs)]
 
-use gpui::{div, prelude::*, ElementId, IntoElement, Styled, WindowContext};
-use std::sync::Arc;
+use gpui::{
+    div, ease_in_out, prelude::*, AnyElement, Bounds, Div, Element, ElementId, GlobalElementId,
+    IntoElement, LayoutId, Pixels, Styled, WindowContext,
+};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::prelude::*;
 use crate::utils::is_light;
@@ -328,6 +334,224 @@ impl RenderOnce for SwitchWithLabel {
     }
 }
 
+/// # Toggle
+///
+/// A pill-shaped switch for turning a single setting on or off, whose thumb slides
+/// across the track when the setting changes.
+#[derive(IntoElement)]
+pub struct Toggle {
+    id: ElementId,
+    on: bool,
+    size: ToggleSize,
+    disabled: bool,
+    on_change: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
+}
+
+/// The size of a [`Toggle`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ToggleSize {
+    Small,
+    #[default]
+    Medium,
+}
+
+impl ToggleSize {
+    fn track_size(self) -> (DynamicSpacing, DynamicSpacing) {
+        match self {
+            Self::Small => (DynamicSpacing::Base24, DynamicSpacing::Base16),
+            Self::Medium => (DynamicSpacing::Base32, DynamicSpacing::Base20),
+        }
+    }
+
+    fn thumb_size(self) -> DynamicSpacing {
+        match self {
+            Self::Small => DynamicSpacing::Base08,
+            Self::Medium => DynamicSpacing::Base12,
+        }
+    }
+}
+
+impl Toggle {
+    pub fn new(id: impl Into<ElementId>, on: bool) -> Self {
+        Self {
+            id: id.into(),
+            on,
+            size: ToggleSize::default(),
+            disabled: false,
+            on_change: None,
+        }
+    }
+
+    pub fn size(mut self, size: ToggleSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Called with the new state when the toggle is clicked.
+    pub fn on_change(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Box::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Toggle {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        let track_color = if self.on {
+            colors.text_accent
+        } else {
+            colors.element_background
+        };
+        let border_color = if self.on {
+            colors.text_accent
+        } else {
+            colors.border_variant
+        };
+        let thumb_color = if self.on {
+            colors.editor_background
+        } else {
+            colors.text.opacity(0.5)
+        };
+
+        let (track_width, track_height) = self.size.track_size();
+        let thumb_size = self.size.thumb_size();
+        let padding = DynamicSpacing::Base02.px(cx);
+        // The distance the thumb slides, within the track's padding and its 1px border.
+        let thumb_travel = track_width.px(cx) - thumb_size.px(cx) - padding * 2. - px(2.);
+
+        let on = self.on;
+        h_flex()
+            .id(self.id)
+            .flex_none()
+            .items_center()
+            .w(track_width.rems(cx))
+            .h(track_height.rems(cx))
+            .px(padding)
+            .rounded_full()
+            .bg(track_color)
+            .border_1()
+            .border_color(border_color)
+            .when(self.disabled, |this| this.opacity(0.5))
+            .child(ToggleThumb {
+                on,
+                travel: thumb_travel,
+                thumb: Some(
+                    div()
+                        .size(thumb_size.rems(cx))
+                        .rounded_full()
+                        .bg(thumb_color),
+                ),
+            })
+            .when_some(
+                self.on_change.filter(|_| !self.disabled),
+                |this, on_change| this.on_click(move |_, cx| on_change(&!on, cx)),
+            )
+    }
+}
+
+/// How long the thumb of a [`Toggle`] takes to slide across its track.
+const TOGGLE_SLIDE_DURATION: Duration = Duration::from_millis(150);
+
+/// The thumb of a [`Toggle`]. It remembers the state it was last drawn in, so that it only
+/// slides when the state changes, not when the toggle is first shown.
+struct ToggleThumb {
+    on: bool,
+    travel: Pixels,
+    thumb: Option<Div>,
+}
+
+struct ToggleThumbState {
+    on: bool,
+    changed_at: Option<Instant>,
+}
+
+impl IntoElement for ToggleThumb {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ToggleThumb {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some("toggle-thumb".into())
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        cx.with_element_state(id.unwrap(), |state: Option<ToggleThumbState>, cx| {
+            let mut state = state.unwrap_or(ToggleThumbState {
+                on: self.on,
+                changed_at: None,
+            });
+            if state.on != self.on {
+                state = ToggleThumbState {
+                    on: self.on,
+                    changed_at: Some(Instant::now()),
+                };
+            }
+
+            let progress = match state.changed_at {
+                Some(changed_at) => {
+                    let progress =
+                        changed_at.elapsed().as_secs_f32() / TOGGLE_SLIDE_DURATION.as_secs_f32();
+                    if progress < 1. {
+                        cx.request_animation_frame();
+                    }
+                    ease_in_out(progress.min(1.))
+                }
+                None => 1.,
+            };
+            let offset = if self.on {
+                self.travel * progress
+            } else {
+                self.travel * (1. - progress)
+            };
+
+            let mut thumb = self
+                .thumb
+                .take()
+                .expect("should only be called once")
+                .ml(offset)
+                .into_any_element();
+            ((thumb.request_layout(cx), thumb), state)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        thumb: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) {
+        thumb.prepaint(cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        thumb: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        thumb.paint(cx);
+    }
+}
+
 impl ComponentPreview for Checkbox {
     fn description() -> impl Into<Option<&'static str>> {
         "A checkbox lets people choose between a pair of opposing states, like enabled and disabled, using a different appearance to indicate each state."
@@ -482,3 +706,44 @@ impl ComponentPreview for SwitchWithLabel {
         ])]
     }
 }
+
+impl ComponentPreview for Toggle {
+    fn description() -> impl Into<Option<&'static str>> {
+        "A toggle turns a single setting on or off, sliding its thumb across the track when the setting changes."
+    }
+
+    fn examples(_: &mut WindowContext) -> Vec<ComponentExampleGroup<Self>> {
+        vec![
+            example_group_with_title(
+                "Medium",
+                vec![
+                    single_example("Off", Toggle::new("toggle_medium_off", false)),
+                    single_example("On", Toggle::new("toggle_medium_on", true)),
+                ],
+            ),
+            example_group_with_title(
+                "Small",
+                vec![
+                    single_example(
+                        "Off",
+                        Toggle::new("toggle_small_off", false).size(ToggleSize::Small),
+                    ),
+                    single_example(
+                        "On",
+                        Toggle::new("toggle_small_on", true).size(ToggleSize::Small),
+                    ),
+                ],
+            ),
+            example_group_with_title(
+                "Disabled",
+                vec![
+                    single_example(
+                        "Off",
+                        Toggle::new("toggle_disabled_off", false).disabled(true),
+                    ),
+                    single_example("On", Toggle::new("toggle_disabled_on", true).disabled(true)),
+                ],
+            ),
+        ]
+    }
+}
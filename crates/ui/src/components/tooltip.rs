@@ -23,6 +23,21 @@ impl Tooltip {
         .into()
     }
 
+    /// Creates a tooltip showing the given shortcut, such as `cmd-k cmd-s`, for
+    /// when the shortcut is not bound to an action.
+    pub fn with_shortcut(
+        title: impl Into<SharedString>,
+        shortcut: &str,
+        cx: &mut WindowContext,
+    ) -> AnyView {
+        cx.new_view(|_cx| Self {
+            title: title.into(),
+            meta: None,
+            key_binding: KeyBinding::from_keystrokes(shortcut),
+        })
+        .into()
+    }
+
     pub fn for_action(
         title: impl Into<SharedString>,
         action: &dyn Action,
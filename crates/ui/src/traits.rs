@@ -3,5 +3,6 @@ pub mod component_preview;
 pub mod disableable;
 pub mod fixed;
 pub mod styled_ext;
+pub mod styled_text_ext;
 pub mod toggleable;
 pub mod visible_on_hover;
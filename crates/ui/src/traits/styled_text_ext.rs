@@ -0,0 +1,25 @@
+use std::ops::Range;
+
+use gpui::{AppContext, StyledText};
+use theme::ActiveTheme;
+
+/// Extends [`StyledText`] with highlights in the colors of the current theme.
+pub trait StyledTextExt {
+    /// Highlights the given ranges of the text as search matches, using the
+    /// theme's search match background.
+    fn with_search_matches(
+        self,
+        matches: impl IntoIterator<Item = Range<usize>>,
+        cx: &AppContext,
+    ) -> Self;
+}
+
+impl StyledTextExt for StyledText {
+    fn with_search_matches(
+        self,
+        matches: impl IntoIterator<Item = Range<usize>>,
+        cx: &AppContext,
+    ) -> Self {
+        self.with_background_highlights(matches, cx.theme().colors().search_match_background)
+    }
+}
@@ -0,0 +1,269 @@
+use editor::{
+    actions::{Backspace, Tab, TabPrev},
+    EditorEvent,
+};
+use gpui::{AppContext, EventEmitter, FocusHandle, FocusableView, Subscription, View};
+use ui::{prelude::*, IconButtonShape};
+
+use crate::TextField;
+
+pub enum ChipInputEvent {
+    /// A chip was entered, and appended to the chips.
+    Added(SharedString),
+    /// The chip at the given index was removed.
+    Removed(usize),
+}
+
+/// A multi-value text input that renders each value as a removable pill,
+/// followed by a text field for entering the next value.
+///
+/// Pressing `enter` or typing `,` turns the entered text into a new chip. `tab` cycles
+/// through the existing chips before returning to the text field, and `backspace`
+/// removes the selected chip, or selects the last chip when the text field is empty.
+pub struct ChipInput {
+    chips: Vec<SharedString>,
+    text_field: View<TextField>,
+    /// The chip selected by cycling through the chips with `tab`, if any.
+    selected_chip: Option<usize>,
+    _subscription: Subscription,
+}
+
+impl ChipInput {
+    pub fn new(
+        chips: impl IntoIterator<Item = impl Into<SharedString>>,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let placeholder = placeholder.into();
+        let text_field = cx.new_view(|cx| TextField::new(cx, "", placeholder));
+        let editor = text_field.read(cx).editor().clone();
+        let subscription = cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| {
+            if let EditorEvent::BufferEdited = event {
+                this.text_edited(cx);
+            }
+        });
+
+        Self {
+            chips: chips.into_iter().map(Into::into).collect(),
+            text_field,
+            selected_chip: None,
+            _subscription: subscription,
+        }
+    }
+
+    pub fn chips(&self) -> &[SharedString] {
+        &self.chips
+    }
+
+    pub fn selected_chip(&self) -> Option<usize> {
+        self.selected_chip
+    }
+
+    pub fn set_chips(
+        &mut self,
+        chips: impl IntoIterator<Item = impl Into<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.chips = chips.into_iter().map(Into::into).collect();
+        self.selected_chip = None;
+        cx.notify();
+    }
+
+    /// Turns any text entered before a `,` into chips.
+    fn text_edited(&mut self, cx: &mut ViewContext<Self>) {
+        self.selected_chip = None;
+
+        let text = self.text_field.read(cx).text(cx);
+        let Some((entered, rest)) = text.rsplit_once(',') else {
+            cx.notify();
+            return;
+        };
+
+        let rest = rest.to_string();
+        for chip in entered.split(',') {
+            self.add_chip(chip, cx);
+        }
+        self.text_field
+            .update(cx, |text_field, cx| text_field.set_text(rest, cx));
+        cx.notify();
+    }
+
+    fn add_chip(&mut self, chip: &str, cx: &mut ViewContext<Self>) {
+        let chip = chip.trim();
+        if chip.is_empty() {
+            return;
+        }
+
+        let chip = SharedString::from(chip.to_string());
+        self.chips.push(chip.clone());
+        cx.emit(ChipInputEvent::Added(chip));
+    }
+
+    fn remove_chip(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.chips.len() {
+            return;
+        }
+
+        self.chips.remove(ix);
+        self.selected_chip = None;
+        cx.emit(ChipInputEvent::Removed(ix));
+        cx.notify();
+    }
+
+    fn confirm(&mut self, _: &menu::Confirm, cx: &mut ViewContext<Self>) {
+        let text = self.text_field.read(cx).text(cx);
+        if text.trim().is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.add_chip(&text, cx);
+        self.text_field
+            .update(cx, |text_field, cx| text_field.set_text("", cx));
+        cx.notify();
+    }
+
+    fn select_next_chip(&mut self, _: &Tab, cx: &mut ViewContext<Self>) {
+        if self.chips.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.selected_chip = match self.selected_chip {
+            Some(ix) if ix + 1 < self.chips.len() => Some(ix + 1),
+            Some(_) => None,
+            None => Some(0),
+        };
+        cx.stop_propagation();
+        cx.notify();
+    }
+
+    fn select_prev_chip(&mut self, _: &TabPrev, cx: &mut ViewContext<Self>) {
+        if self.chips.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.selected_chip = match self.selected_chip {
+            Some(0) => None,
+            Some(ix) => Some(ix - 1),
+            None => self.chips.len().checked_sub(1),
+        };
+        cx.stop_propagation();
+        cx.notify();
+    }
+
+    fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.selected_chip {
+            self.remove_chip(ix, cx);
+        } else if self.text_field.read(cx).text(cx).is_empty() && !self.chips.is_empty() {
+            self.selected_chip = Some(self.chips.len() - 1);
+            cx.notify();
+        } else {
+            cx.propagate();
+            return;
+        }
+        cx.stop_propagation();
+    }
+}
+
+impl EventEmitter<ChipInputEvent> for ChipInput {}
+
+impl FocusableView for ChipInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.text_field.read(cx).focus_handle(cx)
+    }
+}
+
+impl Render for ChipInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        let is_focused = self.focus_handle(cx).contains_focused(cx);
+
+        let chips = self.chips.iter().enumerate().map(|(ix, chip)| {
+            let is_selected = is_focused && self.selected_chip == Some(ix);
+
+            h_flex()
+                .gap_0p5()
+                .pl_1p5()
+                .pr_0p5()
+                .rounded_md()
+                .bg(colors.element_background)
+                .border_1()
+                .border_color(if is_selected {
+                    colors.border_focused
+                } else {
+                    colors.border_variant
+                })
+                .child(Label::new(chip.clone()).size(LabelSize::Small))
+                .child(
+                    IconButton::new(("remove_chip", ix), IconName::Close)
+                        .shape(IconButtonShape::Square)
+                        .icon_size(IconSize::XSmall)
+                        .on_click(cx.listener(move |this, _, cx| this.remove_chip(ix, cx))),
+                )
+        });
+
+        h_flex()
+            .flex_wrap()
+            .gap_1()
+            .w_full()
+            .capture_action(cx.listener(Self::select_next_chip))
+            .capture_action(cx.listener(Self::select_prev_chip))
+            .capture_action(cx.listener(Self::backspace))
+            .on_action(cx.listener(Self::confirm))
+            .children(chips)
+            .child(div().flex_1().min_w_32().child(self.text_field.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use editor::actions::{Backspace, Tab};
+    use gpui::{FocusableView as _, SharedString, TestAppContext};
+
+    use super::ChipInput;
+    use crate::test::init_test;
+
+    #[gpui::test]
+    fn test_chip_input(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (input, cx) =
+            cx.add_window_view(|cx| ChipInput::new(Vec::<SharedString>::new(), "Add a filter", cx));
+        cx.update(|cx| input.read(cx).focus_handle(cx).focus(cx));
+        let chips = |cx: &mut gpui::VisualTestContext| cx.update(|cx| input.read(cx).chips.clone());
+
+        cx.simulate_input("*.rs,*.toml");
+        assert_eq!(chips(cx), vec![SharedString::from("*.rs")]);
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(
+            chips(cx),
+            vec![SharedString::from("*.rs"), SharedString::from("*.toml")]
+        );
+        assert_eq!(
+            cx.update(|cx| input.read(cx).text_field.read(cx).text(cx)),
+            ""
+        );
+
+        cx.dispatch_action(Tab);
+        cx.dispatch_action(Tab);
+        assert_eq!(cx.update(|cx| input.read(cx).selected_chip()), Some(1));
+        cx.dispatch_action(Tab);
+        assert_eq!(cx.update(|cx| input.read(cx).selected_chip()), None);
+
+        cx.dispatch_action(Tab);
+        cx.dispatch_action(Backspace);
+        assert_eq!(chips(cx), vec![SharedString::from("*.toml")]);
+        assert_eq!(cx.update(|cx| input.read(cx).selected_chip()), None);
+
+        cx.simulate_input("x");
+        cx.dispatch_action(Backspace);
+        assert_eq!(
+            cx.update(|cx| input.read(cx).text_field.read(cx).text(cx)),
+            ""
+        );
+        cx.dispatch_action(Backspace);
+        assert_eq!(cx.update(|cx| input.read(cx).selected_chip()), Some(0));
+    }
+}
@@ -0,0 +1,295 @@
+use editor::EditorEvent;
+use fuzzy::{StringMatch, StringMatchCandidate};
+use gpui::{
+    anchored, deferred, AppContext, EventEmitter, FocusHandle, FocusableView, ScrollHandle,
+    Subscription, Task, View,
+};
+use ui::{prelude::*, HighlightedLabel};
+
+use crate::{FieldLabelLayout, TextField};
+
+/// The maximum number of suggestions shown for a query.
+const MAX_MATCHES: usize = 100;
+
+pub enum ComboboxEvent<T> {
+    /// A suggestion was selected, either by clicking it or by pressing `enter`.
+    Selected(T),
+}
+
+/// A text field with a dropdown of suggestions below it, fuzzy-filtered by the
+/// entered text as it is typed.
+///
+/// While the field is focused, `up` and `down` move through the matching
+/// suggestions, `enter` selects the highlighted one, and `escape` dismisses
+/// them. Suggestions can also be selected by clicking them.
+pub struct Combobox<T: Clone + 'static> {
+    options: Vec<(SharedString, T)>,
+    text_field: View<TextField>,
+    matches: Vec<StringMatch>,
+    /// The index of the highlighted suggestion, among the matches.
+    selected_index: usize,
+    /// The query for which the suggestions were dismissed, either by selecting one or
+    /// pressing `escape`. They are shown again once the query changes.
+    dismissed_query: Option<String>,
+    scroll_handle: ScrollHandle,
+    pending_matches: Task<()>,
+    _subscription: Subscription,
+}
+
+impl<T: Clone + 'static> Combobox<T> {
+    pub fn new(
+        options: Vec<(SharedString, T)>,
+        label: impl Into<SharedString>,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let label = label.into();
+        let placeholder = placeholder.into();
+        let text_field = cx.new_view(|cx| {
+            let layout = if label.is_empty() {
+                FieldLabelLayout::Hidden
+            } else {
+                FieldLabelLayout::Stacked
+            };
+            TextField::new(cx, label, placeholder).with_label(layout)
+        });
+        let editor = text_field.read(cx).editor().clone();
+        let subscription = cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| {
+            if let EditorEvent::BufferEdited = event {
+                this.update_matches(cx);
+            }
+        });
+
+        let mut this = Self {
+            options,
+            text_field,
+            matches: Vec::new(),
+            selected_index: 0,
+            dismissed_query: None,
+            scroll_handle: ScrollHandle::new(),
+            pending_matches: Task::ready(()),
+            _subscription: subscription,
+        };
+        this.update_matches(cx);
+        this
+    }
+
+    pub fn query(&self, cx: &AppContext) -> String {
+        self.text_field.read(cx).text(cx)
+    }
+
+    pub fn set_query(&mut self, query: impl Into<String>, cx: &mut ViewContext<Self>) {
+        let query = query.into();
+        self.text_field
+            .update(cx, |text_field, cx| text_field.set_text(query, cx));
+    }
+
+    fn is_dismissed(&self, cx: &AppContext) -> bool {
+        self.dismissed_query
+            .as_ref()
+            .map_or(false, |dismissed_query| *dismissed_query == self.query(cx))
+    }
+
+    fn update_matches(&mut self, cx: &mut ViewContext<Self>) {
+        let query = self.query(cx);
+        let candidates = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(ix, (label, _))| StringMatchCandidate::new(ix, label))
+            .collect::<Vec<_>>();
+        let executor = cx.background_executor().clone();
+
+        self.pending_matches = cx.spawn(|this, mut cx| async move {
+            let matches = fuzzy::match_strings(
+                &candidates,
+                &query,
+                false,
+                MAX_MATCHES,
+                &Default::default(),
+                executor,
+            )
+            .await;
+
+            this.update(&mut cx, |this, cx| {
+                this.matches = matches;
+                this.selected_index = 0;
+                this.scroll_handle.scroll_to_item(0);
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn select_next(&mut self, _: &menu::SelectNext, cx: &mut ViewContext<Self>) {
+        if self.is_dismissed(cx) || self.matches.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.selected_index = (self.selected_index + 1).min(self.matches.len() - 1);
+        self.scroll_handle.scroll_to_item(self.selected_index);
+        cx.notify();
+    }
+
+    fn select_prev(&mut self, _: &menu::SelectPrev, cx: &mut ViewContext<Self>) {
+        if self.is_dismissed(cx) || self.matches.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.selected_index = self.selected_index.saturating_sub(1);
+        self.scroll_handle.scroll_to_item(self.selected_index);
+        cx.notify();
+    }
+
+    fn confirm(&mut self, _: &menu::Confirm, cx: &mut ViewContext<Self>) {
+        if self.is_dismissed(cx) || self.matches.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.select(self.selected_index, cx);
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        if self.is_dismissed(cx) || self.matches.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.dismissed_query = Some(self.query(cx));
+        cx.notify();
+    }
+
+    fn select(&mut self, match_ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(option_ix) = self.matches.get(match_ix).map(|m| m.candidate_id) else {
+            return;
+        };
+
+        let (label, value) = self.options[option_ix].clone();
+        self.set_query(label.clone(), cx);
+        self.dismissed_query = Some(label.to_string());
+        cx.emit(ComboboxEvent::Selected(value));
+        cx.notify();
+    }
+}
+
+impl<T: Clone + 'static> EventEmitter<ComboboxEvent<T>> for Combobox<T> {}
+
+impl<T: Clone + 'static> FocusableView for Combobox<T> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.text_field.read(cx).focus_handle(cx)
+    }
+}
+
+impl<T: Clone + 'static> Render for Combobox<T> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        let is_focused = self.focus_handle(cx).contains_focused(cx);
+
+        let show_suggestions = is_focused && !self.is_dismissed(cx) && !self.matches.is_empty();
+        let suggestions = show_suggestions.then(|| {
+            let options = self.matches.iter().enumerate().map(|(ix, string_match)| {
+                h_flex()
+                    .id(("combobox-option", ix))
+                    .px_2()
+                    .py_0p5()
+                    .rounded_sm()
+                    .cursor_pointer()
+                    .when(ix == self.selected_index, |this| {
+                        this.bg(colors.element_selected)
+                    })
+                    .hover(|style| style.bg(colors.element_hover))
+                    .child(HighlightedLabel::new(
+                        string_match.string.clone(),
+                        string_match.positions.clone(),
+                    ))
+                    .on_click(cx.listener(move |this, _, cx| this.select(ix, cx)))
+            });
+
+            div().child(deferred(
+                anchored().snap_to_window_with_margin(px(8.)).child(
+                    v_flex()
+                        .id("combobox-suggestions")
+                        .occlude()
+                        .mt_1()
+                        .p_1()
+                        .min_w_48()
+                        .max_h_64()
+                        .overflow_y_scroll()
+                        .track_scroll(&self.scroll_handle)
+                        .elevation_2(cx)
+                        .children(options),
+                ),
+            ))
+        });
+
+        v_flex()
+            .w_full()
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::cancel))
+            .child(self.text_field.clone())
+            .children(suggestions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use gpui::{FocusableView as _, SharedString, TestAppContext};
+
+    use super::{Combobox, ComboboxEvent};
+    use crate::test::init_test;
+
+    #[gpui::test]
+    async fn test_combobox(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let options = ["Rust", "Python", "TypeScript", "JavaScript"]
+            .into_iter()
+            .map(|language| (SharedString::from(language), language))
+            .collect();
+        let (combobox, cx) = cx.add_window_view(|cx| Combobox::new(options, "Language", "", cx));
+        let selected = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            cx.subscribe(&combobox, {
+                let selected = selected.clone();
+                move |_, event: &ComboboxEvent<&'static str>, _| match event {
+                    ComboboxEvent::Selected(value) => selected.borrow_mut().push(*value),
+                }
+            })
+            .detach();
+            combobox.read(cx).focus_handle(cx).focus(cx);
+        });
+
+        cx.simulate_input("script");
+        cx.run_until_parked();
+        cx.update(|cx| {
+            let matches = &combobox.read(cx).matches;
+            assert_eq!(matches.len(), 2);
+            assert!(matches
+                .iter()
+                .all(|string_match| string_match.string.ends_with("Script")));
+        });
+
+        cx.dispatch_action(menu::SelectNext);
+        let expected = cx.update(|cx| combobox.read(cx).matches[1].string.clone());
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(*selected.borrow(), vec![expected.as_str()]);
+        assert_eq!(cx.update(|cx| combobox.read(cx).query(cx)), expected);
+
+        // Once a suggestion is selected, the suggestions stay dismissed until the query changes.
+        cx.run_until_parked();
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(selected.borrow().len(), 1);
+
+        cx.simulate_input("x");
+        cx.run_until_parked();
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(selected.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,389 @@
+use chrono::{Datelike, Days, Local, Months, NaiveDate};
+use editor::{
+    actions::{MoveDown, MoveLeft, MoveRight, MoveUp},
+    EditorEvent,
+};
+use gpui::{
+    anchored, deferred, AppContext, EventEmitter, FocusHandle, FocusableView, Subscription, View,
+};
+use ui::prelude::*;
+
+use crate::{FieldLabelLayout, TextField};
+
+/// The format dates are displayed and entered in.
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+pub enum DatePickerEvent {
+    /// A date was selected in the calendar, or entered in the text field.
+    Changed(NaiveDate),
+}
+
+/// A text field for a date, with a month calendar below it while focused.
+///
+/// Dates can be typed in `YYYY-MM-DD` format. In the calendar, the arrow keys
+/// move the highlighted day, `enter` selects it, and `escape` closes the
+/// calendar. Days can also be selected by clicking them.
+pub struct DatePicker {
+    text_field: View<TextField>,
+    selected: Option<NaiveDate>,
+    /// The date highlighted in the calendar, moved with the arrow keys.
+    /// Defaults to the selected date, or today if there is none.
+    highlighted: Option<NaiveDate>,
+    /// The text for which the calendar was dismissed, either by selecting a date or
+    /// pressing `escape`. It is shown again once the entered text changes.
+    dismissed_text: Option<String>,
+    _subscription: Subscription,
+}
+
+impl DatePicker {
+    pub fn new(
+        selected: Option<NaiveDate>,
+        label: impl Into<SharedString>,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let label = label.into();
+        let placeholder = placeholder.into();
+        let text_field = cx.new_view(|cx| {
+            let layout = if label.is_empty() {
+                FieldLabelLayout::Hidden
+            } else {
+                FieldLabelLayout::Stacked
+            };
+            let mut text_field = TextField::new(cx, label, placeholder).with_label(layout);
+            if let Some(date) = selected {
+                text_field.set_text(date.format(DATE_FORMAT).to_string(), cx);
+            }
+            text_field
+        });
+        let editor = text_field.read(cx).editor().clone();
+        let subscription = cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| {
+            if let EditorEvent::BufferEdited = event {
+                this.text_edited(cx);
+            }
+        });
+
+        Self {
+            text_field,
+            selected,
+            highlighted: selected,
+            dismissed_text: None,
+            _subscription: subscription,
+        }
+    }
+
+    pub fn selected(&self) -> Option<NaiveDate> {
+        self.selected
+    }
+
+    pub fn set_selected(&mut self, date: NaiveDate, cx: &mut ViewContext<Self>) {
+        self.selected = Some(date);
+        self.highlighted = Some(date);
+        let text = date.format(DATE_FORMAT).to_string();
+        self.text_field
+            .update(cx, |text_field, cx| text_field.set_text(text, cx));
+        cx.notify();
+    }
+
+    fn text(&self, cx: &AppContext) -> String {
+        self.text_field.read(cx).text(cx)
+    }
+
+    fn highlighted(&self) -> NaiveDate {
+        self.highlighted
+            .or(self.selected)
+            .unwrap_or_else(|| Local::now().date_naive())
+    }
+
+    fn is_calendar_shown(&self, cx: &WindowContext) -> bool {
+        self.focus_handle(cx).contains_focused(cx)
+            && self
+                .dismissed_text
+                .as_ref()
+                .map_or(true, |dismissed_text| *dismissed_text != self.text(cx))
+    }
+
+    /// Selects the entered date once it's complete.
+    fn text_edited(&mut self, cx: &mut ViewContext<Self>) {
+        let Ok(date) = NaiveDate::parse_from_str(self.text(cx).trim(), DATE_FORMAT) else {
+            cx.notify();
+            return;
+        };
+
+        self.highlighted = Some(date);
+        if self.selected != Some(date) {
+            self.selected = Some(date);
+            cx.emit(DatePickerEvent::Changed(date));
+        }
+        cx.notify();
+    }
+
+    fn select(&mut self, date: NaiveDate, cx: &mut ViewContext<Self>) {
+        self.set_selected(date, cx);
+        self.dismissed_text = Some(self.text(cx));
+        cx.emit(DatePickerEvent::Changed(date));
+    }
+
+    fn move_highlighted(
+        &mut self,
+        cx: &mut ViewContext<Self>,
+        move_date: impl FnOnce(NaiveDate) -> Option<NaiveDate>,
+    ) {
+        if !self.is_calendar_shown(cx) {
+            return;
+        }
+
+        let highlighted = self.highlighted();
+        self.highlighted = Some(move_date(highlighted).unwrap_or(highlighted));
+        cx.stop_propagation();
+        cx.notify();
+    }
+
+    fn move_left(&mut self, _: &MoveLeft, cx: &mut ViewContext<Self>) {
+        self.move_highlighted(cx, |date| date.pred_opt());
+    }
+
+    fn move_right(&mut self, _: &MoveRight, cx: &mut ViewContext<Self>) {
+        self.move_highlighted(cx, |date| date.succ_opt());
+    }
+
+    fn move_up(&mut self, _: &MoveUp, cx: &mut ViewContext<Self>) {
+        self.move_highlighted(cx, |date| date.checked_sub_days(Days::new(7)));
+    }
+
+    fn move_down(&mut self, _: &MoveDown, cx: &mut ViewContext<Self>) {
+        self.move_highlighted(cx, |date| date.checked_add_days(Days::new(7)));
+    }
+
+    fn confirm(&mut self, _: &menu::Confirm, cx: &mut ViewContext<Self>) {
+        if !self.is_calendar_shown(cx) {
+            cx.propagate();
+            return;
+        }
+
+        self.select(self.highlighted(), cx);
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        if !self.is_calendar_shown(cx) {
+            cx.propagate();
+            return;
+        }
+
+        self.dismissed_text = Some(self.text(cx));
+        cx.notify();
+    }
+}
+
+/// Returns the weeks of the calendar showing the month containing `date`,
+/// starting on Monday and padded with days from the adjacent months.
+fn calendar_weeks(date: NaiveDate) -> Vec<[NaiveDate; 7]> {
+    let first_of_month = date.with_day(1).unwrap_or(date);
+    let mut day =
+        first_of_month - Days::new(first_of_month.weekday().num_days_from_monday() as u64);
+
+    let mut weeks = Vec::new();
+    while weeks.is_empty() || day.month() == first_of_month.month() {
+        weeks.push(std::array::from_fn(|_| {
+            let current = day;
+            day = day.succ_opt().unwrap_or(day);
+            current
+        }));
+    }
+    weeks
+}
+
+impl EventEmitter<DatePickerEvent> for DatePicker {}
+
+impl FocusableView for DatePicker {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.text_field.read(cx).focus_handle(cx)
+    }
+}
+
+impl Render for DatePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        let selected = self.selected;
+        let highlighted = self.highlighted();
+
+        let calendar = self.is_calendar_shown(cx).then(|| {
+            let month_button = |id: &'static str, icon: IconName, forward: bool| {
+                IconButton::new(id, icon)
+                    .icon_size(IconSize::Small)
+                    .on_click(cx.listener(move |this, _, cx| {
+                        let highlighted = this.highlighted();
+                        let moved = if forward {
+                            highlighted.checked_add_months(Months::new(1))
+                        } else {
+                            highlighted.checked_sub_months(Months::new(1))
+                        };
+                        this.highlighted = moved.or(Some(highlighted));
+                        cx.notify();
+                    }))
+            };
+
+            let header = h_flex()
+                .justify_between()
+                .child(month_button(
+                    "date-picker-previous-month",
+                    IconName::ChevronLeft,
+                    false,
+                ))
+                .child(Label::new(highlighted.format("%B %Y").to_string()))
+                .child(month_button(
+                    "date-picker-next-month",
+                    IconName::ChevronRight,
+                    true,
+                ));
+
+            let weekdays =
+                h_flex().children(["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"].map(|weekday| {
+                    h_flex().w_8().justify_center().child(
+                        Label::new(weekday)
+                            .size(LabelSize::Small)
+                            .color(Color::Muted),
+                    )
+                }));
+
+            let weeks = calendar_weeks(highlighted).into_iter().map(|week| {
+                h_flex().children(week.map(|date| {
+                    let color = if date.month() == highlighted.month() {
+                        Color::Default
+                    } else {
+                        Color::Muted
+                    };
+
+                    h_flex()
+                        .id(("date-picker-day", date.num_days_from_ce() as usize))
+                        .w_8()
+                        .h_6()
+                        .justify_center()
+                        .rounded_sm()
+                        .cursor_pointer()
+                        .when(Some(date) == selected, |this| {
+                            this.bg(colors.element_selected)
+                        })
+                        .when(date == highlighted, |this| {
+                            this.border_1().border_color(colors.border_focused)
+                        })
+                        .hover(|style| style.bg(colors.element_hover))
+                        .child(Label::new(date.day().to_string()).color(color))
+                        .on_click(cx.listener(move |this, _, cx| this.select(date, cx)))
+                }))
+            });
+
+            div().child(deferred(
+                anchored().snap_to_window_with_margin(px(8.)).child(
+                    v_flex()
+                        .id("date-picker-calendar")
+                        .occlude()
+                        .mt_1()
+                        .p_1()
+                        .gap_0p5()
+                        .elevation_2(cx)
+                        .child(header)
+                        .child(weekdays)
+                        .children(weeks),
+                ),
+            ))
+        });
+
+        v_flex()
+            .w_full()
+            .capture_action(cx.listener(Self::move_left))
+            .capture_action(cx.listener(Self::move_right))
+            .capture_action(cx.listener(Self::move_up))
+            .capture_action(cx.listener(Self::move_down))
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::cancel))
+            .child(self.text_field.clone())
+            .children(calendar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use chrono::{Datelike, NaiveDate, Weekday};
+    use editor::actions::{MoveDown, MoveLeft, MoveRight};
+    use gpui::{FocusableView as _, TestAppContext};
+
+    use super::{calendar_weeks, DatePicker, DatePickerEvent};
+    use crate::test::init_test;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_weeks() {
+        // June 2024 starts on a Saturday and ends on a Sunday.
+        let weeks = calendar_weeks(date(2024, 6, 15));
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][0], date(2024, 5, 27));
+        assert_eq!(weeks[0][5], date(2024, 6, 1));
+        assert_eq!(weeks[4][6], date(2024, 6, 30));
+        assert!(weeks.iter().all(|week| week[0].weekday() == Weekday::Mon));
+
+        // February 2021 starts on a Monday and spans exactly four weeks.
+        let weeks = calendar_weeks(date(2021, 2, 1));
+        assert_eq!(weeks.len(), 4);
+        assert_eq!(weeks[0][0], date(2021, 2, 1));
+        assert_eq!(weeks[3][6], date(2021, 2, 28));
+    }
+
+    #[gpui::test]
+    fn test_date_picker(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (picker, cx) =
+            cx.add_window_view(|cx| DatePicker::new(Some(date(2024, 6, 15)), "Date", "", cx));
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            cx.subscribe(&picker, {
+                let changes = changes.clone();
+                move |_, event: &DatePickerEvent, _| match event {
+                    DatePickerEvent::Changed(date) => changes.borrow_mut().push(*date),
+                }
+            })
+            .detach();
+            picker.read(cx).focus_handle(cx).focus(cx);
+        });
+
+        cx.dispatch_action(MoveRight);
+        cx.dispatch_action(MoveRight);
+        cx.dispatch_action(MoveDown);
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(*changes.borrow(), vec![date(2024, 6, 24)]);
+        assert_eq!(
+            cx.update(|cx| picker.read(cx).selected()),
+            Some(date(2024, 6, 24))
+        );
+        assert_eq!(cx.update(|cx| picker.read(cx).text(cx)), "2024-06-24");
+
+        // The calendar is closed after selecting, so arrow keys no longer move the date.
+        cx.dispatch_action(MoveLeft);
+        assert_eq!(
+            cx.update(|cx| picker.read(cx).highlighted()),
+            date(2024, 6, 24)
+        );
+
+        // Typing a full date selects it.
+        cx.update(|cx| {
+            picker.update(cx, |picker, cx| {
+                picker
+                    .text_field
+                    .update(cx, |text_field, cx| text_field.set_text("", cx))
+            })
+        });
+        cx.simulate_input("2025-01-02");
+        assert_eq!(
+            cx.update(|cx| picker.read(cx).selected()),
+            Some(date(2025, 1, 2))
+        );
+        assert_eq!(changes.borrow().last(), Some(&date(2025, 1, 2)));
+        assert_eq!(changes.borrow().len(), 2);
+    }
+}
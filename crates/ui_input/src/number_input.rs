@@ -0,0 +1,357 @@
+use std::{fmt::Display, str::FromStr};
+
+use editor::EditorEvent;
+use gpui::{AppContext, EventEmitter, FocusHandle, FocusableView, Subscription, View};
+use ui::{prelude::*, IconButtonShape};
+
+use crate::TextField;
+
+/// A number that can be edited with a [`NumberInput`].
+pub trait NumberInputValue: Copy + PartialOrd + Display + FromStr + 'static {
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+    const ONE: Self;
+    /// Whether the number can have a fractional part, such as a font size.
+    const FRACTIONAL: bool;
+
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer_input_value {
+    ($($ty:ty),*) => {
+        $(
+            impl NumberInputValue for $ty {
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const FRACTIONAL: bool = false;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$ty>::saturating_add(self, other)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$ty>::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_input_value {
+    ($($ty:ty),*) => {
+        $(
+            impl NumberInputValue for $ty {
+                const MIN: Self = <$ty>::MIN;
+                const MAX: Self = <$ty>::MAX;
+                const ZERO: Self = 0.;
+                const ONE: Self = 1.;
+                const FRACTIONAL: bool = true;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    (self + other).clamp(Self::MIN, Self::MAX)
+                }
+
+                fn saturating_sub(self, other: Self) -> Self {
+                    (self - other).clamp(Self::MIN, Self::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_input_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_float_input_value!(f32, f64);
+
+pub enum NumberInputEvent<T> {
+    /// The value was changed by stepping it or by committing the entered text.
+    Changed(T),
+}
+
+/// A text input for a number within a range, with buttons on either side to
+/// step the value down and up.
+///
+/// Only characters that can form a number can be entered: digits, a leading minus
+/// sign when the range allows negative values, and a decimal point for fractional
+/// numbers. The entered number is validated when pressing `enter` or when the input
+/// loses focus, and clamped to the range.
+pub struct NumberInput<T: NumberInputValue> {
+    text_field: View<TextField>,
+    value: T,
+    min: T,
+    max: T,
+    step: T,
+    /// The last entered text that can still become a number, restored when an
+    /// edit inserts any other characters.
+    valid_text: String,
+    _subscription: Subscription,
+}
+
+impl<T: NumberInputValue> NumberInput<T> {
+    pub fn new(value: T, cx: &mut ViewContext<Self>) -> Self {
+        let text_field = cx.new_view(|cx| {
+            let mut text_field = TextField::new(cx, "", "");
+            text_field.set_text(value.to_string(), cx);
+            text_field
+        });
+        let editor = text_field.read(cx).editor().clone();
+        let subscription = cx.subscribe(&editor, |this, _, event: &EditorEvent, cx| match event {
+            EditorEvent::BufferEdited => this.validate_text(cx),
+            EditorEvent::Blurred => this.commit(cx),
+            _ => {}
+        });
+
+        Self {
+            text_field,
+            value,
+            min: T::MIN,
+            max: T::MAX,
+            step: T::ONE,
+            valid_text: value.to_string(),
+            _subscription: subscription,
+        }
+    }
+
+    pub fn min(mut self, min: T) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: T) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Reverts edits that would make the entered text something other than a number,
+    /// and shows an error while the number is incomplete or out of range.
+    fn validate_text(&mut self, cx: &mut ViewContext<Self>) {
+        let text = self.text_field.read(cx).text(cx);
+        if !is_number_prefix(&text, self.min < T::ZERO, T::FRACTIONAL) {
+            let valid_text = self.valid_text.clone();
+            self.text_field
+                .update(cx, |text_field, cx| text_field.set_text(valid_text, cx));
+            return;
+        }
+
+        let is_valid = text
+            .trim()
+            .parse::<T>()
+            .map_or(false, |value| value >= self.min && value <= self.max);
+        let error = (!text.is_empty() && !is_valid).then(|| {
+            SharedString::from(format!("Enter a number from {} to {}", self.min, self.max))
+        });
+        self.text_field
+            .update(cx, |text_field, cx| text_field.set_error(error, cx));
+        self.valid_text = text;
+    }
+
+    /// Validates the entered text, reverting to the current value if it isn't a number.
+    fn commit(&mut self, cx: &mut ViewContext<Self>) {
+        let text = self.text_field.read(cx).text(cx);
+        let value = parse_clamped(&text, self.min, self.max).unwrap_or(self.value);
+        self.set_value(value, cx);
+    }
+
+    fn set_value(&mut self, value: T, cx: &mut ViewContext<Self>) {
+        let value = clamp(value, self.min, self.max);
+        self.valid_text = value.to_string();
+        let text = self.valid_text.clone();
+        self.text_field.update(cx, |text_field, cx| {
+            text_field.set_text(text, cx);
+            text_field.set_error(None, cx);
+        });
+
+        if value != self.value {
+            self.value = value;
+            cx.emit(NumberInputEvent::Changed(value));
+        }
+        cx.notify();
+    }
+}
+
+fn clamp<T: NumberInputValue>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Returns whether the text is a number, or could become one as more is typed.
+fn is_number_prefix(text: &str, allow_negative: bool, fractional: bool) -> bool {
+    let digits = match text.strip_prefix('-') {
+        Some(digits) if allow_negative => digits,
+        _ => text,
+    };
+    let mut seen_decimal_point = false;
+    digits.chars().all(|c| match c {
+        '0'..='9' => true,
+        '.' if fractional && !seen_decimal_point => {
+            seen_decimal_point = true;
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Parses the entered text and clamps it to the range, treating numbers too
+/// large for the type as the nearest bound. Returns `None` if the text isn't a number.
+fn parse_clamped<T: NumberInputValue>(text: &str, min: T, max: T) -> Option<T> {
+    let text = text.trim();
+    if let Ok(value) = text.parse::<T>() {
+        return Some(clamp(value, min, max));
+    }
+
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    Some(if text.starts_with('-') { min } else { max })
+}
+
+impl<T: NumberInputValue> EventEmitter<NumberInputEvent<T>> for NumberInput<T> {}
+
+impl<T: NumberInputValue> FocusableView for NumberInput<T> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.text_field.read(cx).focus_handle(cx)
+    }
+}
+
+impl<T: NumberInputValue> Render for NumberInput<T> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let shape = IconButtonShape::Square;
+        let icon_size = IconSize::Small;
+
+        h_flex()
+            .gap_1()
+            .items_start()
+            .on_action(cx.listener(|this, _: &menu::Confirm, cx| this.commit(cx)))
+            .child(
+                IconButton::new("decrement", IconName::Dash)
+                    .shape(shape)
+                    .icon_size(icon_size)
+                    .disabled(self.value <= self.min)
+                    .on_click(cx.listener(|this, _, cx| {
+                        let value = this.value.saturating_sub(this.step);
+                        this.set_value(value, cx);
+                    })),
+            )
+            .child(div().w_24().child(self.text_field.clone()))
+            .child(
+                IconButton::new("increment", IconName::Plus)
+                    .shape(shape)
+                    .icon_size(icon_size)
+                    .disabled(self.value >= self.max)
+                    .on_click(cx.listener(|this, _, cx| {
+                        let value = this.value.saturating_add(this.step);
+                        this.set_value(value, cx);
+                    })),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use gpui::{FocusableView as _, TestAppContext, VisualContext as _, VisualTestContext};
+
+    use super::{is_number_prefix, parse_clamped, NumberInput, NumberInputEvent};
+    use crate::test::init_test;
+
+    #[test]
+    fn test_parse_clamped() {
+        assert_eq!(parse_clamped("12", 0u8, 100), Some(12));
+        assert_eq!(parse_clamped(" 12 ", 0u8, 100), Some(12));
+        assert_eq!(parse_clamped("120", 0u8, 100), Some(100));
+        assert_eq!(parse_clamped("3", 6u8, 100), Some(6));
+        assert_eq!(parse_clamped("99999", 0u8, 100), Some(100));
+        assert_eq!(parse_clamped("-99999", -10i8, 10), Some(-10));
+        assert_eq!(parse_clamped("-5", -10i8, 10), Some(-5));
+        assert_eq!(parse_clamped("", 0u8, 100), None);
+        assert_eq!(parse_clamped("-", -10i8, 10), None);
+        assert_eq!(parse_clamped("1a", 0u8, 100), None);
+        assert_eq!(parse_clamped("12.5", 6., 100.), Some(12.5));
+        assert_eq!(parse_clamped(".5", 0., 1.), Some(0.5));
+        assert_eq!(parse_clamped("250.", 6., 100.), Some(100.));
+    }
+
+    #[test]
+    fn test_is_number_prefix() {
+        assert!(is_number_prefix("", false, false));
+        assert!(is_number_prefix("42", false, false));
+        assert!(!is_number_prefix("4.2", false, false));
+        assert!(!is_number_prefix("-4", false, false));
+        assert!(is_number_prefix("-", true, false));
+        assert!(is_number_prefix("-4.", true, true));
+        assert!(is_number_prefix(".5", false, true));
+        assert!(!is_number_prefix("4.2.", false, true));
+        assert!(!is_number_prefix("4e2", false, true));
+    }
+
+    #[gpui::test]
+    fn test_number_input(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let cx = cx.add_empty_window();
+        let input = cx.new_view(|cx| NumberInput::new(14., cx).min(6.).max(100.).step(0.5));
+        let changes = cx.update(|cx| {
+            let changes = Rc::new(RefCell::new(Vec::new()));
+            cx.subscribe(&input, {
+                let changes = changes.clone();
+                move |_, event: &NumberInputEvent<f32>, _| match event {
+                    NumberInputEvent::Changed(value) => changes.borrow_mut().push(*value),
+                }
+            })
+            .detach();
+            changes
+        });
+        let text = |cx: &mut VisualTestContext| {
+            cx.update(|cx| input.read(cx).text_field.read(cx).text(cx))
+        };
+
+        cx.update(|cx| {
+            input.update(cx, |input, cx| {
+                input
+                    .text_field
+                    .update(cx, |text_field, cx| text_field.set_text("", cx))
+            });
+            input.read(cx).focus_handle(cx).focus(cx);
+        });
+        cx.run_until_parked();
+
+        cx.simulate_input("12.x5");
+        assert_eq!(text(cx), "12.5");
+        assert!(changes.borrow().is_empty());
+
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(cx.update(|cx| input.read(cx).value()), 12.5);
+        assert_eq!(*changes.borrow(), vec![12.5]);
+
+        cx.update(|cx| {
+            input.update(cx, |input, cx| {
+                input
+                    .text_field
+                    .update(cx, |text_field, cx| text_field.set_text("", cx))
+            })
+        });
+        cx.simulate_input("250");
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(text(cx), "100");
+        assert_eq!(*changes.borrow(), vec![12.5, 100.]);
+    }
+}
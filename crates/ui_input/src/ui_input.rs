@@ -1,11 +1,24 @@
 //! # UI – Text Field
 //!
-//! This crate provides a text field component that can be used to create text fields like search inputs, form fields, etc.
+//! This crate provides a text field component that can be used to create text fields like search inputs, form fields, etc.,
+//! along with inputs built on it, such as [`NumberInput`], [`ChipInput`], [`Combobox`] and [`DatePicker`].
 //!
 //! It can't be located in the `ui` crate because it depends on `editor`.
 //!
 
-use editor::{Editor, EditorElement, EditorStyle};
+mod chip_input;
+mod combobox;
+mod date_picker;
+mod number_input;
+
+pub use chip_input::*;
+pub use combobox::*;
+pub use date_picker::*;
+pub use number_input::*;
+
+use std::sync::Arc;
+
+use editor::{actions::MoveToEnd, Editor, EditorElement, EditorStyle};
 use gpui::{AppContext, FocusHandle, FocusableView, FontStyle, Hsla, TextStyle, View};
 use settings::Settings;
 use theme::ThemeSettings;
@@ -46,6 +59,8 @@ pub struct TextField {
     with_label: FieldLabelLayout,
     /// Whether the text field is disabled.
     disabled: bool,
+    /// An optional error message, shown below the text field.
+    error: Option<SharedString>,
 }
 
 impl FocusableView for TextField {
@@ -75,6 +90,7 @@ impl TextField {
             start_icon: None,
             with_label: FieldLabelLayout::Hidden,
             disabled: false,
+            error: None,
         }
     }
 
@@ -94,9 +110,27 @@ impl TextField {
             .update(cx, |editor, _| editor.set_read_only(disabled))
     }
 
+    /// Sets the error message shown below the text field, outlining it in the error color.
+    pub fn set_error(&mut self, error: Option<SharedString>, cx: &mut ViewContext<Self>) {
+        self.error = error;
+        cx.notify();
+    }
+
     pub fn editor(&self) -> &View<Editor> {
         &self.editor
     }
+
+    pub fn text(&self, cx: &AppContext) -> String {
+        self.editor.read(cx).text(cx)
+    }
+
+    /// Replaces the text of the field, placing the cursor at its end.
+    pub fn set_text(&mut self, text: impl Into<Arc<str>>, cx: &mut ViewContext<Self>) {
+        self.editor.update(cx, |editor, cx| {
+            editor.set_text(text, cx);
+            editor.move_to_end(&MoveToEnd, cx);
+        })
+    }
 }
 
 impl Render for TextField {
@@ -114,13 +148,12 @@ impl Render for TextField {
             style.text_color = theme_color.text_disabled;
             style.background_color = theme_color.ghost_element_disabled;
             style.border_color = theme_color.border_disabled;
+        } else if self.error.is_some() {
+            style.border_color = cx.theme().status().error_border;
+        } else if self.editor.focus_handle(cx).is_focused(cx) {
+            style.border_color = theme_color.border_focused;
         }
 
-        // if self.error_message.is_some() {
-        //     style.text_color = cx.theme().status().error;
-        //     style.border_color = cx.theme().status().error_border
-        // }
-
         let text_style = TextStyle {
             font_family: settings.buffer_font.family.clone(),
             font_features: settings.buffer_font.features.clone(),
@@ -185,5 +218,55 @@ impl Render for TextField {
                         ),
                 ),
             )
+            .when_some(self.error.clone(), |this, error| {
+                this.child(Label::new(error).size(LabelSize::Small).color(Color::Error))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use editor::actions::MoveLeft;
+    use gpui::{FocusableView as _, TestAppContext, VisualTestContext};
+    use project::Project;
+    use settings::SettingsStore;
+
+    use super::TextField;
+
+    pub(crate) fn init_test(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let store = SettingsStore::test(cx);
+            cx.set_global(store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+            language::init(cx);
+            Project::init_settings(cx);
+            editor::init(cx);
+        });
+    }
+
+    #[gpui::test]
+    fn test_text_field_unicode_input(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (field, cx) = cx.add_window_view(|cx| TextField::new(cx, "Name", "Name"));
+        cx.update(|cx| field.read(cx).focus_handle(cx).focus(cx));
+        let cursor = |cx: &mut VisualTestContext| {
+            cx.update(|cx| {
+                let editor = field.read(cx).editor().clone();
+                editor.update(cx, |editor, cx| {
+                    editor.selections.newest::<usize>(cx).head()
+                })
+            })
+        };
+
+        cx.simulate_input("héllo, 世界 🦀");
+        assert_eq!(cx.update(|cx| field.read(cx).text(cx)), "héllo, 世界 🦀");
+        assert_eq!(cursor(cx), "héllo, 世界 🦀".len());
+
+        cx.dispatch_action(MoveLeft);
+        assert_eq!(cursor(cx), "héllo, 世界 ".len());
+        cx.simulate_input("!");
+        assert_eq!(cx.update(|cx| field.read(cx).text(cx)), "héllo, 世界 !🦀");
+        assert_eq!(cursor(cx), "héllo, 世界 !".len());
     }
 }
@@ -683,8 +683,12 @@ fn generate_commands(_: &AppContext) -> Vec<VimCommand> {
                 .boxed_clone(),
             )
         }),
-        VimCommand::new(("sor", "t"), SortLinesCaseSensitive).range(select_range),
-        VimCommand::new(("sort i", ""), SortLinesCaseInsensitive).range(select_range),
+        VimCommand::new(("sor", "t"), SortLinesCaseSensitive::default())
+            .bang(SortLinesCaseSensitive { descending: true })
+            .range(select_range),
+        VimCommand::new(("sort i", ""), SortLinesCaseInsensitive::default())
+            .bang(SortLinesCaseInsensitive { descending: true })
+            .range(select_range),
         VimCommand::str(("E", "xplore"), "project_panel::ToggleFocus"),
         VimCommand::str(("H", "explore"), "project_panel::ToggleFocus"),
         VimCommand::str(("L", "explore"), "project_panel::ToggleFocus"),
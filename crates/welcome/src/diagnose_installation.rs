@@ -0,0 +1,190 @@
+use gpui::{
+    actions, AppContext, DismissEvent, EventEmitter, FocusHandle, FocusableView, Render, Task,
+    ViewContext,
+};
+use ui::{prelude::*, ElevationIndex, Modal, ModalHeader, Section};
+use workspace::{ModalView, Workspace};
+
+actions!(welcome, [DiagnoseInstallation]);
+
+const RUSTUP_URL: &str = "https://rustup.rs";
+const NODE_DOWNLOAD_URL: &str = "https://nodejs.org/en/download";
+
+pub fn init(cx: &mut AppContext) {
+    cx.observe_new_views(|workspace: &mut Workspace, _cx| {
+        workspace.register_action(toggle);
+    })
+    .detach();
+}
+
+pub fn toggle(
+    workspace: &mut Workspace,
+    _: &DiagnoseInstallation,
+    cx: &mut ViewContext<Workspace>,
+) {
+    workspace.toggle_modal(cx, DiagnoseInstallationModal::new);
+}
+
+/// A common setup problem that is checked for by [`DiagnoseInstallation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InstallationCheck {
+    Rustc,
+    Node,
+    ExtensionsDir,
+}
+
+impl InstallationCheck {
+    const ALL: [Self; 3] = [Self::Rustc, Self::Node, Self::ExtensionsDir];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Rustc => "Rust toolchain (rustc)",
+            Self::Node => "Node.js (node)",
+            Self::ExtensionsDir => "Extensions directory",
+        }
+    }
+
+    /// Runs the check, returning a description of what was found or why it failed.
+    ///
+    /// This touches the file system, so it should be run on the background executor.
+    fn run(self) -> Result<String, String> {
+        match self {
+            Self::Rustc => find_binary("rustc"),
+            Self::Node => find_binary("node"),
+            Self::ExtensionsDir => {
+                let extensions_dir = paths::extensions_dir();
+                if extensions_dir.is_dir() {
+                    Ok(extensions_dir.display().to_string())
+                } else {
+                    Err(format!("{} does not exist", extensions_dir.display()))
+                }
+            }
+        }
+    }
+
+    fn fix(self, cx: &mut WindowContext) {
+        match self {
+            Self::Rustc => cx.open_url(RUSTUP_URL),
+            Self::Node => cx.open_url(NODE_DOWNLOAD_URL),
+            // Installing an extension creates the directory.
+            Self::ExtensionsDir => cx.dispatch_action(Box::new(zed_actions::Extensions)),
+        }
+    }
+}
+
+fn find_binary(name: &str) -> Result<String, String> {
+    which::which(name)
+        .map(|path| path.display().to_string())
+        .map_err(|_| format!("`{name}` was not found on your PATH"))
+}
+
+pub struct DiagnoseInstallationModal {
+    focus_handle: FocusHandle,
+    /// `None` while the checks are still running.
+    results: Option<Vec<(InstallationCheck, Result<String, String>)>>,
+    _run_checks: Task<()>,
+}
+
+impl EventEmitter<DismissEvent> for DiagnoseInstallationModal {}
+impl ModalView for DiagnoseInstallationModal {}
+
+impl FocusableView for DiagnoseInstallationModal {
+    fn focus_handle(&self, _cx: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl DiagnoseInstallationModal {
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let run_checks = cx.background_executor().spawn(async move {
+            InstallationCheck::ALL
+                .into_iter()
+                .map(|check| (check, check.run()))
+                .collect::<Vec<_>>()
+        });
+        let _run_checks = cx.spawn(|this, mut cx| async move {
+            let results = run_checks.await;
+            this.update(&mut cx, |this, cx| {
+                this.results = Some(results);
+                cx.notify();
+            })
+            .ok();
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            results: None,
+            _run_checks,
+        }
+    }
+
+    fn cancel(&mut self, _: &menu::Cancel, cx: &mut ViewContext<Self>) {
+        cx.emit(DismissEvent)
+    }
+
+    fn render_result(
+        &self,
+        check: InstallationCheck,
+        result: &Result<String, String>,
+    ) -> impl IntoElement {
+        let (icon, color, detail) = match result {
+            Ok(detail) => (IconName::Check, Color::Success, detail),
+            Err(detail) => (IconName::XCircle, Color::Error, detail),
+        };
+
+        h_flex()
+            .gap_2()
+            .justify_between()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Icon::new(icon).size(IconSize::Small).color(color))
+                    .child(
+                        v_flex().child(Label::new(check.label())).child(
+                            Label::new(detail.clone())
+                                .size(LabelSize::Small)
+                                .color(Color::Muted),
+                        ),
+                    ),
+            )
+            .when(result.is_err(), |this| {
+                this.child(
+                    Button::new(("fix", check as usize), "Fix")
+                        .style(ButtonStyle::Filled)
+                        .layer(ElevationIndex::ModalSurface)
+                        .on_click(move |_, cx| check.fix(cx)),
+                )
+            })
+    }
+}
+
+impl Render for DiagnoseInstallationModal {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let section = match &self.results {
+            Some(results) => Section::new().child(
+                v_flex().gap_3().children(
+                    results
+                        .iter()
+                        .map(|(check, result)| self.render_result(*check, result)),
+                ),
+            ),
+            None => Section::new().child(Label::new("Checking…").color(Color::Muted)),
+        };
+
+        div()
+            .track_focus(&self.focus_handle(cx))
+            .elevation_3(cx)
+            .on_action(cx.listener(Self::cancel))
+            .occlude()
+            .w(rems(30.))
+            .child(
+                Modal::new("diagnose-installation", None)
+                    .header(
+                        ModalHeader::new().show_dismiss_button(true).child(
+                            Headline::new("Diagnose Installation").size(HeadlineSize::Small),
+                        ),
+                    )
+                    .section(section),
+            )
+    }
+}
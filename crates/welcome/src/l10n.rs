@@ -0,0 +1,192 @@
+//! Translations of the strings shown on the welcome page.
+//!
+//! String tables live in `l10n/{locale}.json`, keyed by [`LocalizedString::key`].
+//! Strings missing from the table for the system locale fall back to English.
+
+use std::sync::OnceLock;
+
+use collections::HashMap;
+
+const FALLBACK_LOCALE: &str = "en";
+
+const STRING_TABLES: &[(&str, &str)] = &[
+    ("en", include_str!("l10n/en.json")),
+    ("de", include_str!("l10n/de.json")),
+    ("fr", include_str!("l10n/fr.json")),
+    ("ja", include_str!("l10n/ja.json")),
+    ("zh-Hans", include_str!("l10n/zh-Hans.json")),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalizedString {
+    GetStarted,
+    ChooseTheme,
+    ChooseKeymap,
+    SignInToCopilot,
+    EditSettings,
+    Resources,
+    InstallCli,
+    RegisterLinkHandler,
+    LinkHandlerRegistered,
+    DiagnoseInstallation,
+    ViewDocumentation,
+    ExploreExtensions,
+    BookOnboarding,
+    EnableVimMode,
+    VimModeTooltip,
+    SendCrashReports,
+    SendTelemetry,
+}
+
+impl LocalizedString {
+    pub const ALL: &'static [Self] = &[
+        Self::GetStarted,
+        Self::ChooseTheme,
+        Self::ChooseKeymap,
+        Self::SignInToCopilot,
+        Self::EditSettings,
+        Self::Resources,
+        Self::InstallCli,
+        Self::RegisterLinkHandler,
+        Self::LinkHandlerRegistered,
+        Self::DiagnoseInstallation,
+        Self::ViewDocumentation,
+        Self::ExploreExtensions,
+        Self::BookOnboarding,
+        Self::EnableVimMode,
+        Self::VimModeTooltip,
+        Self::SendCrashReports,
+        Self::SendTelemetry,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::GetStarted => "get_started",
+            Self::ChooseTheme => "choose_theme",
+            Self::ChooseKeymap => "choose_keymap",
+            Self::SignInToCopilot => "sign_in_to_copilot",
+            Self::EditSettings => "edit_settings",
+            Self::Resources => "resources",
+            Self::InstallCli => "install_cli",
+            Self::RegisterLinkHandler => "register_link_handler",
+            Self::LinkHandlerRegistered => "link_handler_registered",
+            Self::DiagnoseInstallation => "diagnose_installation",
+            Self::ViewDocumentation => "view_documentation",
+            Self::ExploreExtensions => "explore_extensions",
+            Self::BookOnboarding => "book_onboarding",
+            Self::EnableVimMode => "enable_vim_mode",
+            Self::VimModeTooltip => "vim_mode_tooltip",
+            Self::SendCrashReports => "send_crash_reports",
+            Self::SendTelemetry => "send_telemetry",
+        }
+    }
+
+    /// Returns this string translated for the system locale.
+    pub fn text(self) -> &'static str {
+        static STRINGS: OnceLock<Strings> = OnceLock::new();
+        STRINGS
+            .get_or_init(|| {
+                let locale = sys_locale::get_locale().unwrap_or_default();
+                Strings::for_locale(&locale)
+            })
+            .get(self)
+    }
+}
+
+struct Strings {
+    table: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+impl Strings {
+    fn for_locale(locale: &str) -> Self {
+        let table = match resolve_locale(locale) {
+            FALLBACK_LOCALE => HashMap::default(),
+            locale => load_string_table(locale),
+        };
+        Self {
+            table,
+            fallback: load_string_table(FALLBACK_LOCALE),
+        }
+    }
+
+    fn get(&self, string: LocalizedString) -> &str {
+        let key = string.key();
+        self.table
+            .get(key)
+            .or_else(|| self.fallback.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+}
+
+fn load_string_table(locale: &str) -> HashMap<String, String> {
+    STRING_TABLES
+        .iter()
+        .find(|(table_locale, _)| *table_locale == locale)
+        .and_then(|(_, json)| {
+            serde_json::from_str(json)
+                .map_err(|error| log::error!("invalid {locale} string table: {error}"))
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+/// Maps a system locale, such as `de-AT` or `zh_CN`, to the string table used for it.
+fn resolve_locale(locale: &str) -> &'static str {
+    let mut subtags = locale.split(['-', '_', '.']);
+    let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    let region_or_script = subtags.next().unwrap_or_default();
+
+    let table_locale = match language.as_str() {
+        "zh" => match region_or_script {
+            // Traditional Chinese is not translated yet.
+            "Hant" | "TW" | "HK" | "MO" => FALLBACK_LOCALE,
+            _ => "zh-Hans",
+        },
+        language => language,
+    };
+
+    STRING_TABLES
+        .iter()
+        .map(|(locale, _)| *locale)
+        .find(|locale| *locale == table_locale)
+        .unwrap_or(FALLBACK_LOCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_locale() {
+        assert_eq!(resolve_locale("en-US"), "en");
+        assert_eq!(resolve_locale("de-AT"), "de");
+        assert_eq!(resolve_locale("fr_CA.UTF-8"), "fr");
+        assert_eq!(resolve_locale("ja-JP"), "ja");
+        assert_eq!(resolve_locale("zh-Hans-CN"), "zh-Hans");
+        assert_eq!(resolve_locale("zh_CN"), "zh-Hans");
+        assert_eq!(resolve_locale("zh-Hant-TW"), "en");
+        assert_eq!(resolve_locale("pt-BR"), "en");
+        assert_eq!(resolve_locale(""), "en");
+    }
+
+    #[test]
+    fn test_string_tables_are_complete() {
+        for (locale, _) in STRING_TABLES {
+            let table = load_string_table(locale);
+            for string in LocalizedString::ALL {
+                assert!(
+                    table.contains_key(string.key()),
+                    "{locale} string table is missing {:?}",
+                    string.key()
+                );
+            }
+        }
+
+        let strings = Strings::for_locale("de-DE");
+        assert_eq!(strings.get(LocalizedString::ChooseTheme), "Theme auswählen");
+        let strings = Strings::for_locale("pt-BR");
+        assert_eq!(strings.get(LocalizedString::ChooseTheme), "Choose a Theme");
+    }
+}
@@ -1,17 +1,20 @@
 mod base_keymap_picker;
 mod base_keymap_setting;
+mod diagnose_installation;
+mod l10n;
 mod multibuffer_hint;
 
-use client::{telemetry::Telemetry, TelemetrySettings};
+use client::{telemetry::Telemetry, TelemetrySettings, ZED_URL_SCHEME};
 use db::kvp::KEY_VALUE_STORE;
 use gpui::{
     actions, svg, Action, AppContext, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
     ParentElement, Render, Styled, Subscription, Task, View, ViewContext, VisualContext, WeakView,
     WindowContext,
 };
+use l10n::LocalizedString;
 use settings::{Settings, SettingsStore};
 use std::sync::Arc;
-use ui::{prelude::*, CheckboxWithLabel, Tooltip};
+use ui::{prelude::*, CheckboxWithLabel, Toggle, Tooltip};
 use vim_mode_setting::VimModeSetting;
 use workspace::{
     dock::DockPosition,
@@ -20,6 +23,7 @@ use workspace::{
 };
 
 pub use base_keymap_setting::BaseKeymap;
+pub use diagnose_installation::DiagnoseInstallation;
 pub use multibuffer_hint::*;
 
 actions!(welcome, [ResetHints]);
@@ -42,6 +46,7 @@ pub fn init(cx: &mut AppContext) {
     .detach();
 
     base_keymap_picker::init(cx);
+    diagnose_installation::init(cx);
 }
 
 pub fn show_welcome_view(
@@ -65,11 +70,15 @@ pub struct WelcomePage {
     workspace: WeakView<Workspace>,
     focus_handle: FocusHandle,
     telemetry: Arc<Telemetry>,
+    /// Whether Zed was registered as the handler for `zed://` links from this page.
+    link_handler_registered: bool,
     _settings_subscription: Subscription,
 }
 
 impl Render for WelcomePage {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let vim_mode_enabled = VimModeSetting::get_global(cx).0;
+
         h_flex()
             .size_full()
             .bg(cx.theme().colors().editor_background)
@@ -117,13 +126,13 @@ impl Render for WelcomePage {
                                     .border_color(cx.theme().colors().border_variant)
                                     .child(
                                         self.section_label(cx).child(
-                                            Label::new("Get Started")
+                                            Label::new(LocalizedString::GetStarted.text())
                                                 .size(LabelSize::XSmall)
                                                 .color(Color::Muted),
                                         ),
                                     )
                                     .child(
-                                        Button::new("choose-theme", "Choose a Theme")
+                                        Button::new("choose-theme", LocalizedString::ChooseTheme.text())
                                             .icon(IconName::SwatchBook)
                                             .icon_size(IconSize::XSmall)
                                             .icon_color(Color::Muted)
@@ -140,7 +149,7 @@ impl Render for WelcomePage {
                                             })),
                                     )
                                     .child(
-                                        Button::new("choose-keymap", "Choose a Keymap")
+                                        Button::new("choose-keymap", LocalizedString::ChooseKeymap.text())
                                             .icon(IconName::Keyboard)
                                             .icon_size(IconSize::XSmall)
                                             .icon_color(Color::Muted)
@@ -163,7 +172,7 @@ impl Render for WelcomePage {
                                     .child(
                                         Button::new(
                                             "sign-in-to-copilot",
-                                            "Sign in to GitHub Copilot",
+                                            LocalizedString::SignInToCopilot.text(),
                                         )
                                         .icon(IconName::Copilot)
                                         .icon_size(IconSize::XSmall)
@@ -179,7 +188,7 @@ impl Render for WelcomePage {
                                         ),
                                     )
                                     .child(
-                                        Button::new("edit settings", "Edit Settings")
+                                        Button::new("edit settings", LocalizedString::EditSettings.text())
                                             .icon(IconName::Settings)
                                             .icon_size(IconSize::XSmall)
                                             .icon_color(Color::Muted)
@@ -199,14 +208,14 @@ impl Render for WelcomePage {
                                     .gap_2()
                                     .child(
                                         self.section_label(cx).child(
-                                            Label::new("Resources")
+                                            Label::new(LocalizedString::Resources.text())
                                                 .size(LabelSize::XSmall)
                                                 .color(Color::Muted),
                                         ),
                                     )
                                     .when(cfg!(target_os = "macos"), |el| {
                                         el.child(
-                                            Button::new("install-cli", "Install the CLI")
+                                            Button::new("install-cli", LocalizedString::InstallCli.text())
                                                 .icon(IconName::Terminal)
                                                 .icon_size(IconSize::XSmall)
                                                 .icon_color(Color::Muted)
@@ -222,9 +231,62 @@ impl Render for WelcomePage {
                                                         .detach_and_log_err(cx);
                                                 })),
                                         )
+                                        .child(
+                                            Button::new(
+                                                "register-link-handler",
+                                                if self.link_handler_registered {
+                                                    LocalizedString::LinkHandlerRegistered.text()
+                                                } else {
+                                                    LocalizedString::RegisterLinkHandler.text()
+                                                },
+                                            )
+                                            .icon(IconName::Link)
+                                            .icon_size(IconSize::XSmall)
+                                            .icon_color(Color::Muted)
+                                            .icon_position(IconPosition::Start)
+                                            .disabled(self.link_handler_registered)
+                                            .on_click(cx.listener(|this, _, cx| {
+                                                this.telemetry.report_app_event(
+                                                    "welcome page: register link handler".to_string(),
+                                                );
+                                                let register = cx.register_url_scheme(ZED_URL_SCHEME);
+                                                cx.spawn(|this, mut cx| async move {
+                                                    register.await?;
+                                                    this.update(&mut cx, |this, cx| {
+                                                        this.link_handler_registered = true;
+                                                        cx.notify();
+                                                    })
+                                                })
+                                                .detach_and_log_err(cx);
+                                            })),
+                                        )
                                     })
                                     .child(
-                                        Button::new("view-docs", "View Documentation")
+                                        Button::new(
+                                            "diagnose-installation",
+                                            LocalizedString::DiagnoseInstallation.text(),
+                                        )
+                                        .icon(IconName::Check)
+                                        .icon_size(IconSize::XSmall)
+                                        .icon_color(Color::Muted)
+                                        .icon_position(IconPosition::Start)
+                                        .on_click(cx.listener(|this, _, cx| {
+                                            this.telemetry.report_app_event(
+                                                "welcome page: diagnose installation".to_string(),
+                                            );
+                                            this.workspace
+                                                .update(cx, |workspace, cx| {
+                                                    diagnose_installation::toggle(
+                                                        workspace,
+                                                        &DiagnoseInstallation,
+                                                        cx,
+                                                    )
+                                                })
+                                                .ok();
+                                        })),
+                                    )
+                                    .child(
+                                        Button::new("view-docs", LocalizedString::ViewDocumentation.text())
                                             .icon(IconName::FileCode)
                                             .icon_size(IconSize::XSmall)
                                             .icon_color(Color::Muted)
@@ -237,7 +299,7 @@ impl Render for WelcomePage {
                                             })),
                                     )
                                     .child(
-                                        Button::new("explore-extensions", "Explore Extensions")
+                                        Button::new("explore-extensions", LocalizedString::ExploreExtensions.text())
                                             .icon(IconName::Blocks)
                                             .icon_size(IconSize::XSmall)
                                             .icon_color(Color::Muted)
@@ -252,7 +314,7 @@ impl Render for WelcomePage {
                                             })),
                                     )
                                     .child(
-                                        Button::new("book-onboarding", "Book Onboarding")
+                                        Button::new("book-onboarding", LocalizedString::BookOnboarding.text())
                                             .icon(IconName::PhoneIncoming)
                                             .icon_size(IconSize::XSmall)
                                             .icon_color(Color::Muted)
@@ -269,34 +331,35 @@ impl Render for WelcomePage {
                             .child(
                                 h_flex()
                                     .justify_between()
-                                    .child(CheckboxWithLabel::new(
-                                        "enable-vim",
-                                        Label::new("Enable Vim Mode"),
-                                        if VimModeSetting::get_global(cx).0 {
-                                            ui::ToggleState::Selected
-                                        } else {
-                                            ui::ToggleState::Unselected
-                                        },
-                                        cx.listener(move |this, selection, cx| {
-                                            this.telemetry
-                                                .report_app_event("welcome page: toggle vim".to_string());
-                                            this.update_settings::<VimModeSetting>(
-                                                selection,
-                                                cx,
-                                                |setting, value| *setting = Some(value),
-                                            );
-                                        }),
-                                    ))
+                                    .child(
+                                        h_flex()
+                                            .gap(DynamicSpacing::Base08.rems(cx))
+                                            .child(
+                                                Toggle::new("enable-vim", vim_mode_enabled)
+                                                    .on_change(cx.listener(|this, on, cx| {
+                                                        this.set_vim_mode(*on, cx)
+                                                    })),
+                                            )
+                                            .child(
+                                                div()
+                                                    .id("enable-vim-label")
+                                                    .cursor_pointer()
+                                                    .on_click(cx.listener(move |this, _, cx| {
+                                                        this.set_vim_mode(!vim_mode_enabled, cx)
+                                                    }))
+                                                    .child(Label::new(LocalizedString::EnableVimMode.text())),
+                                            ),
+                                    )
                                     .child(
                                         IconButton::new("vim-mode", IconName::Info)
                                             .icon_size(IconSize::XSmall)
                                             .icon_color(Color::Muted)
-                                            .tooltip(|cx| Tooltip::text("You can also toggle Vim Mode via the command palette or Editor Controls menu.", cx)),
+                                            .tooltip(|cx| Tooltip::text(LocalizedString::VimModeTooltip.text(), cx)),
                                     )
                             )
                             .child(CheckboxWithLabel::new(
                                 "enable-crash",
-                                Label::new("Send Crash Reports"),
+                                Label::new(LocalizedString::SendCrashReports.text()),
                                 if TelemetrySettings::get_global(cx).diagnostics {
                                     ui::ToggleState::Selected
                                 } else {
@@ -317,7 +380,7 @@ impl Render for WelcomePage {
                             ))
                             .child(CheckboxWithLabel::new(
                                 "enable-telemetry",
-                                Label::new("Send Telemetry"),
+                                Label::new(LocalizedString::SendTelemetry.text()),
                                 if TelemetrySettings::get_global(cx).metrics {
                                     ui::ToggleState::Selected
                                 } else {
@@ -342,23 +405,29 @@ impl Render for WelcomePage {
 
 impl WelcomePage {
     pub fn new(workspace: &Workspace, cx: &mut ViewContext<Workspace>) -> View<Self> {
-        let this = cx.new_view(|cx| {
-            cx.on_release(|this: &mut Self, _, _| {
-                this.telemetry
-                    .report_app_event("welcome page: close".to_string());
-            })
-            .detach();
-
-            WelcomePage {
-                focus_handle: cx.focus_handle(),
-                workspace: workspace.weak_handle(),
-                telemetry: workspace.client().telemetry().clone(),
-                _settings_subscription: cx
-                    .observe_global::<SettingsStore>(move |_, cx| cx.notify()),
-            }
-        });
+        let workspace_handle = workspace.weak_handle();
+        let telemetry = workspace.client().telemetry().clone();
+        cx.new_view(|cx| Self::build(workspace_handle, telemetry, cx))
+    }
+
+    fn build(
+        workspace: WeakView<Workspace>,
+        telemetry: Arc<Telemetry>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        cx.on_release(|this: &mut Self, _, _| {
+            this.telemetry
+                .report_app_event("welcome page: close".to_string());
+        })
+        .detach();
 
-        this
+        WelcomePage {
+            focus_handle: cx.focus_handle(),
+            workspace,
+            telemetry,
+            link_handler_registered: false,
+            _settings_subscription: cx.observe_global::<SettingsStore>(move |_, cx| cx.notify()),
+        }
     }
 
     fn section_label(&self, cx: &WindowContext) -> Div {
@@ -368,6 +437,16 @@ impl WelcomePage {
             .text_color(Color::Muted.color(cx))
     }
 
+    fn set_vim_mode(&mut self, enabled: bool, cx: &mut ViewContext<Self>) {
+        self.telemetry
+            .report_app_event("welcome page: toggle vim".to_string());
+        self.update_settings::<VimModeSetting>(
+            &ToggleState::from(enabled),
+            cx,
+            |setting, value| *setting = Some(value),
+        );
+    }
+
     fn update_settings<T: Settings>(
         &mut self,
         selection: &ToggleState,
@@ -418,15 +497,82 @@ impl Item for WelcomePage {
         _workspace_id: Option<WorkspaceId>,
         cx: &mut ViewContext<Self>,
     ) -> Option<View<Self>> {
-        Some(cx.new_view(|cx| WelcomePage {
-            focus_handle: cx.focus_handle(),
-            workspace: self.workspace.clone(),
-            telemetry: self.telemetry.clone(),
-            _settings_subscription: cx.observe_global::<SettingsStore>(move |_, cx| cx.notify()),
-        }))
+        let workspace = self.workspace.clone();
+        let telemetry = self.telemetry.clone();
+        Some(cx.new_view(|cx| Self::build(workspace, telemetry, cx)))
     }
 
     fn to_item_events(event: &Self::Event, mut f: impl FnMut(workspace::item::ItemEvent)) {
         f(*event)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use fs::FakeFs;
+    use gpui::{BorrowAppContext, TestAppContext};
+    use project::Project;
+    use settings::SettingsStore;
+    use workspace::{SplitDirection, Workspace};
+
+    use super::*;
+
+    #[gpui::test]
+    async fn test_split_welcome_page_observes_settings(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+            language::init(cx);
+            workspace::init_settings(cx);
+            Project::init_settings(cx);
+            client::init_settings(cx);
+            vim_mode_setting::init(cx);
+            BaseKeymap::register(cx);
+        });
+
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) = cx.add_window_view(|cx| Workspace::test_new(project, cx));
+
+        let welcome_page = workspace.update(cx, |workspace, cx| {
+            let welcome_page = WelcomePage::new(workspace, cx);
+            workspace.add_item_to_active_pane(Box::new(welcome_page.clone()), None, true, cx);
+            welcome_page
+        });
+        let split_welcome_page = workspace.update(cx, |workspace, cx| {
+            let pane = workspace.active_pane().clone();
+            let new_pane = workspace
+                .split_and_clone(pane, SplitDirection::Right, cx)
+                .expect("welcome page should clone on split");
+            new_pane
+                .read(cx)
+                .active_item()
+                .and_then(|item| item.downcast::<WelcomePage>())
+                .expect("split pane should contain a welcome page")
+        });
+        assert_ne!(welcome_page.entity_id(), split_welcome_page.entity_id());
+        cx.update(|cx| {
+            assert_eq!(
+                split_welcome_page.read(cx).tab_content_text(cx),
+                Some("Welcome".into())
+            );
+            assert!(!split_welcome_page.read(cx).can_save(cx));
+        });
+
+        let notifications = Rc::new(Cell::new(0));
+        cx.update(|cx| {
+            for page in [&welcome_page, &split_welcome_page] {
+                let notifications = notifications.clone();
+                cx.observe(page, move |_, _| notifications.set(notifications.get() + 1))
+                    .detach();
+            }
+        });
+
+        cx.update(|cx| cx.update_global::<SettingsStore, _>(|_, _| {}));
+        cx.run_until_parked();
+        assert_eq!(notifications.get(), 2);
+    }
+}
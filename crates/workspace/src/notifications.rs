@@ -1,18 +1,58 @@
 use crate::{Toast, Workspace};
 use collections::HashMap;
 use gpui::{
-    svg, AnyView, AppContext, AsyncWindowContext, ClipboardItem, DismissEvent, Entity, EntityId,
-    EventEmitter, Global, PromptLevel, Render, ScrollHandle, Task, View, ViewContext,
-    VisualContext, WindowContext,
+    svg, AnyElement, AnyView, AppContext, AsyncWindowContext, ClipboardItem, DismissEvent, Entity,
+    EntityId, EventEmitter, FontWeight, Global, PromptLevel, Render, ScrollHandle, Task, View,
+    ViewContext, VisualContext, WindowContext,
 };
 use language::DiagnosticSeverity;
 
 use std::{any::TypeId, ops::DerefMut, time::Duration};
-use ui::{prelude::*, Tooltip};
+use ui::{notification_level_color, prelude::*, Tooltip};
 use util::ResultExt;
 
 pub fn init(cx: &mut AppContext) {
     cx.set_global(NotificationTracker::new());
+    cx.set_notification_renderer(render_window_notification);
+}
+
+/// Renders a notification shown with [`WindowContext::push_notification`] in the colors of the
+/// current theme.
+fn render_window_notification(
+    notification: &gpui::Notification,
+    id: gpui::NotificationId,
+    cx: &mut WindowContext,
+) -> AnyElement {
+    let accent = notification_level_color(notification.level, cx);
+
+    h_flex()
+        .id(id)
+        .occlude()
+        .w_80()
+        .items_start()
+        .gap_2()
+        .p_2()
+        .elevation_3(cx)
+        .child(div().w_1().h_full().flex_none().rounded_sm().bg(accent))
+        .child(
+            v_flex()
+                .flex_1()
+                .gap_1()
+                .overflow_hidden()
+                .child(Label::new(notification.title.clone()).weight(FontWeight::SEMIBOLD))
+                .children(
+                    notification
+                        .body
+                        .clone()
+                        .map(|body| Label::new(body).size(LabelSize::Small).color(Color::Muted)),
+                ),
+        )
+        .child(
+            IconButton::new("dismiss-notification", IconName::Close)
+                .icon_size(IconSize::Small)
+                .on_click(move |_, cx| cx.dismiss_notification(id)),
+        )
+        .into_any_element()
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -5,8 +5,9 @@ use theme::all_theme_colors;
 use ui::{
     element_cell, prelude::*, string_cell, utils::calculate_contrast_ratio, AudioStatus,
     Availability, Avatar, AvatarAudioStatusIndicator, AvatarAvailabilityIndicator, ButtonLike,
-    Checkbox, CheckboxWithLabel, ContentGroup, DecoratedIcon, ElevationIndex, Facepile,
-    IconDecoration, Indicator, Switch, SwitchWithLabel, Table, TintColor, Tooltip,
+    Checkbox, CheckboxWithLabel, CodeBlock, ContentGroup, DecoratedIcon, ElevationIndex,
+    EmptyState, Facepile, IconDecoration, Indicator, Slider, Switch, SwitchWithLabel, Table,
+    TintColor, Toast, Toggle, Tooltip,
 };
 
 use crate::{Item, Workspace};
@@ -369,18 +370,24 @@ impl ThemePreview {
             .overflow_scroll()
             .size_full()
             .gap_2()
+            .child(Avatar::render_component_previews(cx))
             .child(Button::render_component_previews(cx))
             .child(Checkbox::render_component_previews(cx))
             .child(CheckboxWithLabel::render_component_previews(cx))
+            .child(CodeBlock::render_component_previews(cx))
             .child(ContentGroup::render_component_previews(cx))
             .child(DecoratedIcon::render_component_previews(cx))
+            .child(EmptyState::render_component_previews(cx))
             .child(Facepile::render_component_previews(cx))
             .child(Icon::render_component_previews(cx))
             .child(IconDecoration::render_component_previews(cx))
             .child(Indicator::render_component_previews(cx))
+            .child(Slider::render_component_previews(cx))
             .child(Switch::render_component_previews(cx))
             .child(SwitchWithLabel::render_component_previews(cx))
             .child(Table::render_component_previews(cx))
+            .child(Toast::render_component_previews(cx))
+            .child(Toggle::render_component_previews(cx))
     }
 
     fn render_page_nav(&self, cx: &ViewContext<Self>) -> impl IntoElement {
@@ -5,6 +5,7 @@ pub(crate) mod linux_prompts;
 #[cfg(target_os = "macos")]
 pub(crate) mod mac_only_instance;
 mod open_listener;
+mod progress_icon;
 mod quick_action_bar;
 #[cfg(target_os = "windows")]
 pub(crate) mod windows_only_instance;
@@ -30,6 +31,7 @@ use gpui::{
 pub use open_listener::*;
 use outline_panel::OutlinePanel;
 use paths::{local_settings_file_relative_path, local_tasks_file_relative_path};
+use progress_icon::ProgressIcon;
 use project::{DirectoryLister, ProjectItem};
 use project_panel::ProjectPanel;
 use quick_action_bar::QuickActionBar;
@@ -173,6 +175,12 @@ pub fn initialize_workspace(
             cx.new_view(|cx| diagnostics::items::DiagnosticIndicator::new(workspace, cx));
         let activity_indicator =
             activity_indicator::ActivityIndicator::new(workspace, app_state.languages.clone(), cx);
+        let mut progress_icon = ProgressIcon::default();
+        cx.observe(&activity_indicator, move |_, activity_indicator, cx| {
+            let progress = activity_indicator.read(cx).indexing_progress(cx);
+            progress_icon.set_progress(progress, cx);
+        })
+        .detach();
         let active_buffer_language =
             cx.new_view(|_| language_selector::ActiveBufferLanguage::new(workspace));
         let active_toolchain_language =
@@ -0,0 +1,116 @@
+use gpui::{Hsla, Rgba, WindowContext, WINDOW_ICON_SIZE};
+use image::{imageops::FilterType, ImageFormat, RgbaImage};
+use release_channel::ReleaseChannel;
+use std::{f32::consts::TAU, sync::Arc};
+use theme::ActiveTheme;
+use util::ResultExt;
+
+/// The thickness, in pixels, of the progress ring drawn around the edge of the icon.
+const RING_WIDTH: f32 = 6.;
+
+/// Shows the progress of long-running work, such as indexing, as a ring drawn over the window
+/// icon, and restores the default icon once the work is done.
+#[derive(Default)]
+pub(crate) struct ProgressIcon {
+    app_icon: Option<RgbaImage>,
+    percentage: Option<usize>,
+}
+
+impl ProgressIcon {
+    pub fn set_progress(&mut self, percentage: Option<usize>, cx: &mut WindowContext) {
+        let percentage = percentage.map(|percentage| percentage.min(100));
+        if percentage == self.percentage {
+            return;
+        }
+        self.percentage = percentage;
+
+        let Some(percentage) = percentage else {
+            cx.set_window_icon(None);
+            return;
+        };
+        if self.app_icon.is_none() {
+            self.app_icon = load_app_icon(ReleaseChannel::global(cx));
+        }
+        if let Some(app_icon) = &self.app_icon {
+            let mut icon = app_icon.clone();
+            draw_progress_ring(&mut icon, percentage, cx.theme().colors().text_accent);
+            cx.set_window_icon(Some(Arc::from(icon.into_raw())));
+        }
+    }
+}
+
+fn load_app_icon(release_channel: ReleaseChannel) -> Option<RgbaImage> {
+    let png: &[u8] = match release_channel {
+        ReleaseChannel::Dev => include_bytes!("../../resources/app-icon-dev.png"),
+        ReleaseChannel::Nightly => include_bytes!("../../resources/app-icon-nightly.png"),
+        ReleaseChannel::Preview => include_bytes!("../../resources/app-icon-preview.png"),
+        ReleaseChannel::Stable => include_bytes!("../../resources/app-icon.png"),
+    };
+    let image = image::load_from_memory_with_format(png, ImageFormat::Png).log_err()?;
+    Some(
+        image
+            .resize_exact(WINDOW_ICON_SIZE, WINDOW_ICON_SIZE, FilterType::Triangle)
+            .into_rgba8(),
+    )
+}
+
+/// Draws a ring around the edge of the icon, filled clockwise from the top by `percentage`,
+/// with the rest of the ring dimmed as a track.
+fn draw_progress_ring(icon: &mut RgbaImage, percentage: usize, color: Hsla) {
+    let color = Rgba::from(color);
+    let track = Rgba {
+        r: 0.,
+        g: 0.,
+        b: 0.,
+        a: 0.5,
+    };
+    let filled_angle = TAU * percentage as f32 / 100.;
+    let center = icon.width() as f32 / 2.;
+    let outer_radius = center;
+    let inner_radius = outer_radius - RING_WIDTH;
+
+    for (x, y, pixel) in icon.enumerate_pixels_mut() {
+        let dx = x as f32 + 0.5 - center;
+        let dy = y as f32 + 0.5 - center;
+        let distance = dx.hypot(dy);
+        if distance < inner_radius || distance > outer_radius {
+            continue;
+        }
+
+        // Measure the angle clockwise from the top of the icon.
+        let angle = dx.atan2(-dy).rem_euclid(TAU);
+        let overlay = if angle <= filled_angle { color } else { track };
+        for (channel, overlay_channel) in pixel.0[..3]
+            .iter_mut()
+            .zip([overlay.r, overlay.g, overlay.b])
+        {
+            let blended = *channel as f32 * (1. - overlay.a) + overlay_channel * 255. * overlay.a;
+            *channel = blended.round() as u8;
+        }
+        let alpha = pixel.0[3] as f32 / 255.;
+        pixel.0[3] = ((alpha + overlay.a * (1. - alpha)) * 255.).round() as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::red;
+
+    #[test]
+    fn test_progress_ring() {
+        let mut icon = RgbaImage::new(WINDOW_ICON_SIZE, WINDOW_ICON_SIZE);
+        draw_progress_ring(&mut icon, 25, red());
+
+        let top = icon.get_pixel(WINDOW_ICON_SIZE / 2, 1).0;
+        let right = icon
+            .get_pixel(WINDOW_ICON_SIZE - 2, WINDOW_ICON_SIZE / 2 - 1)
+            .0;
+        let bottom = icon.get_pixel(WINDOW_ICON_SIZE / 2, WINDOW_ICON_SIZE - 2).0;
+        let center = icon.get_pixel(WINDOW_ICON_SIZE / 2, WINDOW_ICON_SIZE / 2).0;
+        assert_eq!(top, [255, 0, 0, 255]);
+        assert_eq!(right, [255, 0, 0, 255]);
+        assert_eq!(bottom, [0, 0, 0, 128]);
+        assert_eq!(center, [0, 0, 0, 0]);
+    }
+}
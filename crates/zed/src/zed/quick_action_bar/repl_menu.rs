@@ -1,10 +1,12 @@
 use std::time::Duration;
 
+use editor::Editor;
 use gpui::ElementId;
-use gpui::{percentage, Animation, AnimationExt, AnyElement, Transformation, View};
+use gpui::{percentage, Animation, AnimationExt, AnyElement, Transformation, View, WeakView};
 use picker::Picker;
 use repl::{
     components::{KernelPickerDelegate, KernelSelector},
+    kernels::RunningKernelInfo,
     worktree_id_for_editor, ExecutionState, JupyterSettings, Kernel, KernelSpecification,
     KernelStatus, Session, SessionSupport,
 };
@@ -202,6 +204,14 @@ impl QuickActionBar {
                             }
                         },
                     )
+                    .map(|menu| {
+                        let running_kernels = repl::reconnectable_kernels(cx);
+                        if running_kernels.is_empty() {
+                            menu
+                        } else {
+                            reconnect_kernel_entries(menu.separator(), running_kernels, &editor)
+                        }
+                    })
                     .separator()
                     .action("View Sessions", Box::new(repl::Sessions))
                     // TODO: Add shut down all kernels action
@@ -272,6 +282,36 @@ impl QuickActionBar {
                         .tooltip(move |cx| Tooltip::text(tooltip.clone(), cx))
                         .on_click(|_, cx| cx.dispatch_action(Box::new(repl::Run {}))),
                 )
+                .children(self.render_reconnect_menu(cx))
+                .into_any_element(),
+        )
+    }
+
+    /// Renders a menu for reconnecting the editor to a kernel left running outside
+    /// of a session, when there are any.
+    fn render_reconnect_menu(&self, cx: &mut ViewContext<Self>) -> Option<AnyElement> {
+        let editor = self.active_editor()?.downgrade();
+        if repl::reconnectable_kernels(cx).is_empty() {
+            return None;
+        }
+
+        Some(
+            PopoverMenu::new("repl-reconnect-menu")
+                .menu(move |cx| {
+                    let editor = editor.clone();
+                    ContextMenu::build(cx, move |menu, cx| {
+                        reconnect_kernel_entries(menu, repl::reconnectable_kernels(cx), &editor)
+                    })
+                    .into()
+                })
+                .trigger(
+                    IconButton::new("repl-reconnect-menu-trigger", IconName::ChevronDownSmall)
+                        .size(ButtonSize::Compact)
+                        .icon_size(IconSize::XSmall)
+                        .icon_color(Color::Muted)
+                        .style(ButtonStyle::Subtle)
+                        .tooltip(move |cx| Tooltip::text("Reconnect to a Running Kernel", cx)),
+                )
                 .into_any_element(),
         )
     }
@@ -370,6 +410,22 @@ impl QuickActionBar {
     }
 }
 
+fn reconnect_kernel_entries(
+    menu: ContextMenu,
+    running_kernels: Vec<RunningKernelInfo>,
+    editor: &WeakView<Editor>,
+) -> ContextMenu {
+    running_kernels.into_iter().fold(
+        menu.header("Reconnect to Running Kernel"),
+        |menu, running_kernel| {
+            let editor = editor.clone();
+            menu.entry(running_kernel.label(), None, move |cx| {
+                repl::reconnect_kernel(running_kernel.clone(), editor.clone(), cx).log_err();
+            })
+        },
+    )
+}
+
 fn session_state(session: View<Session>, cx: &WindowContext) -> ReplMenuState {
     let session = session.read(cx);
 